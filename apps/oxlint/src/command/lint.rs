@@ -76,6 +76,7 @@ pub struct BasicOptions {
 ///
 /// Accumulate rules and categories from left to right on the command-line.
 ///   For example `-D correctness -A no-debugger` or `-A all -D no-debugger`.
+///   Rule names can be prefixed with their plugin, e.g. `-D unicorn/no-lonely-if`.
 ///   The categories are:
 ///   * `correctness` - code that is outright wrong or useless (default)
 ///   * `suspicious`  - code that is most likely wrong or useless
@@ -308,6 +308,22 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn filter_plugin_rule() {
+        let args = &["-D", "eslint/no-debugger", "fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_errors, 1);
+
+        let args = &["-D", "unicorn/no-debugger", "fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_errors, 0);
+
+        let args =
+            &["-W", "correctness", "-A", "eslint/no-debugger", "fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+    }
+
     #[test]
     fn filter_error() {
         let args = &["-D", "correctness", "fixtures/linter/debugger.js"];
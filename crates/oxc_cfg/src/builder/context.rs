@@ -130,11 +130,11 @@ impl<'a, 'c> QueryCtx<'a, 'c> {
 
         self.resolve_ctx(ctx);
 
-        // mark the upper label continue jump point the same as ours if it isn't already assigned,
+        // mark the upper labels continue jump point the same as ours if it isn't already assigned,
         // NOTE: if it is already assigned there's a resolution before this context.
         if let Some(jmp) = continue_jmp {
-            if let Some(label_ctx @ RefCtxCursor(Ctx { continue_jmp: None, .. })) =
-                self.0.immediate_labeled_ctx()
+            for label_ctx in
+                self.0.immediate_labeled_ctxs().filter(|it| it.0.continue_jmp.is_none())
             {
                 label_ctx.mark_continue(jmp);
             }
@@ -221,9 +221,14 @@ impl<'a> ControlFlowGraphBuilder<'a> {
         QueryCtx(self, label)
     }
 
-    /// Returns `None` if there is no immediate labeled context before this call.
-    fn immediate_labeled_ctx<'c>(&'c mut self) -> Option<RefCtxCursor<'a, 'c>> {
-        self.ctx_stack.last_mut().filter(|it| it.label.is_some()).map(RefCtxCursor)
+    /// Returns the labeled contexts immediately before this call, e.g. both `a` and `b` in
+    /// `a: b: while (x) {}`.
+    fn immediate_labeled_ctxs<'c>(&'c mut self) -> impl Iterator<Item = RefCtxCursor<'a, 'c>> {
+        self.ctx_stack
+            .iter_mut()
+            .rev()
+            .take_while(|it| it.label.is_some() && it.flags.contains(CtxFlags::CONTINUE))
+            .map(RefCtxCursor)
     }
 
     fn in_break_context<F: Fn(&mut Ctx<'a>)>(&mut self, label: Option<&str>, f: F) {
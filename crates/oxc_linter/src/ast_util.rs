@@ -1,9 +1,12 @@
 use std::hash::{Hash, Hasher};
 
 use oxc_ast::AstKind;
-use oxc_semantic::{AstNode, SymbolId};
+use oxc_semantic::{AstNode, AstNodeId, SymbolId};
 use oxc_span::{GetSpan, Span};
-use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator, LogicalOperator, UnaryOperator},
+    precedence::{GetPrecedence, Precedence},
+};
 use rustc_hash::FxHasher;
 
 pub fn calculate_hash<T: Hash>(t: &T) -> u64 {
@@ -285,6 +288,30 @@ pub fn extract_regex_flags<'a>(
     Some(flags)
 }
 
+/// Checks if `callee` is the global `RegExp`, as in `new RegExp("a")` or `RegExp("a")`.
+pub fn is_global_regexp_callee(callee: &Expression, ctx: &LintContext) -> bool {
+    matches!(
+        callee.without_parenthesized(),
+        Expression::Identifier(ident) if ident.name == "RegExp" && is_global_reference(ident, ctx)
+    )
+}
+
+/// Returns the pattern and flags passed to the global `RegExp` constructor, as in
+/// `new RegExp("a", "u")` or `RegExp("a")`, when the pattern is a string literal.
+pub fn get_regexp_constructor_pattern<'a>(
+    callee: &Expression<'a>,
+    arguments: &'a oxc_allocator::Vec<'a, Argument<'a>>,
+    ctx: &LintContext<'a>,
+) -> Option<(&'a StringLiteral<'a>, Option<RegExpFlags>)> {
+    if !is_global_regexp_callee(callee, ctx) {
+        return None;
+    }
+    let Some(Argument::StringLiteral(pattern)) = arguments.first() else {
+        return None;
+    };
+    Some((pattern, extract_regex_flags(arguments)))
+}
+
 pub fn is_method_call<'a>(
     call_expr: &CallExpression<'a>,
     objects: Option<&[&'a str]>,
@@ -374,6 +401,15 @@ pub fn get_new_expr_ident_name<'a>(new_expr: &'a NewExpression<'a>) -> Option<&'
 
 /// Check if the given [IdentifierReference] is a global reference.
 /// Such as `window`, `document`, `globalThis`, etc.
+/// Returns the member expression in `expr`, looking through parentheses and an optional
+/// chain, e.g. `a.b` in `(a?.b)`.
+pub fn get_member_expression<'a, 'b>(expr: &'b Expression<'a>) -> Option<&'b MemberExpression<'a>> {
+    match expr.without_parenthesized() {
+        Expression::ChainExpression(chain) => chain.expression.as_member_expression(),
+        expr => expr.as_member_expression(),
+    }
+}
+
 pub fn is_global_reference(ident: &IdentifierReference, ctx: &LintContext) -> bool {
     let symbol_table = ctx.semantic().symbols();
     let Some(reference_id) = ident.reference_id.get() else {
@@ -403,3 +439,144 @@ pub fn is_function_node(node: &AstNode) -> bool {
         _ => false,
     }
 }
+
+/// Checks if the object is passed as a property descriptor to a global method:
+/// - `Object.defineProperty(obj, key, descriptor)`
+/// - `Reflect.defineProperty(obj, key, descriptor)`
+/// - `Object.defineProperties(obj, { key: descriptor })`
+/// - `Object.create(proto, { key: descriptor })`
+pub fn is_property_descriptor(obj_id: AstNodeId, ctx: &LintContext) -> bool {
+    let AstKind::ObjectExpression(obj) = ctx.nodes().kind(obj_id) else {
+        return false;
+    };
+    if is_argument_of_global_method_call(obj_id, "Object", "defineProperty", 2, ctx)
+        || is_argument_of_global_method_call(obj_id, "Reflect", "defineProperty", 2, ctx)
+    {
+        return true;
+    }
+
+    let Some(prop_node) = ctx.nodes().parent_node(obj_id) else {
+        return false;
+    };
+    let AstKind::ObjectProperty(prop) = prop_node.kind() else {
+        return false;
+    };
+    if prop.value.span() != obj.span {
+        return false;
+    }
+    ctx.nodes().parent_node(prop_node.id()).is_some_and(|properties| {
+        is_argument_of_global_method_call(properties.id(), "Object", "create", 1, ctx)
+            || is_argument_of_global_method_call(
+                properties.id(),
+                "Object",
+                "defineProperties",
+                1,
+                ctx,
+            )
+    })
+}
+
+fn is_argument_of_global_method_call(
+    node_id: AstNodeId,
+    object_name: &str,
+    method_name: &str,
+    index: usize,
+    ctx: &LintContext,
+) -> bool {
+    let Some(argument_node) = ctx.nodes().parent_node(node_id) else {
+        return false;
+    };
+    if !matches!(argument_node.kind(), AstKind::Argument(_)) {
+        return false;
+    }
+    let Some(AstKind::CallExpression(call)) = ctx.nodes().parent_kind(argument_node.id()) else {
+        return false;
+    };
+    let span = ctx.nodes().kind(node_id).span();
+    if !call.arguments.get(index).is_some_and(|argument| argument.span() == span) {
+        return false;
+    }
+    let Some(member) = call.callee.get_member_expr() else {
+        return false;
+    };
+    let Expression::Identifier(ident) = member.object() else {
+        return false;
+    };
+    ident.name == object_name
+        && member.static_property_name() == Some(method_name)
+        && is_global_reference(ident, ctx)
+}
+
+/// Returns the precedence of an expression, e.g. to decide if it needs
+/// parentheses when it becomes an operand.
+/// Expressions without operators, such as literals and calls, have the highest precedence.
+pub fn get_precedence(expr: &Expression) -> Precedence {
+    match expr {
+        Expression::SequenceExpression(_) => Precedence::Comma,
+        Expression::AssignmentExpression(_) | Expression::ArrowFunctionExpression(_) => {
+            Precedence::Assign
+        }
+        Expression::YieldExpression(_) => Precedence::Yield,
+        Expression::ConditionalExpression(_) => Precedence::Conditional,
+        Expression::LogicalExpression(logical) => logical.operator.precedence(),
+        Expression::BinaryExpression(binary) => binary.operator.precedence(),
+        Expression::PrivateInExpression(_)
+        | Expression::TSAsExpression(_)
+        | Expression::TSSatisfiesExpression(_) => Precedence::Relational,
+        Expression::UnaryExpression(_)
+        | Expression::AwaitExpression(_)
+        | Expression::TSTypeAssertion(_) => Precedence::Prefix,
+        Expression::UpdateExpression(_) => Precedence::Postfix,
+        _ => Precedence::Member,
+    }
+}
+
+/// Checks if the character `c` can be directly followed by `other` without the two
+/// being merged into a single token, e.g. `a` and `b` or `+` and `+`.
+pub fn can_be_adjacent(c: char, other: Option<char>) -> bool {
+    let Some(other) = other else {
+        return true;
+    };
+    let is_word = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    !(is_word(c) && is_word(other) || matches!(c, '+' | '-') && c == other)
+}
+
+/// Returns the expression statement that starts with `node`, e.g. `foo()` in `foo().bar;`,
+/// including the concise body of an arrow function.
+pub fn get_starting_expression_statement<'a, 'b>(
+    node: &'b AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    let start = node.kind().span().start;
+    ctx.nodes()
+        .iter_parents(node.id())
+        .skip(1)
+        .take_while(|parent| parent.kind().span().start == start)
+        .find(|parent| matches!(parent.kind(), AstKind::ExpressionStatement(_)))
+}
+
+/// Checks if replacing `node` with code that starts with `(`, `[` or `` ` `` would continue
+/// the previous statement, e.g. `foo\nnew Array("a")` or `const f = function () {}\nObject()`,
+/// so that a semicolon needs to be inserted first.
+pub fn needs_leading_semicolon<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> bool {
+    let Some(statement) = get_starting_expression_statement(node, ctx) else {
+        return false;
+    };
+    // Other statements, like `if (a) b` or `() => b`, can't directly follow a statement
+    let in_statement_list =
+        ctx.nodes().parent_node(statement.id()).is_some_and(|parent| match parent.kind() {
+            AstKind::Program(_)
+            | AstKind::BlockStatement(_)
+            | AstKind::StaticBlock(_)
+            | AstKind::SwitchCase(_)
+            | AstKind::TSModuleBlock(_) => true,
+            AstKind::FunctionBody(_) => !matches!(
+                ctx.nodes().parent_kind(parent.id()),
+                Some(AstKind::ArrowFunctionExpression(arrow)) if arrow.expression
+            ),
+            _ => false,
+        });
+    // A `}` may end an expression, such as a function or an object literal
+    let before = ctx.source_text()[..statement.kind().span().start as usize].trim_end();
+    in_statement_list && !before.is_empty() && !before.ends_with([';', '{'])
+}
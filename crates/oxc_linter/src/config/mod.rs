@@ -10,6 +10,7 @@ use rustc_hash::FxHashSet;
 use schemars::JsonSchema;
 use serde::Deserialize;
 
+pub(crate) use self::rules::parse_rule_key;
 pub use self::{
     env::OxlintEnv,
     globals::OxlintGlobals,
@@ -6,7 +6,10 @@ use schemars::{schema::SchemaObject, JsonSchema};
 use serde_json::{Number, Value};
 
 use crate::{
-    config::OxlintConfig, fixer::FixKind, rules::RULES, utils::is_jest_rule_adapted_to_vitest,
+    config::{parse_rule_key, OxlintConfig},
+    fixer::FixKind,
+    rules::RULES,
+    utils::is_jest_rule_adapted_to_vitest,
     FrameworkFlags, RuleCategory, RuleEnum, RuleWithSeverity,
 };
 
@@ -309,7 +312,7 @@ impl LintOptions {
                                 rules.extend(
                                     all_rules
                                         .iter()
-                                        .filter(|rule| rule.name() == name_or_category)
+                                        .filter(|rule| is_rule_selected(rule, name_or_category))
                                         .map(|rule| RuleWithSeverity::new(rule.clone(), *severity)),
                                 );
                             }
@@ -323,7 +326,7 @@ impl LintOptions {
                             if name_or_category == "all" {
                                 rules.clear();
                             } else {
-                                rules.retain(|rule| rule.name() != name_or_category);
+                                rules.retain(|rule| !is_rule_selected(rule, name_or_category));
                             }
                         }
                     };
@@ -374,3 +377,13 @@ impl LintOptions {
             .collect::<Vec<_>>()
     }
 }
+
+/// Checks if `rule` is selected by `name`, a rule name which may be prefixed with its plugin
+/// to tell apart rules of the same name, e.g. `no-lonely-if` or `unicorn/no-lonely-if`.
+fn is_rule_selected(rule: &RuleEnum, name: &str) -> bool {
+    if !name.contains('/') {
+        return rule.name() == name;
+    }
+    let (plugin_name, rule_name) = parse_rule_key(name);
+    rule.name() == rule_name && rule.plugin_name() == plugin_name
+}
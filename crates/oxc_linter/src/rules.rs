@@ -25,6 +25,7 @@ mod import {
 }
 
 mod eslint {
+    pub mod accessor_pairs;
     pub mod array_callback_return;
     pub mod constructor_super;
     pub mod default_case;
@@ -33,6 +34,7 @@ mod eslint {
     pub mod eqeqeq;
     pub mod for_direction;
     pub mod getter_return;
+    pub mod grouped_accessor_pairs;
     pub mod guard_for_in;
     pub mod max_classes_per_file;
     pub mod max_lines;
@@ -60,6 +62,7 @@ mod eslint {
     pub mod no_dupe_else_if;
     pub mod no_dupe_keys;
     pub mod no_duplicate_case;
+    pub mod no_else_return;
     pub mod no_empty;
     pub mod no_empty_character_class;
     pub mod no_empty_function;
@@ -68,6 +71,7 @@ mod eslint {
     pub mod no_eq_null;
     pub mod no_eval;
     pub mod no_ex_assign;
+    pub mod no_extend_native;
     pub mod no_extra_boolean_cast;
     pub mod no_fallthrough;
     pub mod no_func_assign;
@@ -77,21 +81,30 @@ mod eslint {
     pub mod no_irregular_whitespace;
     pub mod no_iterator;
     pub mod no_label_var;
+    pub mod no_lone_blocks;
+    pub mod no_lonely_if;
     pub mod no_loss_of_precision;
+    pub mod no_misleading_character_class;
+    pub mod no_multi_assign;
     pub mod no_multi_str;
     pub mod no_new;
     pub mod no_new_native_nonconstructor;
     pub mod no_new_wrappers;
     pub mod no_nonoctal_decimal_escape;
     pub mod no_obj_calls;
+    pub mod no_object_constructor;
+    pub mod no_octal_escape;
+    pub mod no_promise_executor_return;
     pub mod no_proto;
     pub mod no_prototype_builtins;
     pub mod no_redeclare;
     pub mod no_regex_spaces;
     pub mod no_restricted_globals;
+    pub mod no_return_assign;
     pub mod no_script_url;
     pub mod no_self_assign;
     pub mod no_self_compare;
+    pub mod no_sequences;
     pub mod no_setter_return;
     pub mod no_shadow_restricted_names;
     pub mod no_sparse_arrays;
@@ -100,23 +113,40 @@ mod eslint {
     pub mod no_this_before_super;
     pub mod no_undef;
     pub mod no_undefined;
+    pub mod no_unexpected_multiline;
+    pub mod no_unmodified_loop_condition;
     pub mod no_unreachable;
+    pub mod no_unreachable_loop;
     pub mod no_unsafe_finally;
     pub mod no_unsafe_negation;
     pub mod no_unsafe_optional_chaining;
+    pub mod no_unused_expressions;
     pub mod no_unused_labels;
     pub mod no_unused_private_class_members;
+    pub mod no_useless_backreference;
+    pub mod no_useless_call;
     pub mod no_useless_catch;
+    pub mod no_useless_computed_key;
     pub mod no_useless_concat;
     pub mod no_useless_constructor;
     pub mod no_useless_escape;
     pub mod no_useless_rename;
+    pub mod no_useless_return;
     pub mod no_var;
     pub mod no_void;
     pub mod no_with;
+    pub mod operator_assignment;
     pub mod prefer_exponentiation_operator;
+    pub mod prefer_named_capture_group;
+    pub mod prefer_numeric_literals;
+    pub mod prefer_object_spread;
+    pub mod prefer_regex_literals;
+    pub mod prefer_rest_params;
+    pub mod prefer_spread;
     pub mod radix;
+    pub mod require_atomic_updates;
     pub mod require_await;
+    pub mod require_unicode_regexp;
     pub mod require_yield;
     pub mod sort_imports;
     pub mod symbol_description;
@@ -131,10 +161,13 @@ mod typescript {
     pub mod ban_ts_comment;
     pub mod ban_tslint_comment;
     pub mod ban_types;
+    pub mod class_literal_property_style;
+    pub mod class_methods_use_this;
     pub mod consistent_indexed_object_style;
     pub mod consistent_type_definitions;
     pub mod consistent_type_imports;
     pub mod explicit_function_return_type;
+    pub mod method_signature_style;
     pub mod no_confusing_non_null_assertion;
     pub mod no_duplicate_enum_values;
     pub mod no_dynamic_delete;
@@ -143,6 +176,7 @@ mod typescript {
     pub mod no_extra_non_null_assertion;
     pub mod no_extraneous_class;
     pub mod no_import_type_side_effects;
+    pub mod no_inferrable_types;
     pub mod no_misused_new;
     pub mod no_namespace;
     pub mod no_non_null_asserted_nullish_coalescing;
@@ -158,8 +192,10 @@ mod typescript {
     pub mod prefer_for_of;
     pub mod prefer_function_type;
     pub mod prefer_literal_enum_member;
+    pub mod prefer_namespace_keyword;
     pub mod prefer_ts_expect_error;
     pub mod triple_slash_reference;
+    pub mod unified_signatures;
 }
 
 mod jest {
@@ -539,6 +575,36 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_restricted_globals,
     eslint::prefer_exponentiation_operator,
     eslint::no_constructor_return,
+    eslint::no_unmodified_loop_condition,
+    eslint::require_atomic_updates,
+    eslint::no_return_assign,
+    eslint::no_sequences,
+    eslint::no_unused_expressions,
+    eslint::no_lonely_if,
+    eslint::no_else_return,
+    eslint::no_lone_blocks,
+    eslint::no_multi_assign,
+    eslint::operator_assignment,
+    eslint::prefer_object_spread,
+    eslint::prefer_numeric_literals,
+    eslint::no_object_constructor,
+    eslint::no_useless_call,
+    eslint::no_useless_computed_key,
+    eslint::no_useless_return,
+    eslint::prefer_rest_params,
+    eslint::prefer_spread,
+    eslint::no_extend_native,
+    eslint::no_octal_escape,
+    eslint::no_promise_executor_return,
+    eslint::no_unreachable_loop,
+    eslint::grouped_accessor_pairs,
+    eslint::accessor_pairs,
+    eslint::no_unexpected_multiline,
+    eslint::no_misleading_character_class,
+    eslint::prefer_named_capture_group,
+    eslint::no_useless_backreference,
+    eslint::require_unicode_regexp,
+    eslint::prefer_regex_literals,
     typescript::adjacent_overload_signatures,
     typescript::array_type,
     typescript::ban_ts_comment,
@@ -573,6 +639,12 @@ oxc_macros::declare_all_lint_rules! {
     typescript::no_confusing_non_null_assertion,
     typescript::no_dynamic_delete,
     typescript::no_extraneous_class,
+    typescript::class_literal_property_style,
+    typescript::no_inferrable_types,
+    typescript::method_signature_style,
+    typescript::prefer_namespace_keyword,
+    typescript::unified_signatures,
+    typescript::class_methods_use_this,
     jest::consistent_test_it,
     jest::expect_expect,
     jest::max_expects,
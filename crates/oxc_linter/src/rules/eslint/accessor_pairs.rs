@@ -0,0 +1,338 @@
+use oxc_ast::{
+    ast::{
+        ClassElement, MethodDefinitionKind, MethodDefinitionType, ObjectExpression,
+        ObjectPropertyKind, PropertyKey, PropertyKind,
+    },
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    ast_util::is_property_descriptor,
+    context::LintContext,
+    rule::Rule,
+    utils::{get_accessor_key, AccessorKey, AccessorKind},
+    AstNode,
+};
+
+fn missing_getter_in_property_descriptor_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Getter is not present in property descriptor.").with_label(span0)
+}
+
+fn missing_setter_in_property_descriptor_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Setter is not present in property descriptor.").with_label(span0)
+}
+
+fn missing_getter_diagnostic(x0: &str, in_class: bool, span1: Span) -> OxcDiagnostic {
+    let class = if in_class { "class " } else { "" };
+    OxcDiagnostic::warn(format!("Getter is not present for {class}{x0}.")).with_label(span1)
+}
+
+fn missing_setter_diagnostic(x0: &str, in_class: bool, span1: Span) -> OxcDiagnostic {
+    let class = if in_class { "class " } else { "" };
+    OxcDiagnostic::warn(format!("Setter is not present for {class}{x0}.")).with_label(span1)
+}
+
+#[derive(Debug, Clone)]
+pub struct AccessorPairs {
+    set_without_get: bool,
+    get_without_set: bool,
+    enforce_for_class_members: bool,
+}
+
+impl Default for AccessorPairs {
+    fn default() -> Self {
+        Self { set_without_get: true, get_without_set: false, enforce_for_class_members: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce getter and setter pairs in objects and classes.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// It's a common mistake in JavaScript to create an object with just a setter for
+    /// a property but never have a corresponding getter defined for it. Without a
+    /// getter, you cannot read the property, so it ends up not being used.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var o = {
+    ///     set a(value) {
+    ///         this.val = value;
+    ///     }
+    /// };
+    ///
+    /// Object.defineProperty(o, "b", {
+    ///     set: function(value) {
+    ///         this.val = value;
+    ///     }
+    /// });
+    ///
+    /// class C {
+    ///     static set a(value) {
+    ///         this.val = value;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `setWithoutGet`: report a setter without a getter. Default `true`.
+    /// - `getWithoutSet`: report a getter without a setter. Default `false`.
+    /// - `enforceForClassMembers`: also check class getters and setters. Default `true`.
+    AccessorPairs,
+    pedantic
+);
+
+impl Rule for AccessorPairs {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let get_bool = |name: &str, default: bool| {
+            config
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(default)
+        };
+        Self {
+            set_without_get: get_bool("setWithoutGet", true),
+            get_without_set: get_bool("getWithoutSet", false),
+            enforce_for_class_members: get_bool("enforceForClassMembers", true),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !self.set_without_get && !self.get_without_set {
+            return;
+        }
+        match node.kind() {
+            AstKind::ObjectExpression(object) => {
+                let accessors = object
+                    .properties
+                    .iter()
+                    .filter_map(|prop| match prop {
+                        ObjectPropertyKind::ObjectProperty(prop) => {
+                            let kind = match prop.kind {
+                                PropertyKind::Get => AccessorKind::Get,
+                                PropertyKind::Set => AccessorKind::Set,
+                                PropertyKind::Init => return None,
+                            };
+                            Some(Accessor {
+                                kind,
+                                key: &prop.key,
+                                is_static: false,
+                                span: prop.span,
+                            })
+                        }
+                        ObjectPropertyKind::SpreadProperty(_) => None,
+                    })
+                    .collect::<Vec<_>>();
+                self.check_list(&accessors, false, ctx);
+                if is_property_descriptor(node.id(), ctx) {
+                    self.check_property_descriptor(object, ctx);
+                }
+            }
+            AstKind::ClassBody(body) if self.enforce_for_class_members => {
+                let accessors = body
+                    .body
+                    .iter()
+                    .filter_map(|element| match element {
+                        ClassElement::MethodDefinition(method)
+                            if method.r#type == MethodDefinitionType::MethodDefinition =>
+                        {
+                            let kind = match method.kind {
+                                MethodDefinitionKind::Get => AccessorKind::Get,
+                                MethodDefinitionKind::Set => AccessorKind::Set,
+                                _ => return None,
+                            };
+                            Some(Accessor {
+                                kind,
+                                key: &method.key,
+                                is_static: method.r#static,
+                                span: method.span,
+                            })
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                let (static_accessors, instance_accessors): (Vec<_>, Vec<_>) =
+                    accessors.into_iter().partition(|accessor| accessor.is_static);
+                self.check_list(&static_accessors, true, ctx);
+                self.check_list(&instance_accessors, true, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+struct Accessor<'a, 'b> {
+    kind: AccessorKind,
+    key: &'b PropertyKey<'a>,
+    is_static: bool,
+    span: Span,
+}
+
+impl AccessorPairs {
+    fn check_list<'a>(
+        &self,
+        accessors: &[Accessor<'a, '_>],
+        in_class: bool,
+        ctx: &LintContext<'a>,
+    ) {
+        let keys = accessors
+            .iter()
+            .map(|accessor| get_accessor_key(accessor.key, ctx))
+            .collect::<Vec<_>>();
+        for (accessor, key) in accessors.iter().zip(&keys) {
+            let has_counterpart = accessors
+                .iter()
+                .zip(&keys)
+                .any(|(other, other_key)| other.kind != accessor.kind && other_key == key);
+            if has_counterpart {
+                continue;
+            }
+            let name = get_accessor_name(accessor, key);
+            match accessor.kind {
+                AccessorKind::Set if self.set_without_get => {
+                    ctx.diagnostic(missing_getter_diagnostic(&name, in_class, accessor.span));
+                }
+                AccessorKind::Get if self.get_without_set => {
+                    ctx.diagnostic(missing_setter_diagnostic(&name, in_class, accessor.span));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Checks descriptors such as `Object.defineProperty(obj, "foo", { set(v) {} })`.
+    fn check_property_descriptor(&self, object: &ObjectExpression, ctx: &LintContext) {
+        let has_key = |name: &str| {
+            object.properties.iter().any(|prop| match prop {
+                ObjectPropertyKind::ObjectProperty(prop) => {
+                    prop.key.static_name().is_some_and(|key| key == name)
+                }
+                ObjectPropertyKind::SpreadProperty(_) => false,
+            })
+        };
+        let (has_get, has_set) = (has_key("get"), has_key("set"));
+        if self.set_without_get && has_set && !has_get {
+            ctx.diagnostic(missing_getter_in_property_descriptor_diagnostic(object.span));
+        } else if self.get_without_set && has_get && !has_set {
+            ctx.diagnostic(missing_setter_in_property_descriptor_diagnostic(object.span));
+        }
+    }
+}
+
+/// Describes the accessor as in ESLint, e.g. `static setter 'foo'`.
+fn get_accessor_name(accessor: &Accessor, key: &AccessorKey) -> String {
+    let static_ = if accessor.is_static { "static " } else { "" };
+    let kind = if accessor.kind == AccessorKind::Get { "getter" } else { "setter" };
+    match key {
+        AccessorKey::Static(key) => format!("{static_}{kind} '{key}'"),
+        AccessorKey::Private(key) => format!("{static_}private {kind} #{key}"),
+        AccessorKey::Computed(_) => format!("{static_}{kind}"),
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var o = { a: 1 };", None),
+        ("var o = { get a() {} };", None),
+        ("var o = { get a() {}, set a(foo) {} };", None),
+        ("var o = { set a(foo) {}, get a() {} };", None),
+        ("var o = { set a(foo) {}, b: 1, get a() {} };", None),
+        ("var o = { get 'a'() {}, set a(foo) {} };", None),
+        ("var o = { get a() {}, set ['a'](foo) {} };", None),
+        ("var o = { get 1() {}, set 1e0(foo) {} };", None),
+        ("var o = { get [a]() {}, set [a](foo) {} };", None),
+        ("var o = { get [a + b]() {}, set [a + b](foo) {} };", None),
+        ("var o = { set a(foo) {} };", Some(serde_json::json!([{ "setWithoutGet": false }]))),
+        (
+            "var o = { set a(foo) {} };",
+            Some(serde_json::json!([{ "setWithoutGet": false, "getWithoutSet": false }])),
+        ),
+        (
+            "var o = { get a() {}, set a(foo) {} };",
+            Some(serde_json::json!([{ "getWithoutSet": true }])),
+        ),
+        ("var o = { a: 1, get b() {} };", Some(serde_json::json!([{ "getWithoutSet": false }]))),
+        ("var o = { set: function(value) {} };", None),
+        ("var o = { set(value) {} };", None),
+        ("Object.defineProperty(o, 'a', { get: function() {}, set: function(v) {} });", None),
+        ("Object.defineProperty(o, 'a', { get() {}, set(v) {} });", None),
+        ("Object.defineProperty(o, 'a', { get: function() {} });", None),
+        ("Object.defineProperty(o, 'a', { value: 1 });", None),
+        ("Reflect.defineProperty(o, 'a', { get() {}, set(v) {} });", None),
+        ("Object.defineProperties(o, { a: { get() {}, set(v) {} } });", None),
+        ("Object.create(null, { a: { get() {}, set(v) {} } });", None),
+        ("foo.defineProperty(o, 'a', { set(v) {} });", None),
+        (
+            "Object.defineProperty(o, 'a', { set(v) {} }, 1);",
+            Some(serde_json::json!([{ "setWithoutGet": false }])),
+        ),
+        ("class A { get a() {} set a(foo) {} }", None),
+        ("class A { static get a() {} static set a(foo) {} }", None),
+        ("class A { get #a() {} set #a(foo) {} }", None),
+        ("class A { get [a]() {} set [a](foo) {} }", None),
+        ("class A { get a() {} }", None),
+        (
+            "class A { set a(foo) {} }",
+            Some(serde_json::json!([{ "enforceForClassMembers": false }])),
+        ),
+        (
+            "(class { set a(foo) {} })",
+            Some(serde_json::json!([{ "enforceForClassMembers": false }])),
+        ),
+        ("class A { set a(foo) {} }", Some(serde_json::json!([{ "setWithoutGet": false }]))),
+        ("class A { a() {} }", Some(serde_json::json!([{ "getWithoutSet": true }]))),
+    ];
+
+    let fail = vec![
+        ("var o = { set a(value) {} };", None),
+        ("var o = { set 'a'(value) {} };", None),
+        ("var o = { set [a](value) {} };", None),
+        ("var o = { set a(value) {}, get b() {} };", None),
+        ("var o = { set a(value) {}, get 'A'() {} };", None),
+        ("var o = { set [a](value) {}, get [b]() {} };", None),
+        ("var o = { set a(value) {}, a: 1 };", None),
+        ("var o = { get a() {} };", Some(serde_json::json!([{ "getWithoutSet": true }]))),
+        (
+            "var o = { get a() {}, set b(foo) {} };",
+            Some(serde_json::json!([{ "getWithoutSet": true }])),
+        ),
+        ("var o = { set a(value) {}, ...a };", None),
+        ("var o = { a: { set b(value) {} } };", None),
+        ("Object.defineProperty(o, 'a', { set: function(value) {} });", None),
+        ("Object.defineProperty(o, 'a', { set(value) {} });", None),
+        ("Object.defineProperty(o, 'a', { 'set': function(value) {} });", None),
+        ("Reflect.defineProperty(o, 'a', { set(value) {} });", None),
+        ("Object.defineProperties(o, { a: { set(value) {} } });", None),
+        ("Object.create(null, { a: { set(value) {} } });", None),
+        (
+            "Object.defineProperty(o, 'a', { get() {} });",
+            Some(serde_json::json!([{ "getWithoutSet": true }])),
+        ),
+        ("class A { set a(value) {} }", None),
+        ("(class { set a(value) {} })", None),
+        ("class A { static set a(value) {} }", None),
+        ("class A { set #a(value) {} }", None),
+        ("class A { set [a](value) {} }", None),
+        ("class A { set a(value) {} static get a() {} }", None),
+        ("class A { static set a(value) {} get a() {} }", None),
+        ("class A { set #a(value) {} get a() {} }", None),
+        ("class A { get a() {} }", Some(serde_json::json!([{ "getWithoutSet": true }]))),
+        (
+            "class A { static get a() {} set a(value) {} }",
+            Some(serde_json::json!([{ "getWithoutSet": true }])),
+        ),
+    ];
+
+    Tester::new(AccessorPairs::NAME, pass, fail).test_and_snapshot();
+}
@@ -98,6 +98,8 @@ fn test() {
         r"switch (foo) { case 1: break; case 2: default: break; }",
         r"switch (foo) { case 1: break; case 2: default: }",
         r"switch (foo) { case 1: case 2: default: }",
+        r"switch (foo) { case 1: switch (bar) { case 2: default: } break; default: }",
+        r"switch (foo) { default: switch (bar) { default: } }",
     ];
 
     let fail = vec![
@@ -115,6 +117,9 @@ fn test() {
         r"switch (foo) { case 1: break; default: case 2: break; }",
         r"switch (foo) { case 1: default: case 2: break; }",
         r"switch (foo) { case 1: default: case 2: }",
+        r"switch (foo) { case 1: switch (bar) { default: case 2: } break; default: }",
+        r"switch (foo) { default: switch (bar) { case 1: default: } case 2: }",
+        r"function f() { switch (foo) { default: return; case 1: return 1; } }",
     ];
 
     Tester::new(DefaultCaseLast::NAME, pass, fail).test_and_snapshot();
@@ -1,8 +1,5 @@
 use oxc_ast::{
-    ast::{
-        match_member_expression, ChainElement, Expression, MemberExpression, MethodDefinitionKind,
-        ObjectProperty, PropertyKind,
-    },
+    ast::{MethodDefinitionKind, PropertyKind},
     AstKind,
 };
 use oxc_cfg::{
@@ -14,9 +11,9 @@ use oxc_cfg::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{ast_util::is_property_descriptor, context::LintContext, rule::Rule, AstNode};
 
 fn getter_return_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Expected to always return a value in getter.")
@@ -29,13 +26,6 @@ pub struct GetterReturn {
     pub allow_implicit: bool,
 }
 
-const METHODS_TO_WATCH_FOR: [(&str, &str); 4] = [
-    ("Object", "defineProperty"),
-    ("Reflect", "defineProperty"),
-    ("Object", "create"),
-    ("Object", "defineProperties"),
-];
-
 declare_oxc_lint!(
     /// ### What it does
     /// Requires all getters to have a return statement
@@ -84,103 +74,28 @@ impl Rule for GetterReturn {
 }
 
 impl GetterReturn {
-    fn handle_member_expression<'a>(member_expression: &'a MemberExpression<'a>) -> bool {
-        for (a, b) in METHODS_TO_WATCH_FOR {
-            if member_expression.through_optional_is_specific_member_access(a, b) {
-                return true;
-            }
-        }
-
-        false
-    }
-
-    fn handle_actual_expression<'a>(callee: &'a Expression<'a>) -> bool {
-        match callee.without_parenthesized() {
-            expr @ match_member_expression!(Expression) => {
-                Self::handle_member_expression(expr.to_member_expression())
-            }
-            Expression::ChainExpression(ce) => match &ce.expression {
-                match_member_expression!(ChainElement) => {
-                    Self::handle_member_expression(ce.expression.to_member_expression())
-                }
-                ChainElement::CallExpression(_) => {
-                    false // todo: make a test for this
-                }
-            },
-            _ => false,
-        }
-    }
-
-    fn handle_paren_expr<'a>(expr: &'a Expression<'a>) -> bool {
-        match expr.without_parenthesized() {
-            Expression::CallExpression(ce) => Self::handle_actual_expression(&ce.callee),
-            _ => false,
-        }
-    }
-
     /// Checks whether it is necessary to check the node
     fn is_wanted_node(node: &AstNode, ctx: &LintContext<'_>) -> bool {
-        if let Some(parent) = ctx.nodes().parent_node(node.id()) {
-            match parent.kind() {
-                AstKind::MethodDefinition(mdef) => {
-                    if matches!(mdef.kind, MethodDefinitionKind::Get) {
-                        return true;
-                    }
-                }
-                AstKind::ObjectProperty(ObjectProperty { kind, key: prop_key, .. }) => {
-                    if matches!(kind, PropertyKind::Get) {
-                        return true;
-                    }
-                    if prop_key.name().is_some_and(|key| key != "get") {
-                        return false;
-                    }
-
-                    if let Some(parent_2) = ctx.nodes().parent_node(parent.id()) {
-                        if let Some(parent_3) = ctx.nodes().parent_node(parent_2.id()) {
-                            if let Some(parent_4) = ctx.nodes().parent_node(parent_3.id()) {
-                                // handle (X())
-                                match parent_4.kind() {
-                                    AstKind::ParenthesizedExpression(p) => {
-                                        if Self::handle_paren_expr(&p.expression) {
-                                            return true;
-                                        }
-                                    }
-                                    AstKind::CallExpression(ce) => {
-                                        if Self::handle_actual_expression(&ce.callee) {
-                                            return true;
-                                        }
-                                    }
-                                    _ => {}
-                                }
-
-                                if let Some(parent_5) = ctx.nodes().parent_node(parent_4.id()) {
-                                    if let Some(parent_6) = ctx.nodes().parent_node(parent_5.id()) {
-                                        match parent_6.kind() {
-                                            AstKind::ParenthesizedExpression(p) => {
-                                                if Self::handle_paren_expr(&p.expression) {
-                                                    return true;
-                                                }
-                                            }
-                                            AstKind::CallExpression(ce) => {
-                                                if Self::handle_actual_expression(&ce.callee) {
-                                                    return true;
-                                                }
-                                            }
-                                            _ => {
-                                                return false;
-                                            }
-                                        };
-                                    }
-                                }
-                            }
-                        }
-                    }
+        let Some(parent) = ctx.nodes().parent_node(node.id()) else {
+            return false;
+        };
+        match parent.kind() {
+            AstKind::MethodDefinition(mdef) => matches!(mdef.kind, MethodDefinitionKind::Get),
+            AstKind::ObjectProperty(prop) => match prop.kind {
+                PropertyKind::Get => true,
+                // Object.defineProperty(foo, 'bar', { get() {} })
+                PropertyKind::Init => {
+                    prop.key.is_specific_static_name("get")
+                        && prop.value.span() == node.kind().span()
+                        && ctx
+                            .nodes()
+                            .parent_node(parent.id())
+                            .is_some_and(|obj| is_property_descriptor(obj.id(), ctx))
                 }
-                _ => {}
-            }
+                PropertyKind::Set => false,
+            },
+            _ => false,
         }
-
-        false
     }
 
     fn run_diagnostic<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>, span: Span) {
@@ -329,6 +244,11 @@ fn test() {
         ("foo.defineProperty(null, { get() {} });", None),
         ("foo.defineProperties(null, { bar: { get() {} } });", None),
         ("foo.create(null, { bar: { get() {} } });", None),
+        ("Object.defineProperty(foo, 'bar', { baz: { get() {} } });", None),
+        ("Object.defineProperty(foo, { get() {} }, 'bar');", None),
+        ("Object.defineProperties(foo, { get() {} });", None),
+        ("var foo = { [get]: function() {} };", None),
+        ("let Object; Object.defineProperty(foo, 'bar', { get() {} });", None),
         ("var foo = { get willThrowSoValid() { throw MyException() } };", None),
         (
             "const originalClearTimeout = targetWindow.clearTimeout;
@@ -0,0 +1,312 @@
+use oxc_ast::{
+    ast::{
+        ClassElement, MethodDefinitionKind, MethodDefinitionType, ObjectPropertyKind, PropertyKey,
+        PropertyKind,
+    },
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_accessor_key, AccessorKey, AccessorKind},
+    AstNode,
+};
+
+fn not_grouped_diagnostic(x0: &str, x1: &str, span2: Span, span3: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Accessor pair {x0} and {x1} should be grouped."))
+        .with_labels([span2.label(format!("{x0} is defined here")), span3.into()])
+}
+
+fn invalid_order_diagnostic(x0: &str, x1: &str, span2: Span, span3: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Expected {x1} to be before {x0}."))
+        .with_labels([span2.label(format!("{x0} is defined here")), span3.into()])
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum PairOrder {
+    #[default]
+    AnyOrder,
+    GetBeforeSet,
+    SetBeforeGet,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GroupedAccessorPairs {
+    order: PairOrder,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require grouped accessor pairs in object literals and classes.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A getter and setter for the same property don't necessarily have to be defined
+    /// adjacent to each other, but it's easier to read the code when they are.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// const foo = {
+    ///     get a() {
+    ///         return this.val;
+    ///     },
+    ///     b: 1,
+    ///     set a(value) {
+    ///         this.val = value;
+    ///     },
+    /// };
+    ///
+    /// class Foo {
+    ///     set a(value) {
+    ///         this.val = value;
+    ///     }
+    ///     b() {}
+    ///     get a() {
+    ///         return this.val;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// A string option sets the order of the pairs:
+    /// - `"anyOrder"` (default) doesn't enforce an order.
+    /// - `"getBeforeSet"` requires the getter to be defined before the setter.
+    /// - `"setBeforeGet"` requires the setter to be defined before the getter.
+    GroupedAccessorPairs,
+    style
+);
+
+impl Rule for GroupedAccessorPairs {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let order = match value.get(0).and_then(serde_json::Value::as_str) {
+            Some("getBeforeSet") => PairOrder::GetBeforeSet,
+            Some("setBeforeGet") => PairOrder::SetBeforeGet,
+            _ => PairOrder::AnyOrder,
+        };
+        Self { order }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ObjectExpression(object) => {
+                let members = object
+                    .properties
+                    .iter()
+                    .map(|prop| match prop {
+                        ObjectPropertyKind::ObjectProperty(prop) => {
+                            let kind = match prop.kind {
+                                PropertyKind::Get => Some(AccessorKind::Get),
+                                PropertyKind::Set => Some(AccessorKind::Set),
+                                PropertyKind::Init => None,
+                            };
+                            Member { kind, key: Some(&prop.key), is_static: false, span: prop.span }
+                        }
+                        ObjectPropertyKind::SpreadProperty(spread) => {
+                            Member { kind: None, key: None, is_static: false, span: spread.span }
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                self.check_list(&members, ctx);
+            }
+            AstKind::ClassBody(body) => {
+                // Only methods count as members between accessors, as in ESLint
+                let methods = body
+                    .body
+                    .iter()
+                    .filter_map(|element| match element {
+                        ClassElement::MethodDefinition(method)
+                            if method.r#type == MethodDefinitionType::MethodDefinition =>
+                        {
+                            let kind = match method.kind {
+                                MethodDefinitionKind::Get => Some(AccessorKind::Get),
+                                MethodDefinitionKind::Set => Some(AccessorKind::Set),
+                                _ => None,
+                            };
+                            Some(Member {
+                                kind,
+                                key: Some(&method.key),
+                                is_static: method.r#static,
+                                span: method.span,
+                            })
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                let (static_methods, instance_methods): (Vec<_>, Vec<_>) =
+                    methods.into_iter().partition(|method| method.is_static);
+                self.check_list(&static_methods, ctx);
+                self.check_list(&instance_methods, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+struct Member<'a, 'b> {
+    kind: Option<AccessorKind>,
+    key: Option<&'b PropertyKey<'a>>,
+    is_static: bool,
+    span: Span,
+}
+
+struct AccessorPair<'a> {
+    key: AccessorKey<'a>,
+    getters: Vec<usize>,
+    setters: Vec<usize>,
+}
+
+impl GroupedAccessorPairs {
+    fn check_list<'a>(&self, members: &[Member<'a, '_>], ctx: &LintContext<'a>) {
+        let mut pairs: Vec<AccessorPair> = vec![];
+        for (index, member) in members.iter().enumerate() {
+            let (Some(kind), Some(key)) = (member.kind, member.key) else {
+                continue;
+            };
+            let key = get_accessor_key(key, ctx);
+            let pair = if let Some(pair) = pairs.iter_mut().find(|pair| pair.key == key) {
+                pair
+            } else {
+                pairs.push(AccessorPair { key, getters: vec![], setters: vec![] });
+                pairs.last_mut().unwrap()
+            };
+            match kind {
+                AccessorKind::Get => pair.getters.push(index),
+                AccessorKind::Set => pair.setters.push(index),
+            }
+        }
+
+        for pair in pairs {
+            // Duplicate accessors are reported by `no-dupe-keys` and `no-dupe-class-members`
+            let ([getter], [setter]) = (pair.getters.as_slice(), pair.setters.as_slice()) else {
+                continue;
+            };
+            let (former, latter) =
+                if getter < setter { (*getter, *setter) } else { (*setter, *getter) };
+            let former_name = get_accessor_name(&members[former], ctx);
+            let latter_name = get_accessor_name(&members[latter], ctx);
+            let (former_span, latter_span) = (members[former].span, members[latter].span);
+            if latter - former > 1 {
+                ctx.diagnostic(not_grouped_diagnostic(
+                    &former_name,
+                    &latter_name,
+                    former_span,
+                    latter_span,
+                ));
+            } else if (self.order == PairOrder::GetBeforeSet && getter > setter)
+                || (self.order == PairOrder::SetBeforeGet && getter < setter)
+            {
+                ctx.diagnostic(invalid_order_diagnostic(
+                    &former_name,
+                    &latter_name,
+                    former_span,
+                    latter_span,
+                ));
+            }
+        }
+    }
+}
+
+/// Describes the accessor as in ESLint, e.g. `static getter 'foo'`.
+fn get_accessor_name<'a>(member: &Member<'a, '_>, ctx: &LintContext<'a>) -> String {
+    let mut name = String::new();
+    if member.is_static {
+        name.push_str("static ");
+    }
+    let key = member.key.map(|key| get_accessor_key(key, ctx));
+    if matches!(key, Some(AccessorKey::Private(_))) {
+        name.push_str("private ");
+    }
+    name.push_str(if member.kind == Some(AccessorKind::Get) { "getter" } else { "setter" });
+    match key {
+        Some(AccessorKey::Static(key)) => name.push_str(&format!(" '{key}'")),
+        Some(AccessorKey::Private(key)) => name.push_str(&format!(" #{key}")),
+        _ => {}
+    }
+    name
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("({})", None),
+        ("({ a })", None),
+        ("({ a(){}, b(){}, a(){} })", None),
+        ("({ a: 1, b: 2 })", None),
+        ("({ a, ...b, c: 1 })", None),
+        ("({ get a(){} })", None),
+        ("({ set a(foo){} })", None),
+        ("({ a: 1, get a(){} })", None),
+        ("({ get a(){}, a: 1 })", None),
+        ("({ get a(){}, b: 1, set b(foo){} })", None),
+        ("({ get a(){}, set a(foo){} })", None),
+        ("({ set a(foo){}, get a(){} })", None),
+        ("({ get a(){}, set a(foo){}, b: 1 })", None),
+        ("({ b: 1, get a(){}, set a(foo){} })", None),
+        ("({ get a(){}, set a(foo){}, get b(){}, set b(bar){} })", None),
+        ("({ get a(){}, b: 1, set a(foo){}, set a(bar){} })", None),
+        ("({ get a(){}, get a(){}, b: 1, set a(foo){} })", None),
+        ("({ get 'a'(){}, set a(foo){} })", None),
+        ("({ get a(){}, set 'a'(foo){} })", None),
+        ("({ get ['a'](){}, set a(foo){} })", None),
+        ("({ get [a](){}, set [a](foo){} })", None),
+        ("({ get [a + b](){}, set [a + b](foo){} })", None),
+        ("({ get 1(){}, set 1e0(foo){} })", None),
+        ("class A {}", None),
+        ("class A { get a(){} }", None),
+        ("class A { get a(){} set a(foo){} }", None),
+        ("class A { set a(foo){} get a(){} }", None),
+        ("class A { static get a(){} static set a(foo){} }", None),
+        ("class A { get a(){} b(){} static set a(foo){} }", None),
+        ("class A { get a(){} static b(){} set a(foo){} }", None),
+        ("class A { static get a(){} b(){} static set a(foo){} }", None),
+        ("class A { get a(){} b = 1; set a(foo){} }", None),
+        ("class A { get #a(){} set #a(foo){} }", None),
+        ("class A { get #a(){} b(){} set a(foo){} }", None),
+        ("class A { get a(){} b(){} get a(){} set a(foo){} }", None),
+        ("({ get a(){}, set a(foo){} })", Some(serde_json::json!(["anyOrder"]))),
+        ("({ set a(foo){}, get a(){} })", Some(serde_json::json!(["anyOrder"]))),
+        ("({ get a(){}, set a(foo){} })", Some(serde_json::json!(["getBeforeSet"]))),
+        ("({ set a(foo){}, get a(){} })", Some(serde_json::json!(["setBeforeGet"]))),
+        ("class A { get a(){} set a(foo){} }", Some(serde_json::json!(["getBeforeSet"]))),
+        ("class A { set a(foo){} get a(){} }", Some(serde_json::json!(["setBeforeGet"]))),
+        ("({ get a(){}, b: 1, get a(){} })", Some(serde_json::json!(["getBeforeSet"]))),
+    ];
+
+    let fail = vec![
+        ("({ get a(){}, b:1, set a(foo){} })", None),
+        ("({ set a(foo){}, b:1, get a(){} })", None),
+        ("({ get a(){}, b(){}, set a(foo){} })", None),
+        ("({ get a(){}, ...b, set a(foo){} })", None),
+        ("({ get a(){}, c: 1, set a(foo){}, get b(){}, d: 1, set b(foo){} })", None),
+        ("({ get a(){}, set b(foo){}, set a(foo){}, get b(){} })", None),
+        ("({ get 'a'(){}, b: 1, set a(foo){} })", None),
+        ("({ get ['a'](){}, b: 1, set a(foo){} })", None),
+        ("({ get [a](){}, b: 1, set [a](foo){} })", None),
+        ("({ get 1(){}, b: 1, set 1e0(foo){} })", None),
+        ("class A { get a(){} b(){} set a(foo){} }", None),
+        ("class A { set a(foo){} b(){} get a(){} }", None),
+        ("class A { static get a(){} static b(){} static set a(foo){} }", None),
+        ("class A { get #a(){} b(){} set #a(foo){} }", None),
+        ("class A { get [a](){} b(){} set [a](foo){} }", None),
+        ("(class { get a(){} b(){} set a(foo){} })", None),
+        ("({ set a(foo){}, get a(){} })", Some(serde_json::json!(["getBeforeSet"]))),
+        ("({ get a(){}, set a(foo){} })", Some(serde_json::json!(["setBeforeGet"]))),
+        ("class A { set a(foo){} get a(){} }", Some(serde_json::json!(["getBeforeSet"]))),
+        (
+            "class A { static get a(){} static set a(foo){} }",
+            Some(serde_json::json!(["setBeforeGet"])),
+        ),
+        ("({ get a(){}, b: 1, set a(foo){} })", Some(serde_json::json!(["getBeforeSet"]))),
+        ("({ set a(foo){}, b: 1, get a(){} })", Some(serde_json::json!(["getBeforeSet"]))),
+    ];
+
+    Tester::new(GroupedAccessorPairs::NAME, pass, fail).test_and_snapshot();
+}
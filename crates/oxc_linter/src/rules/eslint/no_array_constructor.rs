@@ -1,9 +1,17 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    ast_util::{is_global_reference, needs_leading_semicolon},
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
 
 fn no_array_constructor_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Disallow `Array` constructors")
@@ -51,16 +59,55 @@ impl Rule for NoArrayConstructor {
             _ => return,
         };
 
-        if callee.is_specific_id("Array")
-            && arguments.len() != 1
-            && type_parameters.is_none()
-            && !optional
+        let Expression::Identifier(ident) = callee else {
+            return;
+        };
+        if ident.name != "Array" || !is_global_reference(ident, ctx) {
+            return;
+        }
+        // A single argument may be the length of a sparse array, unless it can't be a number
+        if type_parameters.is_some()
+            || optional
+            || matches!(arguments.as_slice(), [arg] if !is_non_numeric(arg))
+        {
+            return;
+        }
+
+        let diagnostic = no_array_constructor_diagnostic(span);
+        // Spread arguments may expand to a single length argument, e.g. `new Array(...[3])`
+        if ctx.semantic().trivias().has_comments_between(span)
+            || arguments.iter().any(Argument::is_spread)
         {
-            ctx.diagnostic(no_array_constructor_diagnostic(span));
+            ctx.diagnostic(diagnostic);
+            return;
         }
+        let elements = match (arguments.first(), arguments.last()) {
+            (Some(first), Some(last)) => {
+                ctx.source_range(Span::new(first.span().start, last.span().end))
+            }
+            _ => "",
+        };
+        let fixed = if needs_leading_semicolon(node, ctx) {
+            format!(";[{elements}]")
+        } else {
+            format!("[{elements}]")
+        };
+        ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(span, fixed));
     }
 }
 
+fn is_non_numeric(arg: &Argument) -> bool {
+    matches!(
+        arg,
+        Argument::StringLiteral(_)
+            | Argument::TemplateLiteral(_)
+            | Argument::BooleanLiteral(_)
+            | Argument::NullLiteral(_)
+            | Argument::ArrayExpression(_)
+            | Argument::ObjectExpression(_)
+    )
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -90,6 +137,9 @@ fn test() {
         ("Array?.<Foo>();", None),
         ("Array?.(0, 1, 2);", None),
         ("Array?.(x, y);", None),
+        ("new Array(...args)", None),
+        ("var Array; new Array(0, 1, 2)", None),
+        ("function foo(Array) { return Array(); }", None),
     ];
 
     let fail = vec![
@@ -100,7 +150,33 @@ fn test() {
         ("new Array(0, 1, 2)", None),
         ("Array(x, y)", None),
         ("Array(0, 1, 2)", None),
+        ("new Array('foo')", None),
+        ("Array(`foo`)", None),
+        ("new Array(true)", None),
+        ("new Array([1, 2])", None),
+        ("Array({})", None),
+        ("const a = () => Array(a, b)", None),
+        ("foo\nArray(0, 1)", None),
+        ("new Array(/* comment */ 0, 1)", None),
+    ];
+
+    let fix = vec![
+        ("new Array()", "[]"),
+        ("new Array", "[]"),
+        ("Array();", "[];"),
+        ("new Array(x, y)", "[x, y]"),
+        ("Array(0, 1, 2)", "[0, 1, 2]"),
+        ("new Array(\n  0,\n  1,\n)", "[0,\n  1]"),
+        ("new Array('foo')", "['foo']"),
+        ("const a = () => Array(a, b)", "const a = () => [a, b]"),
+        ("const a = () => { Array(a, b) }", "const a = () => { [a, b] }"),
+        ("foo;\nArray(0, 1)", "foo;\n[0, 1]"),
+        ("foo\nArray(0, 1)", "foo\n;[0, 1]"),
+        ("const k = function () {}\nArray(0, 1)", "const k = function () {}\n;[0, 1]"),
+        ("foo()\nnew Array(0, 1).forEach(bar)", "foo()\n;[0, 1].forEach(bar)"),
+        // not fixed
+        ("new Array(/* comment */ 0, 1)", "new Array(/* comment */ 0, 1)"),
     ];
 
-    Tester::new(NoArrayConstructor::NAME, pass, fail).test_and_snapshot();
+    Tester::new(NoArrayConstructor::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
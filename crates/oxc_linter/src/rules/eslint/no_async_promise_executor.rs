@@ -9,7 +9,11 @@ use oxc_span::Span;
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 fn no_async_promise_executor_diagnostic(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("Promise executor functions should not be `async`.").with_label(span0)
+    OxcDiagnostic::warn("Promise executor functions should not be `async`.")
+        .with_help(
+            "Remove `async`, and move any awaited calls outside of the `new Promise` constructor.",
+        )
+        .with_label(span0)
 }
 
 #[derive(Debug, Default, Clone)]
@@ -78,12 +82,20 @@ fn test() {
         ("new Promise((resolve, reject) => {})", None),
         ("new Promise((resolve, reject) => {}, async function unrelated() {})", None),
         ("new Foo(async (resolve, reject) => {})", None),
+        ("new Promise(function (resolve, reject) {})", None),
+        ("new Promise(executor)", None),
+        ("new Promise(async)", None),
+        ("new foo.Promise(async (resolve, reject) => {})", None),
+        ("Promise(async (resolve, reject) => {})", None),
+        ("new Promise(() => async () => {})", None),
     ];
 
     let fail = vec![
         ("new Promise(async function foo(resolve, reject) {})", None),
         ("new Promise(async (resolve, reject) => {})", None),
         ("new Promise(((((async () => {})))))", None),
+        ("new Promise<void>(async (resolve) => {})", None),
+        ("new Promise(async function (resolve, reject) { await foo(); })", None),
     ];
 
     Tester::new(NoAsyncPromiseExecutor::NAME, pass, fail).test_and_snapshot();
@@ -4,7 +4,7 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -24,21 +24,32 @@ declare_oxc_lint!(
     /// in the entire switch block but it only gets initialized when it is assigned,
     /// which will only happen if the case where it is defined is reached.
     ///
+    /// Wrapping the clause in a block limits the scope of the declaration to that
+    /// clause, which is what the suggested fix does.
+    ///
     /// ### Example
     /// ```javascript
-    // switch (foo) {
-    //   case 1:
-    //       let x = 1;
-    //       break;
-    //   case 2:
-    //       const y = 2;
-    //       break;
-    //   case 3:
-    //       function f() {}
-    //       break;
-    //   default:
-    //       class C {}
-    // }
+    /// switch (foo) {
+    ///   case 1:
+    ///       let x = 1;
+    ///       break;
+    ///   case 2:
+    ///       const y = 2;
+    ///       break;
+    ///   case 3:
+    ///       function f() {}
+    ///       break;
+    ///   default:
+    ///       class C {}
+    /// }
+    ///
+    /// // Good
+    /// switch (foo) {
+    ///   case 1: {
+    ///       let x = 1;
+    ///       break;
+    ///   }
+    /// }
     /// ```
     NoCaseDeclarations,
     pedantic
@@ -50,29 +61,33 @@ impl Rule for NoCaseDeclarations {
             let consequent = &switch_case.consequent;
 
             for stmt in consequent {
-                match stmt {
+                let span = match stmt {
                     Statement::FunctionDeclaration(d) => {
-                        let start = d.span.start;
-                        let end = start + 8;
-                        ctx.diagnostic(no_case_declarations_diagnostic(Span::new(start, end)));
-                    }
-                    Statement::ClassDeclaration(d) => {
-                        let start = d.span.start;
-                        let end = start + 5;
-                        ctx.diagnostic(no_case_declarations_diagnostic(Span::new(start, end)));
+                        // Label up to the `function` keyword, e.g. `async function`
+                        let end = ctx.source_range(d.span).find("function").unwrap_or(0) + 8;
+                        #[allow(clippy::cast_possible_truncation)]
+                        Span::new(d.span.start, d.span.start + end as u32)
                     }
+                    Statement::ClassDeclaration(d) => Span::new(d.span.start, d.span.start + 5),
                     Statement::VariableDeclaration(var) if var.kind.is_lexical() => {
-                        let start = var.span.start;
                         let end = match var.kind {
                             VariableDeclarationKind::Var => unreachable!(),
                             VariableDeclarationKind::Const => 5,
                             VariableDeclarationKind::Let => 3,
                         };
-                        let end = start + end;
-                        ctx.diagnostic(no_case_declarations_diagnostic(Span::new(start, end)));
+                        Span::new(var.span.start, var.span.start + end)
                     }
-                    _ => {}
+                    _ => continue,
                 };
+                ctx.diagnostic_with_suggestion(no_case_declarations_diagnostic(span), |fixer| {
+                    let (Some(first), Some(last)) = (consequent.first(), consequent.last()) else {
+                        return fixer.noop();
+                    };
+                    let block = Span::new(first.span().start, last.span().end);
+                    fixer
+                        .replace(block, format!("{{ {} }}", ctx.source_range(block)))
+                        .with_message("Add {} brackets around the case block.")
+                });
             }
         }
     }
@@ -90,6 +105,11 @@ fn test() {
             None,
         ),
         ("switch (a) { case 1: { class C {} break; } default: { class C {} break; } }", None),
+        ("switch (a) { case 1: var x = 1; break; }", None),
+        ("switch (a) { case 1: x = 1; break; }", None),
+        ("switch (a) { case 1: if (b) { let x = 1; } break; }", None),
+        ("switch (a) { case 1: for (let i = 0; i < 1; i++) {} break; }", None),
+        ("switch (a) { case 1: { switch (b) { case 2: { const x = 2; } } } }", None),
     ];
 
     let fail = vec![
@@ -101,6 +121,10 @@ fn test() {
         ("switch (a) { default: function f() {} break; }", None),
         ("switch (a) { case 1: class C {} break; }", None),
         ("switch (a) { default: class C {} break; }", None),
+        ("switch (a) { case 1: async function f() {} break; }", None),
+        ("switch (a) { case 1: function* f() {} break; }", None),
+        ("switch (a) { case 1: let x = 1; const y = 2; break; }", None),
+        ("switch (a) { case 1: { switch (b) { case 2: let x = 2; } } }", None),
     ];
 
     Tester::new(NoCaseDeclarations::NAME, pass, fail).test_and_snapshot();
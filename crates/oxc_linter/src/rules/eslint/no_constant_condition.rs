@@ -1,7 +1,11 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    ast::{ArrowFunctionExpression, Expression, Function, YieldExpression},
+    AstKind, Visit,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use oxc_syntax::scope::ScopeFlags;
 
 use crate::{ast_util::IsConstant, context::LintContext, rule::Rule, AstNode};
 
@@ -11,9 +15,15 @@ fn no_constant_condition_diagnostic(span0: Span) -> OxcDiagnostic {
         .with_label(span0)
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct NoConstantCondition {
-    _check_loops: bool,
+    check_loops: bool,
+}
+
+impl Default for NoConstantCondition {
+    fn default() -> Self {
+        Self { check_loops: true }
+    }
 }
 
 declare_oxc_lint!(
@@ -31,7 +41,19 @@ declare_oxc_lint!(
     /// if (false) {
     ///    doSomethingUnfinished();
     /// }
+    ///
+    /// while (true) {
+    ///    doSomethingForever();
+    /// }
     /// ```
+    ///
+    /// Loops in generator functions are allowed to have a constant condition
+    /// if their body contains a `yield`.
+    ///
+    /// ### Options
+    ///
+    /// `checkLoops` (default `true`): set to `false` to allow constant
+    /// conditions in `while`, `do...while` and `for` loops.
     NoConstantCondition,
     correctness
 );
@@ -41,10 +63,10 @@ impl Rule for NoConstantCondition {
         let obj = value.get(0);
 
         Self {
-            _check_loops: obj
+            check_loops: obj
                 .and_then(|v| v.get("checkLoops"))
                 .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
+                .unwrap_or(true),
         }
     }
 
@@ -60,14 +82,74 @@ impl Rule for NoConstantCondition {
                     ctx.diagnostic(no_constant_condition_diagnostic(condition_expr.test.span()));
                 }
             }
+            AstKind::WhileStatement(stmt) if self.check_loops => {
+                Self::check_loop(&stmt.test, ctx, |finder| {
+                    finder.visit_expression(&stmt.test);
+                    finder.visit_statement(&stmt.body);
+                });
+            }
+            AstKind::DoWhileStatement(stmt) if self.check_loops => {
+                Self::check_loop(&stmt.test, ctx, |finder| {
+                    finder.visit_statement(&stmt.body);
+                    finder.visit_expression(&stmt.test);
+                });
+            }
+            AstKind::ForStatement(stmt) if self.check_loops => {
+                let Some(test) = &stmt.test else {
+                    return;
+                };
+                // A `yield` in the initializer runs once, before the loop starts
+                Self::check_loop(test, ctx, |finder| {
+                    finder.visit_expression(test);
+                    if let Some(update) = &stmt.update {
+                        finder.visit_expression(update);
+                    }
+                    finder.visit_statement(&stmt.body);
+                });
+            }
             _ => {}
         }
     }
 }
 
+impl NoConstantCondition {
+    fn check_loop<'a>(
+        test: &Expression<'a>,
+        ctx: &LintContext<'a>,
+        visit_loop: impl FnOnce(&mut YieldFinder),
+    ) {
+        if !test.is_constant(true, ctx) {
+            return;
+        }
+        // Generators commonly loop forever, yielding a value on each iteration
+        let mut finder = YieldFinder::default();
+        visit_loop(&mut finder);
+        if !finder.found {
+            ctx.diagnostic(no_constant_condition_diagnostic(test.span()));
+        }
+    }
+}
+
+/// Finds a `yield` belonging to the function enclosing the visited nodes.
+#[derive(Default)]
+struct YieldFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for YieldFinder {
+    fn visit_yield_expression(&mut self, _it: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
+    use serde_json::json;
 
     let pass = vec![
         ("if(a);", None),
@@ -196,30 +278,29 @@ fn test() {
         ("`foo${a}` === a ? 1 : 2", None),
         ("tag`a` === a ? 1 : 2", None),
         ("tag`${a}` === a ? 1 : 2", None),
-        //TODO
-        // ("while(~!a);", None),
-        // ("while(a = b);", None),
-        // ("while(`${a}`);", None),
-        // ("for(;x < 10;);", None),
-        // ("for(;;);", None),
-        // ("for(;`${a}`;);", None),
-        // ("do{ }while(x)", None),
-        // ("while(x += 3) {}", None),
-        // ("while(tag`a`) {}", None),
-        // ("while(tag`${a}`) {}", None),
-        // ("while(`\\\n${a}`) {}", None),
-        // ("while(true);", Some(json!([{"checkLoops":false}]))),
-        // ("for(;true;);", Some(json!([{"checkLoops":false}]))),
-        // ("do{}while(true)", Some(json!([{"checkLoops":false}]))),
-        // ("function* foo(){while(true){yield 'foo';}}", None),
-        // ("function* foo(){for(;true;){yield 'foo';}}", None),
-        // ("function* foo(){do{yield 'foo';}while(true)}", None),
-        // ("function* foo(){while (true) { while(true) {yield;}}}", None),
-        // ("function* foo() {for (; yield; ) {}}", None),
-        // ("function* foo() {for (; ; yield) {}}", None),
-        // ("function* foo() {while (true) {function* foo() {yield;}yield;}}", None),
-        // ("function* foo() { for (let x = yield; x < 10; x++) {yield;}yield;}", None),
-        // ("function* foo() { for (let x = yield; ; x++) { yield; }}", None),
+        ("while(~!a);", None),
+        ("while(a = b);", None),
+        ("while(`${a}`);", None),
+        ("for(;x < 10;);", None),
+        ("for(;;);", None),
+        ("for(;`${a}`;);", None),
+        ("do{ }while(x)", None),
+        ("while(x += 3) {}", None),
+        ("while(tag`a`) {}", None),
+        ("while(tag`${a}`) {}", None),
+        ("while(`\\\n${a}`) {}", None),
+        ("while(true);", Some(json!([{"checkLoops":false}]))),
+        ("for(;true;);", Some(json!([{"checkLoops":false}]))),
+        ("do{}while(true)", Some(json!([{"checkLoops":false}]))),
+        ("function* foo(){while(true){yield 'foo';}}", None),
+        ("function* foo(){for(;true;){yield 'foo';}}", None),
+        ("function* foo(){do{yield 'foo';}while(true)}", None),
+        ("function* foo(){while (true) { while(true) {yield;}}}", None),
+        ("function* foo() {for (; yield; ) {}}", None),
+        ("function* foo() {for (; ; yield) {}}", None),
+        ("function* foo() {while (true) {function* foo() {yield;}yield;}}", None),
+        ("function* foo() { for (let x = yield; x < 10; x++) {yield;}yield;}", None),
+        ("function* foo() { for (let x = yield; ; x++) { yield; }}", None),
     ];
 
     let fail = vec![
@@ -339,40 +420,40 @@ fn test() {
         ("`` ? 1 : 2;", None),
         ("`foo` ? 1 : 2;", None),
         ("`foo${bar}` ? 1 : 2;", None),
-        // TODO
-        // ("for(;true;);", None),
-        // ("for(;``;);", None),
-        // ("for(;`foo`;);", None),
-        // ("for(;`foo${bar}`;);", None),
-        // ("do{}while(true)", None),
-        // ("do{}while('1')", None),
-        // ("do{}while(0)", None),
-        // ("do{}while(t = -2)", None),
-        // ("do{}while(``)", None),
-        // ("do{}while(`foo`)", None),
-        // ("do{}while(`foo${bar}`)", None),
-        // ("while([]);", None),
-        // ("while(~!0);", None),
-        // ("while(x = 1);", None),
-        // ("while(function(){});", None),
-        // ("while(true);", None),
-        // ("while(1);", None),
-        // ("while(() => {});", None),
-        // ("while(`foo`);", None),
-        // ("while(``);", None),
-        // ("while(`${'foo'}`);", None),
-        // ("while(`${'foo' + 'bar'}`);", None),
-        // ("function* foo(){while(true){} yield 'foo';}", None),
-        // ("function* foo(){while(true){if (true) {yield 'foo';}}}", None),
-        // ("function* foo(){while(true){yield 'foo';} while(true) {}}", None),
-        // ("var a = function* foo(){while(true){} yield 'foo';}", None),
-        // ("while (true) { function* foo() {yield;}}", None),
-        // ("function* foo(){if (true) {yield 'foo';}}", None),
-        // ("function* foo() {for (let foo = yield; true;) {}}", None),
-        // ("function* foo() {for (foo = yield; true;) {}}", None),
-        // ("function foo() {while (true) {function* bar() {while (true) {yield;}}}}", None),
-        // ("function foo() {while (true) {const bar = function*() {while (true) {yield;}}}}", None),
-        // ("function* foo() { for (let foo = 1 + 2 + 3 + (yield); true; baz) {}}", None),
+        ("for(;true;);", None),
+        ("for(;``;);", None),
+        ("for(;`foo`;);", None),
+        ("for(;`foo${bar}`;);", None),
+        ("do{}while(true)", None),
+        ("do{}while('1')", None),
+        ("do{}while(0)", None),
+        ("do{}while(t = -2)", None),
+        ("do{}while(``)", None),
+        ("do{}while(`foo`)", None),
+        ("do{}while(`foo${bar}`)", None),
+        ("while([]);", None),
+        ("while(~!0);", None),
+        ("while(x = 1);", None),
+        ("while(function(){});", None),
+        ("while(true);", None),
+        ("while(true);", Some(json!([{"checkLoops":true}]))),
+        ("while(1);", None),
+        ("while(() => {});", None),
+        ("while(`foo`);", None),
+        ("while(``);", None),
+        ("while(`${'foo'}`);", None),
+        ("while(`${'foo' + 'bar'}`);", None),
+        ("function* foo(){while(true){} yield 'foo';}", None),
+        ("function* foo(){while(true){if (true) {yield 'foo';}}}", None),
+        ("function* foo(){while(true){yield 'foo';} while(true) {}}", None),
+        ("var a = function* foo(){while(true){} yield 'foo';}", None),
+        ("while (true) { function* foo() {yield;}}", None),
+        ("function* foo(){if (true) {yield 'foo';}}", None),
+        ("function* foo() {for (let foo = yield; true;) {}}", None),
+        ("function* foo() {for (foo = yield; true;) {}}", None),
+        ("function foo() {while (true) {function* bar() {while (true) {yield;}}}}", None),
+        ("function foo() {while (true) {const bar = function*() {while (true) {yield;}}}}", None),
+        ("function* foo() { for (let foo = 1 + 2 + 3 + (yield); true; baz) {}}", None),
     ];
 
     Tester::new(NoConstantCondition::NAME, pass, fail).test_and_snapshot();
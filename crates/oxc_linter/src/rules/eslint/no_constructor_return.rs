@@ -26,16 +26,19 @@ declare_oxc_lint!(
     /// In JavaScript, returning a value in the constructor of a class may be a mistake.
     /// Forbidding this pattern prevents mistakes resulting from unfamiliarity with the language or a copy-paste error.
     ///
+    /// A bare `return;` is allowed for flow control, as are returns inside nested
+    /// functions.
+    ///
     /// ### Example
     /// Bad:
-    /// ```rust
+    /// ```javascript
     /// class C {
     ///     constructor() { return 42; }
     /// }
     /// ```
     ///
     /// Good:
-    /// ```rust
+    /// ```javascript
     /// class C {
     ///     constructor() { this.value = 42; }
     /// }
@@ -96,11 +99,26 @@ fn test() {
         "class C { constructor() { this.fn = () => { return true } } }",
         "class C { constructor() { return } }",
         "class C { constructor() { { return } } }",
+        "class C { constructor() { this.fn = () => true } }",
+        "class C { constructor() { [].forEach(x => { return x }) } }",
+        "class C { constructor() { const o = { method() { return 1 } } } }",
+        "class C { constructor() { class D { method() { return 1 } } } }",
+        "class C { fn = () => { return 1 } }",
+        "class C { ['constructor']() { return 1 } }",
+        "class C { static constructor() { return 1 } }",
+        "({ constructor() { return 1 } })",
     ];
 
     let fail = vec![
         "class C { constructor() { return '' } }",
         "class C { constructor(a) { if (!a) { return '' } else { a() } } }",
+        "class C { constructor() { return this } }",
+        "class C { constructor() { return void 0 } }",
+        "class C { constructor(a) { if (!a) return; else return a } }",
+        "class C { constructor() { try { return 1 } finally {} } }",
+        "class C { 'constructor'() { return 1 } }",
+        "(class { constructor() { return 1 } })",
+        "class C { constructor() { class D { constructor() { return 1 } } } }",
     ];
 
     Tester::new(NoConstructorReturn::NAME, pass, fail).test_and_snapshot();
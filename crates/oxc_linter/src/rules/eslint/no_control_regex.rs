@@ -10,10 +10,10 @@ use regex::{Matches, Regex};
 
 use crate::{ast_util::extract_regex_flags, context::LintContext, rule::Rule, AstNode};
 
-fn no_control_regex_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+fn no_control_regex_diagnostic(x0: &str, spans: Vec<Span>) -> OxcDiagnostic {
     OxcDiagnostic::warn("Unexpected control character(s)")
         .with_help(format!("Unexpected control character(s) in regular expression: \"{x0}\""))
-        .with_label(span1)
+        .with_labels(spans)
 }
 
 #[derive(Debug, Default, Clone)]
@@ -64,8 +64,10 @@ declare_oxc_lint!(
 
 impl Rule for NoControlRegex {
     fn run<'a>(&self, node: &AstNode<'a>, context: &LintContext<'a>) {
-        if let Some(RegexPatternData { pattern, flags, span }) = regex_pattern(node) {
+        if let Some(RegexPatternData { pattern, flags, span, pattern_start }) = regex_pattern(node)
+        {
             let mut violations: Vec<&str> = Vec::new();
+            let mut spans: Vec<Span> = Vec::new();
 
             for matched_ctl_pattern in control_patterns(pattern) {
                 let ctl = matched_ctl_pattern.as_str();
@@ -126,11 +128,20 @@ impl Rule for NoControlRegex {
                 }
 
                 violations.push(ctl);
+                if let Some(pattern_start) = pattern_start {
+                    #[allow(clippy::cast_possible_truncation)]
+                    let start = pattern_start + matched_ctl_pattern.start() as u32;
+                    #[allow(clippy::cast_possible_truncation)]
+                    spans.push(Span::new(start, start + ctl.len() as u32));
+                }
             }
 
             if !violations.is_empty() {
                 let violations = violations.join(", ");
-                context.diagnostic(no_control_regex_diagnostic(&violations, span));
+                if spans.is_empty() {
+                    spans.push(span);
+                }
+                context.diagnostic(no_control_regex_diagnostic(&violations, spans));
             }
         }
     }
@@ -152,6 +163,10 @@ struct RegexPatternData<'a> {
     ///
     /// Note that spans are 8 bytes and safely clonable with low performance overhead
     span: Span,
+    /// Where the pattern starts in the source text. This is only known for regex
+    /// literals, as escapes in string patterns have already been cooked, so each
+    /// control character can only be pointed at in literals.
+    pattern_start: Option<u32>,
 }
 
 /// Returns the regex pattern inside a node, if it's applicable.
@@ -169,6 +184,7 @@ fn regex_pattern<'a>(node: &AstNode<'a>) -> Option<RegexPatternData<'a>> {
             pattern: reg.regex.pattern.as_ref(),
             flags: Some(reg.regex.flags),
             span: reg.span,
+            pattern_start: Some(reg.span.start + 1),
         }),
 
         // new RegExp()
@@ -190,6 +206,7 @@ fn regex_pattern<'a>(node: &AstNode<'a>) -> Option<RegexPatternData<'a>> {
                         pattern: pattern.value.as_ref(),
                         flags: extract_regex_flags(&expr.arguments),
                         span: kind.span(),
+                        pattern_start: None,
                     })
                 } else {
                     None
@@ -218,6 +235,7 @@ fn regex_pattern<'a>(node: &AstNode<'a>) -> Option<RegexPatternData<'a>> {
                         pattern: pattern.value.as_ref(),
                         flags: extract_regex_flags(&expr.arguments),
                         span: kind.span(),
+                        pattern_start: None,
                     })
                 } else {
                     None
@@ -349,6 +367,8 @@ mod tests {
                 r"/\u{1F}/ugi",
                 r"new RegExp('\\u{1F}', 'u')",
                 r"new RegExp('\\u{1F}', 'ugi')",
+                r"var regex = /\x00[a-z]\x1f/",
+                "var regex = /a\tb/",
             ],
         )
         .test_and_snapshot();
@@ -145,7 +145,8 @@ fn is_subset<'a, 'b>(a: &'a [&'a Expression<'b>], b: &'a [&'a Expression<'b>]) -
 }
 
 fn is_equal<'a, 'b>(a: &'a Expression<'b>, b: &'a Expression<'b>) -> bool {
-    match (a, b) {
+    // Operands of `&&` and `||` may be parenthesized, e.g. `a && (b || c)`
+    match (a.without_parenthesized(), b.without_parenthesized()) {
         (Expression::LogicalExpression(a), Expression::LogicalExpression(b))
             if matches!(a.operator, LogicalOperator::And | LogicalOperator::Or)
                 && a.operator == b.operator =>
@@ -197,6 +198,8 @@ fn test() {
         ("if (a) {} else if (b && (a || c)) {}", None),
         ("if (a) {} else if (b && (c || d && a)) {}", None),
         ("if (a && b && c) {} else if (a && b && (c || d)) {}", None),
+        ("if (a && (b || c)) {} else if (a && (b || d)) {}", None),
+        ("if ((a || b) && c) {} else if ((a && b) || c) {}", None),
     ];
 
     let fail = vec![
@@ -255,6 +258,10 @@ fn test() {
         ("if (a) {} else if (a && a) {}", None),
         ("if (a && a) {} else if (a && a) {}", None),
         ("if (a && a) {} else if (a) {}", None),
+        ("if (a && (b || c)) {} else if (a && (c || b)) {}", None),
+        ("if (a && (b || c)) {} else if ((c || b) && a) {}", None),
+        ("if ((a || b) && (c || d)) {} else if ((d || c) && (b || a)) {}", None),
+        ("if (a || (b && c)) {} else if ((c && b)) {}", None),
     ];
 
     Tester::new(NoDupeElseIf::NAME, pass, fail).test_and_snapshot();
@@ -50,10 +50,12 @@ impl Rule for NoDuplicateCase {
             map.reserve(ss.cases.len());
             for case in &ss.cases {
                 if let Some(test) = case.test.as_ref() {
-                    let hash = calculate_hash(test);
+                    let hash = calculate_hash(test.without_parenthesized());
 
-                    if let Some(prev_span) = map.insert(hash, test.span()) {
-                        ctx.diagnostic(no_duplicate_case_diagnostic(prev_span, test.span()));
+                    // Point every duplicate at the first case with the same test
+                    let first_span = *map.entry(hash).or_insert_with(|| test.span());
+                    if first_span != test.span() {
+                        ctx.diagnostic(no_duplicate_case_diagnostic(first_span, test.span()));
                     }
                 }
             }
@@ -96,6 +98,8 @@ fn test() {
         ),
         ("switch(a) { case a: break; } switch(a) { case a: break; }", None),
         ("switch(a) { case toString: break; }", None),
+        ("switch(a) { case 1: break; case 1n: break; case '1': break; }", None),
+        ("switch(a) { case a.b: break; case a['b']: break; }", None),
     ];
 
     let fail = vec![
@@ -164,6 +168,9 @@ fn test() {
             "var a = 1, f = function(s) { return { p1: s } }; switch (a) {case f(\na + 1 // comment\n).p1: break; case f(a+1)\n.p1: break; default: break;}",
             None,
         ),
+        ("switch (a) { case 'foo': break; case 'foo': break; case 'foo': break; }", None),
+        ("switch (a) { case (b): break; case b: break; }", None),
+        ("switch (a) { case (b.c): break; case ((b.c)): break; }", None),
     ];
 
     Tester::new(NoDuplicateCase::NAME, pass, fail).test_and_snapshot();
@@ -0,0 +1,358 @@
+use oxc_ast::{
+    ast::{IfStatement, Statement},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::identifier::is_line_terminator;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_else_return_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unnecessary `else` after `return`.")
+        .with_help("Remove the `else` and move its body after the `if` statement.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoElseReturn {
+    allow_else_if: bool,
+}
+
+impl Default for NoElseReturn {
+    fn default() -> Self {
+        Self { allow_else_if: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `else` blocks after `return` statements in `if` statements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If an `if` block always exits with a `return`, `throw`, `break` or `continue`
+    /// statement, the `else` block is unnecessary. Its contents can be placed outside
+    /// of the block.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function foo() {
+    ///     if (x) {
+    ///         return y;
+    ///     } else {
+    ///         return z;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `allowElseIf` (default `true`): allow `else if` blocks after a `return`. When
+    /// `false`, `else if` blocks are reported as well.
+    NoElseReturn,
+    style
+);
+
+impl Rule for NoElseReturn {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_else_if: value
+                .get(0)
+                .and_then(|config| config.get("allowElseIf"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::IfStatement(if_stmt) = node.kind() else {
+            return;
+        };
+        // Only check `if` statements in statement lists, which also skips `else if`
+        if !matches!(
+            ctx.nodes().parent_kind(node.id()),
+            Some(
+                AstKind::Program(_)
+                    | AstKind::BlockStatement(_)
+                    | AstKind::FunctionBody(_)
+                    | AstKind::SwitchCase(_)
+                    | AstKind::StaticBlock(_)
+                    | AstKind::TSModuleBlock(_)
+            )
+        ) {
+            return;
+        }
+
+        if !self.allow_else_if {
+            if if_stmt.alternate.is_some() && always_exits(&if_stmt.consequent) {
+                report(if_stmt, ctx);
+            }
+            return;
+        }
+
+        // Report the final `else` if every block of the `if...else if` chain exits
+        let mut current = if_stmt;
+        loop {
+            let Some(alternate) = &current.alternate else {
+                return;
+            };
+            if !always_exits(&current.consequent) {
+                return;
+            }
+            let Statement::IfStatement(next) = alternate else {
+                report(current, ctx);
+                return;
+            };
+            current = next;
+        }
+    }
+}
+
+fn report(if_stmt: &IfStatement, ctx: &LintContext) {
+    let Some(alternate) = &if_stmt.alternate else {
+        return;
+    };
+    let diagnostic = no_else_return_diagnostic(alternate.span());
+    match get_fix(if_stmt, alternate, ctx) {
+        Some((span, fixed)) => {
+            ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(span, fixed));
+        }
+        None => ctx.diagnostic(diagnostic),
+    }
+}
+
+fn is_exit(stmt: &Statement) -> bool {
+    matches!(
+        stmt,
+        Statement::ReturnStatement(_)
+            | Statement::ThrowStatement(_)
+            | Statement::BreakStatement(_)
+            | Statement::ContinueStatement(_)
+    )
+}
+
+/// Checks if the statement, or the last statement of the block, exits.
+fn naive_exits(stmt: &Statement) -> bool {
+    match stmt {
+        Statement::BlockStatement(block) => block.body.last().is_some_and(is_exit),
+        _ => is_exit(stmt),
+    }
+}
+
+fn is_exit_or_if(stmt: &Statement) -> bool {
+    is_exit(stmt)
+        || matches!(stmt, Statement::IfStatement(if_stmt)
+            if naive_exits(&if_stmt.consequent)
+                && if_stmt.alternate.as_ref().is_some_and(naive_exits))
+}
+
+fn always_exits(stmt: &Statement) -> bool {
+    match stmt {
+        Statement::BlockStatement(block) => block.body.iter().any(is_exit_or_if),
+        _ => is_exit_or_if(stmt),
+    }
+}
+
+/// Returns the span to replace and its replacement, removing the `else` keyword
+/// and the braces of its block.
+fn get_fix(
+    if_stmt: &IfStatement,
+    alternate: &Statement,
+    ctx: &LintContext,
+) -> Option<(Span, String)> {
+    if !is_safe_from_name_collisions(alternate, ctx) {
+        return None;
+    }
+    let consequent_end = if_stmt.consequent.span().end;
+    let alternate_span = alternate.span();
+    let between = Span::new(consequent_end, alternate_span.start);
+    if ctx.semantic().trivias().has_comments_between(between) {
+        return None;
+    }
+    let else_start =
+        consequent_end + u32::try_from(ctx.source_range(between).find("else")?).ok()?;
+
+    let source = ctx.source_range(alternate_span);
+    let body = match alternate {
+        Statement::BlockStatement(_) => &source[1..source.len() - 1],
+        _ => source,
+    };
+
+    // Removing the `else` could join the statements before and after it
+    let consequent_maybe_unsafe = !matches!(if_stmt.consequent, Statement::BlockStatement(_))
+        && !ctx.source_range(if_stmt.consequent.span()).ends_with(';');
+    if consequent_maybe_unsafe && body.trim_start().starts_with(is_unsafe_start) {
+        return None;
+    }
+    if !body.trim_end().ends_with(';') {
+        let rest = &ctx.source_text()[alternate_span.end as usize..];
+        let next = rest.trim_start();
+        let next_on_same_line = !rest[..rest.len() - next.len()].contains(is_line_terminator);
+        if next.starts_with(is_unsafe_start)
+            || (next_on_same_line && !next.is_empty() && !next.starts_with('}'))
+        {
+            return None;
+        }
+    }
+
+    if matches!(alternate, Statement::BlockStatement(_)) && !body.contains('`') {
+        if let Some(dedented) = dedent_block(body) {
+            return Some((Span::new(consequent_end, alternate_span.end), dedented));
+        }
+    }
+    Some((Span::new(else_start, alternate_span.end), body.to_string()))
+}
+
+fn is_unsafe_start(c: char) -> bool {
+    matches!(c, '(' | '[' | '/' | '+' | '`' | '-')
+}
+
+/// Moves the lines of a multiline block body one level out, to the indentation
+/// of its closing brace.
+fn dedent_block(body: &str) -> Option<String> {
+    let (first, rest) = body.split_once('\n')?;
+    let (rest, closing_indent) = rest.rsplit_once('\n')?;
+    if !first.trim().is_empty() || !closing_indent.trim().is_empty() {
+        return None;
+    }
+    let indent = rest
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()?;
+    let extra = indent.saturating_sub(closing_indent.len());
+    let lines = rest
+        .lines()
+        .map(|line| if line.trim().is_empty() { Some("") } else { line.get(extra..) })
+        .collect::<Option<Vec<_>>>()?;
+    Some(format!("\n{}", lines.join("\n")))
+}
+
+/// Checks that the declarations of the `else` block don't collide with other
+/// variables once they are moved to the enclosing scope.
+fn is_safe_from_name_collisions(alternate: &Statement, ctx: &LintContext) -> bool {
+    let Statement::BlockStatement(block) = alternate else {
+        return true;
+    };
+    let Some(scope_id) = block.scope_id.get() else {
+        return true;
+    };
+    let scopes = ctx.scopes();
+    let Some(parent_id) = scopes.get_parent_id(scope_id) else {
+        return true;
+    };
+    scopes.get_bindings(scope_id).keys().all(|name| {
+        scopes.find_binding(parent_id, name).is_none()
+            && !scopes.root_unresolved_references().contains_key(name)
+            && scopes
+                .descendants(parent_id)
+                .filter(|&id| id != scope_id)
+                .all(|id| !scopes.has_binding(id, name))
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("function foo() { var x = true; if (x) { return x; } else if (x === false) { return false; } }", None),
+        ("function foo() { if (true) return bar(); else if (false) baz(); }", None),
+        ("function foo() { if (x) { notAReturn(); } else { return y; } }", None),
+        ("function foo() { if (x) { return x; } return y; }", None),
+        ("function foo() { if (x) { if (y) { return x; } } else { return z; } }", None),
+        ("function foo() { if (x) { return x; } else if (y) { notAReturn() } else { notAReturn(); } }", None),
+        ("if (0) { if (0) {} else {} } else {}", None),
+        (
+            "
+            function foo() {
+                if (foo)
+                    if (bar) return;
+                    else baz;
+                else qux;
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            function foo() {
+                while (foo)
+                    if (bar) return;
+                    else baz;
+            }
+            ",
+            None,
+        ),
+        ("function foo19() { if (true) { return x; } else if (false) { return y; } }", Some(json!([{ "allowElseIf": true }]))),
+        ("function foo20() {if (x) { return true; } else if (y) { notAReturn() } else { notAReturn(); } }", Some(json!([{ "allowElseIf": true }]))),
+        ("function foo21() { var x = true; if (x) { return x; } else if (x === false) { return false; } }", Some(json!([{ "allowElseIf": true }]))),
+        ("function foo() { if (x) { notAReturn(); } else if (y) { return true; } }", Some(json!([{ "allowElseIf": false }]))),
+        ("for (;;) { if (x) { foo(); } else { bar(); } }", None),
+    ];
+
+    let fail = vec![
+        ("function foo1() { if (true) { return x; } else { return y; } }", None),
+        ("function foo2() { if (true) { var x = bar; return x; } else { var y = baz; return y; } }", None),
+        ("function foo3() { if (true) return x; else return y; }", None),
+        ("function foo4() { if (true) { if (false) return x; else return y; } else { return z; } }", None),
+        ("function foo5() { if (true) { if (false) { if (true) return x; else { w = y; } } else { w = x; } } else { return y; } }", None),
+        ("function foo6() { if (true) { if (false) { if (true) return x; else return y; } } else { return z; } }", None),
+        ("function foo7() { if (true) { if (false) { if (true) return x; else return y; } return w; } else { return z; } }", None),
+        ("function foo8() { if (true) { if (false) { if (true) return x; else return y; } else { w = x; } } else { return z; } }", None),
+        ("function foo9() {if (x) { return true; } else if (y) { return true; } else { notAReturn(); } }", None),
+        ("function foo9a() {if (x) { return true; } else if (y) { return true; } else { notAReturn(); } }", Some(json!([{ "allowElseIf": false }]))),
+        ("function foo9b() {if (x) { return true; } if (y) { return true; } else { notAReturn(); } }", Some(json!([{ "allowElseIf": false }]))),
+        ("function foo10() { if (foo) return bar; else (foo).bar(); }", None),
+        ("function foo11() { if (foo) return bar \nelse { [1, 2, 3].map(foo) } }", None),
+        ("function foo12() { if (foo) return bar \nelse { baz() } \n[1, 2, 3].map(foo) }", None),
+        ("function foo13() { if (foo) return bar; \nelse { baz() } \n[1, 2, 3].map(foo) }", None),
+        ("function foo14() { if (foo) return bar \nelse { baz(); } \n[1, 2, 3].map(foo) }", None),
+        ("function foo15() { if (foo) return bar; else { baz() } qaz() }", None),
+        ("function foo16() { if (foo) return bar \nelse { baz() } qaz() }", None),
+        ("function foo17() { if (foo) return bar \nelse { baz() } \nqaz() }", None),
+        ("function foo18() { if (foo) return function() {} \nelse [1, 2, 3].map(bar) }", None),
+        ("function foo19() { if (true) { return x; } else if (false) { return y; } }", Some(json!([{ "allowElseIf": false }]))),
+        ("function foo20() {if (x) { return true; } else if (y) { notAReturn() } else { notAReturn(); } }", Some(json!([{ "allowElseIf": false }]))),
+        ("function foo21() { var x = true; if (x) { return x; } else if (x === false) { return false; } }", Some(json!([{ "allowElseIf": false }]))),
+        ("for (;;) { if (x) { continue; } else { bar(); } }", None),
+        ("for (;;) { if (x) { break; } else { bar(); } }", None),
+        ("function foo() { if (x) { throw new Error(); } else { bar(); } }", None),
+        ("function foo() { if (x) { return; } else { let y = 1; bar(y); } }", None),
+        ("function foo() { let y = 0; if (x) { return y; } else { let y = 1; bar(y); } }", None),
+        ("function foo() { if (x) { return y; } else { let y = 1; bar(y); } }", None),
+    ];
+
+    let fix = vec![
+        ("function foo1() { if (true) { return x; } else { return y; } }", "function foo1() { if (true) { return x; }  return y;  }"),
+        ("function foo3() { if (true) return x; else return y; }", "function foo3() { if (true) return x; return y; }"),
+        (
+            "function foo() {\n  if (x) {\n    return y;\n  } else {\n    bar();\n    return z;\n  }\n}",
+            "function foo() {\n  if (x) {\n    return y;\n  }\n  bar();\n  return z;\n}",
+        ),
+        (
+            "function foo() {\n  if (x) {\n    return y;\n  } else if (z) {\n    return z;\n  } else {\n    if (w) {\n      bar();\n    }\n  }\n}",
+            "function foo() {\n  if (x) {\n    return y;\n  } else if (z) {\n    return z;\n  }\n  if (w) {\n    bar();\n  }\n}",
+        ),
+        ("function foo9a() {if (x) { return true; } else if (y) { return true; } else { notAReturn(); } }", "function foo9a() {if (x) { return true; } else if (y) { return true; }  notAReturn();  }"),
+        ("function foo10() { if (foo) return bar; else (foo).bar(); }", "function foo10() { if (foo) return bar; (foo).bar(); }"),
+        ("function foo13() { if (foo) return bar; \nelse { baz() } \n[1, 2, 3].map(foo) }", "function foo13() { if (foo) return bar; \nelse { baz() } \n[1, 2, 3].map(foo) }"),
+        ("function foo14() { if (foo) return bar \nelse { baz(); } \n[1, 2, 3].map(foo) }", "function foo14() { if (foo) return bar \n baz();  \n[1, 2, 3].map(foo) }"),
+        ("function foo15() { if (foo) return bar; else { baz() } qaz() }", "function foo15() { if (foo) return bar; else { baz() } qaz() }"),
+        ("function foo17() { if (foo) return bar \nelse { baz() } \nqaz() }", "function foo17() { if (foo) return bar \n baz()  \nqaz() }"),
+        ("function foo18() { if (foo) return function() {} \nelse [1, 2, 3].map(bar) }", "function foo18() { if (foo) return function() {} \nelse [1, 2, 3].map(bar) }"),
+        ("function foo() { if (x) { return; } else { let y = 1; bar(y); } }", "function foo() { if (x) { return; }  let y = 1; bar(y);  }"),
+        // Name collisions are not fixed
+        ("function foo() { let y = 0; if (x) { return y; } else { let y = 1; bar(y); } }", "function foo() { let y = 0; if (x) { return y; } else { let y = 1; bar(y); } }"),
+        ("function foo() { if (x) { return y; } else { let y = 1; bar(y); } }", "function foo() { if (x) { return y; } else { let y = 1; bar(y); } }"),
+        ("function foo() { if (x) { return; } else { let y = 1; } if (z) { return; } else { let y = 2; } }", "function foo() { if (x) { return; } else { let y = 1; } if (z) { return; } else { let y = 2; } }"),
+        // Comments are kept
+        ("function foo() { if (x) { return; } /* comment */ else { bar(); } }", "function foo() { if (x) { return; } /* comment */ else { bar(); } }"),
+    ];
+
+    Tester::new(NoElseReturn::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
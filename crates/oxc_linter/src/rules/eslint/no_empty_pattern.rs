@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{ast::Expression, AstKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -14,7 +14,9 @@ fn no_empty_pattern_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
 }
 
 #[derive(Debug, Default, Clone)]
-pub struct NoEmptyPattern;
+pub struct NoEmptyPattern {
+    allow_object_patterns_as_parameters: bool,
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -70,21 +72,63 @@ declare_oxc_lint!(
     /// function foo({a = []}) {}
     /// ```
     ///
+    /// ### Options
+    ///
+    /// `allowObjectPatternsAsParameters`: when `true`, empty object patterns are allowed
+    /// as function parameters, including ones defaulting to an empty object:
+    ///
+    /// ```JavaScript
+    /// function foo({}) {}
+    /// var bar = function({}) {};
+    /// var baz = ({} = {}) => {};
+    /// ```
+    ///
+    /// Nested empty patterns such as `function foo({a: {}}) {}` are still reported.
     NoEmptyPattern,
     correctness,
 );
 
 impl Rule for NoEmptyPattern {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_object_patterns_as_parameters: value
+                .get(0)
+                .and_then(|config| config.get("allowObjectPatternsAsParameters"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let (pattern_type, span) = match node.kind() {
             AstKind::ArrayPattern(array) if array.is_empty() => ("array", array.span),
-            AstKind::ObjectPattern(object) if object.is_empty() => ("object", object.span),
+            AstKind::ObjectPattern(object) if object.is_empty() => {
+                if self.allow_object_patterns_as_parameters && is_function_parameter(node, ctx) {
+                    return;
+                }
+                ("object", object.span)
+            }
             _ => return,
         };
         ctx.diagnostic(no_empty_pattern_diagnostic(pattern_type, span));
     }
 }
 
+/// Checks for `function foo({}) {}` and `function foo({} = {}) {}`.
+fn is_function_parameter(node: &AstNode, ctx: &LintContext) -> bool {
+    let Some(parent) = ctx.nodes().parent_node(node.id()) else {
+        return false;
+    };
+    match parent.kind() {
+        AstKind::FormalParameter(_) => true,
+        AstKind::AssignmentPattern(pattern) => {
+            matches!(&pattern.right, Expression::ObjectExpression(object) if object.properties.is_empty())
+                && matches!(ctx.nodes().parent_kind(parent.id()), Some(AstKind::FormalParameter(_)))
+        }
+        _ => false,
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -98,6 +142,32 @@ fn test() {
         ("var [a] = foo", None),
         ("var {...x} = foo;", None),
         ("var [...x] = foo;", None),
+        ("var {a: {b}} = foo;", None),
+        ("var [[a]] = foo;", None),
+        (
+            "function foo({}) {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "var foo = function({}) {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "var foo = ({}) => {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "function foo({} = {}) {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "var foo = ({} = {}) => {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "class C { foo({}) {} }",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
     ];
 
     let fail = vec![
@@ -110,6 +180,37 @@ fn test() {
         ("function foo([]) {}", None),
         ("function foo({a: {}}) {}", None),
         ("function foo({a: []}) {}", None),
+        (
+            "var {a: {}} = foo",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        ("var {} = foo", Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }]))),
+        (
+            "function foo({a: {}}) {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "function foo({a: {}} = {}) {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "function foo({} = {a: 1}) {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "function foo([]) {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "var foo = ({a: {}}) => {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        (
+            "try {} catch ({}) {}",
+            Some(serde_json::json!([{ "allowObjectPatternsAsParameters": true }])),
+        ),
+        ("var foo = ({}) => {}", None),
+        ("function foo({} = {}) {}", None),
     ];
 
     Tester::new(NoEmptyPattern::NAME, pass, fail).test_and_snapshot();
@@ -22,6 +22,9 @@ declare_oxc_lint!(
     /// Empty block statements, while not technically errors, usually occur due to refactoring that wasn’t completed.
     /// They can cause confusion when reading code.
     ///
+    /// A static block containing only a comment is not reported, since the comment
+    /// explains why it is empty.
+    ///
     /// ### Example
     /// ```javascript
     ///
@@ -30,6 +33,13 @@ declare_oxc_lint!(
     ///     }
     /// }
     ///
+    /// // Good
+    /// class Bar {
+    ///     static {
+    ///         // comment
+    ///     }
+    /// }
+    ///
     /// ```
     NoEmptyStaticBlock,
     correctness
@@ -39,7 +49,12 @@ impl Rule for NoEmptyStaticBlock {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::StaticBlock(static_block) = node.kind() {
             if static_block.body.is_empty() {
-                if ctx.semantic().trivias().has_comments_between(static_block.span) {
+                // Only comments between the braces count, not `static /* comment */ {}`
+                let text = ctx.source_range(static_block.span);
+                #[allow(clippy::cast_possible_truncation)]
+                let body_start = static_block.span.start + text.find('{').unwrap_or(0) as u32;
+                let body_span = Span::new(body_start, static_block.span.end);
+                if ctx.semantic().trivias().has_comments_between(body_span) {
                     return;
                 }
                 ctx.diagnostic(no_empty_static_block_diagnostic(static_block.span));
@@ -59,6 +74,14 @@ fn test() {
 			// comment
 			} }",
         "class Foo { static { bar(); } static { bar(); } }",
+        "class Foo { static { /* comment */ } static { // comment
+			} }",
+        "class Foo { static {
+			/* multi
+			   line */
+			} }",
+        "(class { static { /* comments */ } })",
+        "class Foo { static { ; } }",
     ];
 
     let fail = vec![
@@ -68,6 +91,11 @@ fn test() {
 
 			 } }",
         "class Foo { static { bar(); } static {} }",
+        "class Foo { static /* comment */ {} }",
+        "class Foo { static {} /* comment */ }",
+        "class Foo { /* comment */ static {} }",
+        "class Foo { static {} static {} }",
+        "(class { static {} })",
     ];
 
     Tester::new(NoEmptyStaticBlock::NAME, pass, fail).test_and_snapshot();
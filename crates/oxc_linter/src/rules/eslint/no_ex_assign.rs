@@ -25,11 +25,11 @@ declare_oxc_lint!(
     ///
     /// ### Example
     /// ```javascript
-    // try {
-    //     // code
-    // } catch (e) {
-    //     e = 10;
-    // }
+    /// try {
+    ///     // code
+    /// } catch (e) {
+    ///     e = 10;
+    /// }
     /// ```
     NoExAssign,
     correctness
@@ -56,6 +56,11 @@ fn test() {
         ("try { } catch (e) { three = 2 + 1; }", None),
         ("try { } catch ({e}) { this.something = 2; }", None),
         ("function foo() { try { } catch (e) { return false; } }", None),
+        ("try { } catch (e) { function foo(e) { e = 10; } }", None),
+        ("try { } catch (e) { const foo = (e) => { e = 10; }; }", None),
+        ("try { } catch (e) { { let e = 1; e = 10; } }", None),
+        ("try { } catch (e) { try { } catch (e) { } }", None),
+        ("try { } catch (e) { e.message = 'foo'; }", None),
     ];
 
     let fail = vec![
@@ -64,6 +69,11 @@ fn test() {
         ("try { } catch (ex) { [ex] = []; }", None),
         ("try { } catch (ex) { ({x: ex = 0} = {}); }", None),
         ("try { } catch ({message}) { message = 10; }", None),
+        ("try { } catch ({ message: [first] }) { first = 10; }", None),
+        ("try { } catch (e) { e++; }", None),
+        ("try { } catch (e) { e ||= 10; }", None),
+        ("try { } catch (e) { function foo() { e = 10; } }", None),
+        ("try { } catch (e) { for (e of []) { } }", None),
     ];
 
     Tester::new(NoExAssign::NAME, pass, fail).test_and_snapshot();
@@ -0,0 +1,199 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+
+use crate::{
+    ast_util::{get_member_expression, is_global_reference},
+    context::LintContext,
+    javascript_globals::GLOBALS,
+    rule::Rule,
+    AstNode,
+};
+
+fn no_extend_native_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("{x0} prototype is read only, properties should not be added."))
+        .with_label(span1)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtendNative(Box<NoExtendNativeConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoExtendNativeConfig {
+    exceptions: Vec<CompactStr>,
+}
+
+impl std::ops::Deref for NoExtendNative {
+    type Target = NoExtendNativeConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow extending native types.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// In JavaScript, you can extend any object, including builtin or "native" objects.
+    /// Sometimes people change the behavior of these native objects in ways that break
+    /// the assumptions made about them in other parts of the code.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// Object.prototype.a = "a";
+    /// Object.defineProperty(Array.prototype, "times", { value: 999 });
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `exceptions`: a list of native objects which are allowed to be extended, e.g.
+    /// `{ "exceptions": ["Object"] }`.
+    NoExtendNative,
+    suspicious
+);
+
+impl Rule for NoExtendNative {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(NoExtendNativeConfig {
+            exceptions: value
+                .get(0)
+                .and_then(|config| config.get("exceptions"))
+                .and_then(serde_json::Value::as_array)
+                .map(|exceptions| {
+                    exceptions
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(CompactStr::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            // `Object.prototype.foo = bar`
+            AstKind::AssignmentExpression(assign_expr) => {
+                let Some(member_expr) = assign_expr
+                    .left
+                    .as_simple_assignment_target()
+                    .and_then(|target| target.as_member_expression())
+                else {
+                    return;
+                };
+                if let Some(name) = self.get_native_prototype(member_expr.object(), ctx) {
+                    ctx.diagnostic(no_extend_native_diagnostic(name, assign_expr.span));
+                }
+            }
+            // `Object.defineProperty(Object.prototype, "foo", bar)`
+            AstKind::CallExpression(call_expr) => {
+                if !call_expr.callee.is_specific_member_access("Object", "defineProperty")
+                    && !call_expr.callee.is_specific_member_access("Object", "defineProperties")
+                {
+                    return;
+                }
+                if !get_member_expression(&call_expr.callee)
+                    .and_then(|member| member.object().get_identifier_reference())
+                    .is_some_and(|object| is_global_reference(object, ctx))
+                {
+                    return;
+                }
+                let Some(target) = call_expr.arguments.first().and_then(|arg| arg.as_expression())
+                else {
+                    return;
+                };
+                if let Some(name) = self.get_native_prototype(target, ctx) {
+                    ctx.diagnostic(no_extend_native_diagnostic(name, call_expr.span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoExtendNative {
+    /// Returns the name of the native object if `expr` is `<native>.prototype`, where
+    /// `<native>` refers to a builtin global which isn't in the exceptions.
+    fn get_native_prototype<'a>(
+        &self,
+        expr: &'a Expression<'a>,
+        ctx: &LintContext<'a>,
+    ) -> Option<&'a str> {
+        let member_expr = get_member_expression(expr)?;
+        if member_expr.static_property_name() != Some("prototype") {
+            return None;
+        }
+        let Expression::Identifier(ident) = member_expr.object().without_parenthesized() else {
+            return None;
+        };
+        let name = ident.name.as_str();
+        let is_native = name.starts_with(|c: char| c.is_ascii_uppercase())
+            && GLOBALS["builtin"].contains_key(name);
+        if !is_native || self.exceptions.iter().any(|exception| exception == name) {
+            return None;
+        }
+        is_global_reference(ident, ctx).then_some(name)
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("x.prototype.p = 0", None),
+        ("x.prototype['p'] = 0", None),
+        ("Object.p = 0", None),
+        ("function f(Object) { Object.defineProperty(Array.prototype, 'x', {}) }", None),
+        ("Object.toString.bind = 0", None),
+        ("Object['toString'].bind = 0", None),
+        ("Object.defineProperty(x, 'p', {value: 0})", None),
+        ("Object.defineProperties(x, {p: {value: 0}})", None),
+        ("global.Object.prototype.toString = 0", None),
+        ("this.Object.prototype.toString = 0", None),
+        ("o = Object; o.prototype.toString = 0", None),
+        ("eval('Object.prototype.toString = 0')", None),
+        ("parseFloat.prototype.x = 1", None),
+        ("Object.prototype.g = 0", Some(serde_json::json!([{ "exceptions": ["Object"] }]))),
+        ("obj[Object.prototype] = 0", None),
+        ("Object.defineProperty()", None),
+        ("Object.defineProperties()", None),
+        ("function foo() { var Object = function() {}; Object.prototype.p = 0 }", None),
+        ("{ let Object = function() {}; Object.prototype.p = 0 }", None),
+        ("Object.prototype.p", None),
+        ("foo(Object.prototype, 'p', {value: 0})", None),
+    ];
+
+    let fail = vec![
+        ("Object.prototype.p = 0", None),
+        ("BigInt.prototype.p = 0", None),
+        ("WeakRef.prototype.p = 0", None),
+        ("FinalizationRegistry.prototype.p = 0", None),
+        ("AggregateError.prototype.p = 0", None),
+        ("Function.prototype['p'] = 0", None),
+        ("String['prototype'].p = 0", None),
+        ("Number['prototype']['p'] = 0", None),
+        ("Object.defineProperty(Array.prototype, 'p', {value: 0})", None),
+        ("Object.defineProperties(Array.prototype, {p: {value: 0}})", None),
+        ("Object.defineProperties(Array.prototype, {p: {value: 0}, q: {value: 0}})", None),
+        ("Number['prototype']['p'] = 0", Some(serde_json::json!([{ "exceptions": ["Object"] }]))),
+        ("Object.prototype.p = 0; Object.prototype.q = 0", None),
+        ("function foo() { Object.prototype.p = 0 }", None),
+        ("(Object?.prototype).p = 0", None),
+        ("Object.defineProperty(Object?.prototype, 'p', { value: 0 })", None),
+        ("Object?.defineProperty(Object.prototype, 'p', { value: 0 })", None),
+        ("(Object?.defineProperty)(Object.prototype, 'p', { value: 0 })", None),
+        ("Array.prototype.p &&= 0", None),
+        ("Array.prototype.p ||= 0", None),
+        ("Array.prototype.p ??= 0", None),
+        ("(Array).prototype.p = 0", None),
+        ("Array.prototype.p += 0", None),
+    ];
+
+    Tester::new(NoExtendNative::NAME, pass, fail).test_and_snapshot();
+}
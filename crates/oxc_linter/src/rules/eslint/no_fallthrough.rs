@@ -73,6 +73,43 @@ declare_oxc_lint!(
     ///
     /// Disallow fallthrough of `case` statements
     ///
+    /// ### Why is this bad?
+    ///
+    /// The `switch` statement in JavaScript allows a case to fall through to the next
+    /// one when it doesn't end with a `break`, `return` or `throw`. This is easy to do
+    /// unintentionally, so an intentional fallthrough should be marked with a comment
+    /// matching `/falls?\s?through/i`, such as `// falls through`.
+    ///
+    /// Empty cases, which group several cases together, are allowed when the next case
+    /// directly follows them.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// switch (foo) {
+    ///     case 1:
+    ///         doSomething();
+    ///     case 2:
+    ///         doSomethingElse();
+    /// }
+    ///
+    /// // Good
+    /// switch (foo) {
+    ///     case 1:
+    ///         doSomething();
+    ///         // falls through
+    ///     case 2:
+    ///         doSomethingElse();
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `commentPattern`: a regular expression used instead of the default to find
+    ///   fallthrough comments.
+    /// - `allowEmptyCase`: when `true`, empty cases are allowed even if they are
+    ///   separated from the next case by blank lines. Default `false`.
+    /// - `reportUnusedFallthroughComment`: when `true`, fallthrough comments on cases
+    ///   which cannot fall through are reported. Default `false`.
     NoFallthrough,
     pedantic // Fall through code are still incorrect.
 );
@@ -21,6 +21,10 @@ declare_oxc_lint!(
     /// ### Why is this bad?
     /// Overwriting/reassigning a function written as a FunctionDeclaration is often indicative of a mistake or issue.
     ///
+    /// Reassignments are reported anywhere in the function's scope, including
+    /// before the declaration (as declarations are hoisted) and inside its own body.
+    /// Variables holding a function expression can be reassigned.
+    ///
     /// ### Example
     /// ```javascript
     ///
@@ -60,6 +64,11 @@ fn test() {
         ("var foo = function() {}; foo = bar;", None),
         ("var foo = function() { foo = bar; };", None),
         ("import bar from 'bar'; function foo() { var foo = bar; }", None),
+        ("let foo = function() {}; foo = bar;", None),
+        ("var foo = function bar() {}; foo = bar;", None),
+        ("function foo() {} function bar() { let foo = 1; foo = 2; }", None),
+        ("function foo() {} foo.bar = 1;", None),
+        ("function foo() {} foo();", None),
     ];
 
     let fail = vec![
@@ -71,6 +80,10 @@ fn test() {
         ("function foo() { [foo] = bar; }", None),
         ("(function() { ({x: foo = 0} = bar); function foo() { }; })();", None),
         ("var a = function foo() { foo = 123; };", None),
+        ("foo++; function foo() {}", None),
+        ("function foo() {} foo += 1;", None),
+        ("function foo() {} function bar() { foo = 1; }", None),
+        ("function foo() {} for (foo of bar) {}", None),
     ];
 
     Tester::new(NoFuncAssign::NAME, pass, fail).test_and_snapshot();
@@ -58,12 +58,12 @@ impl Rule for NoImportAssign {
             for reference in symbol_table.get_resolved_references(symbol_id) {
                 if is_namespace_specifier {
                     let Some(parent_node) = ctx.nodes().parent_node(reference.node_id()) else {
-                        return;
+                        continue;
                     };
                     if let AstKind::MemberExpression(expr) = parent_node.kind() {
                         let Some(parent_parent_node) = ctx.nodes().parent_node(parent_node.id())
                         else {
-                            return;
+                            continue;
                         };
                         let is_unary_expression_with_delete_operator = |kind| matches!(kind, AstKind::UnaryExpression(expr) if expr.operator == UnaryOperator::Delete);
                         let parent_parent_kind = parent_parent_node.kind();
@@ -75,8 +75,8 @@ impl Rule for NoImportAssign {
                         {
                             if let Some((span, _)) = expr.static_property_info() {
                                 if span != reference.span() {
-                                    return ctx
-                                        .diagnostic(no_import_assign_diagnostic(expr.span()));
+                                    ctx.diagnostic(no_import_assign_diagnostic(expr.span()));
+                                    continue;
                                 }
                             }
                         }
@@ -260,6 +260,9 @@ fn test() {
         ("import * as mod from 'mod'; Object?.defineProperty(mod, key, d)", None),
         ("import * as mod from 'mod'; (Object?.defineProperty)(mod, key, d)", None),
         ("import * as mod from 'mod'; delete mod?.prop", None),
+        ("import * as mod from 'mod'; mod.a = 0; mod.b = 0;", None),
+        ("import * as mod from 'mod'; mod.a = 0; mod = 0;", None),
+        ("import * as mod from 'mod'; delete mod.a; Object.assign(mod, obj);", None),
     ];
 
     Tester::new(NoImportAssign::NAME, pass, fail).test_and_snapshot();
@@ -38,10 +38,7 @@ impl Rule for NoIterator {
         };
         if let Some(static_property_name) = member_expression.static_property_name() {
             if static_property_name == "__iterator__" {
-                ctx.diagnostic(no_iterator_diagnostic(Span::new(
-                    member_expression.span().start,
-                    member_expression.span().end,
-                )));
+                ctx.diagnostic(no_iterator_diagnostic(member_expression.span()));
             }
         }
     }
@@ -57,6 +54,9 @@ fn test() {
         "foo[`__iterator`] = null;",
         "foo[`__iterator__
 			`] = null;",
+        "class C { #__iterator__; foo() { this.#__iterator__; } }",
+        "var a = { __iterator__: null };",
+        "var a = test[Symbol.iterator];",
     ];
 
     let fail = vec![
@@ -65,6 +65,8 @@ fn test() {
         "var a = test['__iterator__'];",
         "var a = test[`__iterator__`];",
         "test[`__iterator__`] = function () {};",
+        "var a = test?.__iterator__;",
+        "var a = (test).__iterator__;",
     ];
 
     Tester::new(NoIterator::NAME, pass, fail).test_and_snapshot();
@@ -0,0 +1,231 @@
+use oxc_ast::{ast::Statement, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_lone_blocks_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Block is redundant.")
+        .with_help("Remove the braces of this block.")
+        .with_label(span0)
+}
+
+fn no_nested_lone_blocks_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Nested block is redundant.")
+        .with_help("Remove the braces of this block.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoLoneBlocks;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unnecessary nested blocks.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Standalone blocks do not create a new scope for `var` declarations and
+    /// function declarations in non-strict code, so they are redundant and can be
+    /// confusing. Blocks containing `let`, `const` or `class` declarations (or
+    /// function declarations in strict mode) create a block scope and are allowed.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// {}
+    ///
+    /// if (foo) {
+    ///     bar();
+    ///     {
+    ///         baz();
+    ///     }
+    /// }
+    ///
+    /// {
+    ///     var x = 1;
+    /// }
+    /// ```
+    NoLoneBlocks,
+    style
+);
+
+impl Rule for NoLoneBlocks {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::BlockStatement(block) = node.kind() else {
+            return;
+        };
+        let Some(parent) = ctx.nodes().parent_node(node.id()) else {
+            return;
+        };
+        let nested = match parent.kind() {
+            AstKind::BlockStatement(_) | AstKind::FunctionBody(_) | AstKind::StaticBlock(_) => true,
+            AstKind::Program(_) => false,
+            // `case foo: { ... }` is allowed, as a block is the only statement of the case
+            AstKind::SwitchCase(case) => {
+                if case.consequent.len() == 1 {
+                    return;
+                }
+                false
+            }
+            _ => return,
+        };
+
+        let is_strict_mode = ctx.scopes().get_flags(node.scope_id()).is_strict_mode();
+        if block.body.iter().any(|stmt| creates_block_scope(stmt, is_strict_mode)) {
+            return;
+        }
+
+        let span = block.span();
+        if nested {
+            ctx.diagnostic(no_nested_lone_blocks_diagnostic(span));
+        } else {
+            ctx.diagnostic(no_lone_blocks_diagnostic(span));
+        }
+    }
+}
+
+/// Checks if the statement declares a variable scoped to its enclosing block.
+fn creates_block_scope(stmt: &Statement, is_strict_mode: bool) -> bool {
+    match stmt {
+        Statement::VariableDeclaration(decl) => decl.kind.is_lexical(),
+        Statement::UsingDeclaration(_) | Statement::ClassDeclaration(_) => true,
+        // Functions in blocks are hoisted to the enclosing function in non-strict code
+        Statement::FunctionDeclaration(_) => is_strict_mode,
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "if (foo) { if (bar) { baz(); } }",
+        "do { bar(); } while (foo)",
+        "function foo() { while (bar) { baz() } }",
+        "{ let x = 1; }",
+        "{ const y = 1; }",
+        "'use strict'; { function bar() {} }",
+        "{ function bar() {} }",
+        "{ class Bar {} }",
+        "{ {let y = 1;} let x = 1; }",
+        "
+          switch (foo) {
+            case bar: {
+              baz;
+            }
+          }
+        ",
+        "
+          switch (foo) {
+            case bar: {
+              baz;
+            }
+            case qux: {
+              boop;
+            }
+          }
+        ",
+        "
+          switch (foo) {
+            case bar:
+            {
+              baz;
+            }
+          }
+        ",
+        "function foo() { { const x = 4 } const x = 3 }",
+        "class C { static {} }",
+        "class C { static { foo; } }",
+        "class C { static { if (foo) { block; } } }",
+        "class C { static { lbl: { block; } } }",
+        "class C { static { { let block; } something; } }",
+        "class C { static { something; { const block = 1; } } }",
+        "class C { static { { function block(){} } something; } }",
+        "class C { static { something; { class block {} } } }",
+        "foo: { bar(); }",
+        "for (;;) { foo(); }",
+        "try { foo(); } catch { bar(); } finally { baz(); }",
+        "{ using x = foo(); }",
+        "
+          function foo () {
+            {
+              const x = 4;
+            }
+          }
+        ",
+        "
+          class C {
+            static {
+              if (foo) {
+                {
+                  let block;
+                }
+              }
+            }
+          }
+        ",
+    ];
+
+    let fail = vec![
+        "{}",
+        "{var x = 1;}",
+        "foo(); {} bar();",
+        "if (foo) { bar(); {} baz(); }",
+        "{
+            { } }",
+        "function foo() { bar(); {} baz(); }",
+        "while (foo) { {} }",
+        "{ {let y = 1;} }",
+        "
+          switch (foo) {
+            case 1:
+              foo();
+              {
+                bar;
+              }
+          }
+        ",
+        "
+          switch (foo) {
+            case 1:
+            {
+              bar;
+            }
+            foo();
+          }
+        ",
+        "
+          function foo () {
+            {
+              var x = 4;
+            }
+          }
+        ",
+        "
+          class C {
+            static {
+              {
+                block;
+              }
+            }
+          }
+        ",
+        "
+          class C {
+            static {
+              {
+                let block;
+              }
+              {
+                var block;
+              }
+            }
+          }
+        ",
+    ];
+
+    Tester::new(NoLoneBlocks::NAME, pass, fail).test_and_snapshot();
+}
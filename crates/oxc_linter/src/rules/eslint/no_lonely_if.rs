@@ -0,0 +1,174 @@
+use oxc_ast::{ast::Statement, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_lonely_if_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected `if` as the only statement in an `else` block.")
+        .with_help("Use `else if` instead.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoLonelyIf;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `if` statements as the only statement in `else` blocks.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If an `if` statement is the only statement in the `else` block, it is often
+    /// clearer to use an `else if` form.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// if (condition) {
+    ///     // ...
+    /// } else {
+    ///     if (anotherCondition) {
+    ///         // ...
+    ///     }
+    /// }
+    ///
+    /// // Good
+    /// if (condition) {
+    ///     // ...
+    /// } else if (anotherCondition) {
+    ///     // ...
+    /// }
+    /// ```
+    NoLonelyIf,
+    pedantic
+);
+
+impl Rule for NoLonelyIf {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::IfStatement(if_stmt) = node.kind() else {
+            return;
+        };
+        let Some(parent) = ctx.nodes().parent_node(node.id()) else {
+            return;
+        };
+        let AstKind::BlockStatement(block) = parent.kind() else {
+            return;
+        };
+        if block.body.len() != 1 {
+            return;
+        }
+        let Some(AstKind::IfStatement(parent_if_stmt)) = ctx.nodes().parent_kind(parent.id())
+        else {
+            return;
+        };
+        if parent_if_stmt.alternate.as_ref().map(GetSpan::span) != Some(block.span) {
+            return;
+        }
+
+        let diagnostic = no_lonely_if_diagnostic(if_stmt.span);
+        // Comments inside the `else` block would be lost
+        let inner = Span::new(block.span.start + 1, block.span.end - 1);
+        if ctx.semantic().trivias().has_comments_between(inner) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        // Removing the braces could join the inner `if` with the next statement
+        if !matches!(if_stmt.consequent, Statement::BlockStatement(_))
+            && !ctx.source_range(if_stmt.consequent.span()).ends_with(';')
+        {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            let before = ctx.source_range(Span::new(parent_if_stmt.span.start, block.span.start));
+            let space = if before.ends_with("else") { " " } else { "" };
+            fixer.replace(block.span, format!("{space}{}", ctx.source_range(if_stmt.span)))
+        });
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "if (a) {;} else if (b) {}",
+        "if (a) {;} else { if (b) {} ; }",
+        "if (a) {;} else { foo(); if (b) {} }",
+        "if (a) { if (b) {} }",
+        "if (a) {} else { if (b) {} else {} foo(); }",
+        "if (a) {} else { { if (b) {} } }",
+    ];
+
+    let fail = vec![
+        "if (a) {;} else { if (b) {} }",
+        "if (a) {
+          foo();
+        } else {
+          if (b) {
+            bar();
+          }
+        }",
+        "if (a) {
+          foo();
+        } else /* comment */ {
+          if (b) {
+            bar();
+          }
+        }",
+        "if (a) {
+          foo();
+        } else {
+          /* otherwise, do the other thing */ if (b) {
+            bar();
+          }
+        }",
+        "if (a) {
+          foo();
+        } else {
+          if (b) {
+            bar();
+          } /* this comment will be removed */
+        }",
+        "if (a) {;} else { if (b) {} else { if (c) {} } }",
+        "if (a) {;} else{ if (b) {} }",
+        "if (foo) {} else { if (bar) baz() }\nqux();",
+        "if (foo) {} else { if (bar) baz(); }\nqux();",
+    ];
+
+    let fix = vec![
+        ("if (a) {;} else { if (b) {} }", "if (a) {;} else if (b) {}"),
+        ("if (a) {;} else{ if (b) {} }", "if (a) {;} else if (b) {}"),
+        ("if (a) {;} else /* comment */ { if (b) {} }", "if (a) {;} else /* comment */ if (b) {}"),
+        (
+            "if (a) {;} else { if (b) {} else { if (c) {} } }",
+            "if (a) {;} else if (b) {} else { if (c) {} }",
+        ),
+        (
+            "if (foo) {} else { if (bar) baz(); }\nqux();",
+            "if (foo) {} else if (bar) baz();\nqux();",
+        ),
+        // not fixed
+        (
+            "if (a) {;} else { /* comment */ if (b) {} }",
+            "if (a) {;} else { /* comment */ if (b) {} }",
+        ),
+        (
+            "if (a) {;} else { if (b) {} /* comment */ }",
+            "if (a) {;} else { if (b) {} /* comment */ }",
+        ),
+        (
+            "if (foo) {} else { if (bar) baz() }\nqux();",
+            "if (foo) {} else { if (bar) baz() }\nqux();",
+        ),
+    ];
+
+    Tester::new(NoLonelyIf::NAME, pass, fail)
+        .change_rule_plugin("eslint")
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,460 @@
+use lazy_static::lazy_static;
+use oxc_ast::{
+    ast::{Argument, Expression, RegExpFlags},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use regex::Regex;
+
+use crate::{ast_util::get_regexp_constructor_pattern, context::LintContext, rule::Rule, AstNode};
+
+fn no_misleading_character_class_diagnostic(kind: ProblemKind, span1: Span) -> OxcDiagnostic {
+    let message = match kind {
+        ProblemKind::SurrogatePairWithoutUFlag => {
+            "Unexpected surrogate pair in character class. Use 'u' flag."
+        }
+        ProblemKind::CombiningClass => "Unexpected combined character in character class.",
+        ProblemKind::EmojiModifier => "Unexpected modified Emoji in character class.",
+        ProblemKind::RegionalIndicatorSymbol => "Unexpected national flag in character class.",
+        ProblemKind::Zwj => "Unexpected joined character sequence in character class.",
+    };
+    OxcDiagnostic::warn(message).with_label(span1)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMisleadingCharacterClass {
+    allow_escape: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow characters which are made with multiple code points in character class syntax.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unicode includes characters which are made with multiple code points, such as
+    /// `A` followed by a combining acute accent, or emoji with skin tone
+    /// modifiers. A character class matches each of those code points separately, so
+    /// `/^[A\u0301]$/u.test("A\u0301")` is `false`. Without the `u` flag, astral
+    /// characters such as `\u{1F44D}` are even split into two surrogate code units.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// /^[Á]$/u;
+    /// /^[❇️]$/u;
+    /// /^[👶🏻]$/u;
+    /// /^[🇯🇵]$/u;
+    /// /^[👨‍👩‍👦]$/u;
+    /// /^[👍]$/;
+    /// new RegExp("[🎵]");
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `allowEscape`: when `true`, sequences are allowed if the code point that joins
+    /// them is written as an escape sequence in the pattern, e.g. `/[A\u0301]/` or
+    /// `/[\uD83D\uDC4D]/`. Default `false`.
+    NoMisleadingCharacterClass,
+    suspicious
+);
+
+impl Rule for NoMisleadingCharacterClass {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_escape: value
+                .get(0)
+                .and_then(|config| config.get("allowEscape"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::RegExpLiteral(literal) => {
+                let flags = literal.regex.flags;
+                for (class, kind) in self.find_problems(&literal.regex.pattern, flags) {
+                    // The pattern starts after the opening `/`
+                    #[allow(clippy::cast_possible_truncation)]
+                    let span = Span::new(
+                        literal.span.start + 1 + class.start as u32,
+                        literal.span.start + 1 + class.end as u32,
+                    );
+                    ctx.diagnostic(no_misleading_character_class_diagnostic(kind, span));
+                }
+            }
+            AstKind::NewExpression(new_expr) => {
+                self.check_regexp_constructor(&new_expr.callee, &new_expr.arguments, ctx);
+            }
+            AstKind::CallExpression(call) => {
+                self.check_regexp_constructor(&call.callee, &call.arguments, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoMisleadingCharacterClass {
+    /// Checks `new RegExp("[...]")` and `RegExp("[...]")`.
+    fn check_regexp_constructor<'a>(
+        &self,
+        callee: &Expression<'a>,
+        arguments: &'a oxc_allocator::Vec<'a, Argument<'a>>,
+        ctx: &LintContext<'a>,
+    ) {
+        let Some((pattern, flags)) = get_regexp_constructor_pattern(callee, arguments, ctx) else {
+            return;
+        };
+        if flags.is_none() && arguments.len() > 1 {
+            // The flags are unknown
+            return;
+        }
+        if let Some((_, kind)) = self
+            .find_problems(&pattern.value, flags.unwrap_or(RegExpFlags::empty()))
+            .into_iter()
+            .next()
+        {
+            ctx.diagnostic(no_misleading_character_class_diagnostic(kind, pattern.span));
+        }
+    }
+
+    /// Returns the byte range in the pattern of each misleading character class.
+    fn find_problems(
+        &self,
+        pattern: &str,
+        flags: RegExpFlags,
+    ) -> Vec<(std::ops::Range<usize>, ProblemKind)> {
+        let unicode = flags.intersects(RegExpFlags::U | RegExpFlags::V);
+        let mut problems = vec![];
+        for class in CharacterClassParser::new(pattern, unicode, flags.contains(RegExpFlags::V)) {
+            let kind = class
+                .sequences
+                .iter()
+                .find_map(|sequence| find_problem(sequence, unicode, self.allow_escape));
+            if let Some(kind) = kind {
+                problems.push((class.range, kind));
+            }
+        }
+        problems
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProblemKind {
+    SurrogatePairWithoutUFlag,
+    CombiningClass,
+    EmojiModifier,
+    RegionalIndicatorSymbol,
+    Zwj,
+}
+
+/// A code point, or a code unit without the `u` flag, inside a character class.
+#[derive(Debug, Clone, Copy)]
+struct ClassChar {
+    value: u32,
+    /// Whether it is written as an escape sequence, e.g. `\u0301`
+    escaped: bool,
+}
+
+struct CharacterClass {
+    range: std::ops::Range<usize>,
+    /// Runs of adjacent characters, split by ranges and sets such as `\d`
+    sequences: Vec<Vec<ClassChar>>,
+}
+
+fn find_problem(sequence: &[ClassChar], unicode: bool, allow_escape: bool) -> Option<ProblemKind> {
+    lazy_static! {
+        static ref MARK: Regex = Regex::new(r"^\p{M}$").unwrap();
+    }
+    let is_mark = |c: &ClassChar| {
+        char::from_u32(c.value).is_some_and(|c| MARK.is_match(c.encode_utf8(&mut [0; 4])))
+    };
+    let is_high_surrogate = |c: &ClassChar| (0xD800..=0xDBFF).contains(&c.value);
+    let is_low_surrogate = |c: &ClassChar| (0xDC00..=0xDFFF).contains(&c.value);
+    let is_emoji_modifier = |c: &ClassChar| (0x1F3FB..=0x1F3FF).contains(&c.value);
+    let is_regional_indicator = |c: &ClassChar| (0x1F1E6..=0x1F1FF).contains(&c.value);
+
+    // With `allowEscape`, a sequence is fine when its joining code point is escaped
+    let pairs = || {
+        sequence
+            .windows(2)
+            .filter(|pair| !(allow_escape && pair[1].escaped))
+            .map(|pair| (&pair[0], &pair[1]))
+    };
+    if !unicode && pairs().any(|(a, b)| is_high_surrogate(a) && is_low_surrogate(b)) {
+        return Some(ProblemKind::SurrogatePairWithoutUFlag);
+    }
+    if pairs().any(|(a, b)| is_mark(b) && !is_mark(a)) {
+        return Some(ProblemKind::CombiningClass);
+    }
+    if pairs().any(|(a, b)| is_emoji_modifier(b) && !is_emoji_modifier(a)) {
+        return Some(ProblemKind::EmojiModifier);
+    }
+    if pairs().any(|(a, b)| is_regional_indicator(a) && is_regional_indicator(b)) {
+        return Some(ProblemKind::RegionalIndicatorSymbol);
+    }
+    let is_zwj = |c: &ClassChar| c.value == 0x200D && !(allow_escape && c.escaped);
+    if sequence.len() > 2 && sequence[1..sequence.len() - 1].iter().any(is_zwj) {
+        return Some(ProblemKind::Zwj);
+    }
+    None
+}
+
+/// A minimal scanner for the character classes of a regular expression pattern.
+struct CharacterClassParser<'a> {
+    pattern: &'a str,
+    position: usize,
+    unicode: bool,
+    unicode_sets: bool,
+}
+
+impl<'a> CharacterClassParser<'a> {
+    fn new(pattern: &'a str, unicode: bool, unicode_sets: bool) -> Self {
+        Self { pattern, position: 0, unicode, unicode_sets }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.pattern[self.position..].chars().next()
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.position += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Reads up to `max` hex digits, or exactly `max` when `exact` is set.
+    fn read_hex(&mut self, max: usize, exact: bool) -> Option<u32> {
+        let rest = &self.pattern[self.position..];
+        let len = rest.bytes().take(max).take_while(u8::is_ascii_hexdigit).count();
+        if len == 0 || (exact && len != max) {
+            return None;
+        }
+        let value = u32::from_str_radix(&rest[..len], 16).ok()?;
+        self.position += len;
+        Some(value)
+    }
+
+    /// Parses the rest of a character class after its opening `[`.
+    fn parse_class(&mut self, start: usize) -> CharacterClass {
+        let mut sequences = vec![];
+        let mut sequence = vec![];
+        let mut depth = 0;
+        let mut range_pending = false;
+        self.eat('^');
+        while let Some(c) = self.next_char() {
+            let atom = match c {
+                ']' if depth == 0 => break,
+                ']' => {
+                    depth -= 1;
+                    None
+                }
+                '[' if self.unicode_sets => {
+                    depth += 1;
+                    self.eat('^');
+                    None
+                }
+                '-' if !sequence.is_empty() && !range_pending && self.peek() != Some(']') => {
+                    range_pending = true;
+                    continue;
+                }
+                '\\' => self.parse_escape(&mut sequence),
+                c => {
+                    let value = c as u32;
+                    if !self.unicode && value > 0xFFFF {
+                        // Split into surrogates, as the pattern is matched by code units
+                        let mut units = [0; 2];
+                        c.encode_utf16(&mut units);
+                        sequence.push(ClassChar { value: u32::from(units[0]), escaped: false });
+                        Some(ClassChar { value: u32::from(units[1]), escaped: false })
+                    } else {
+                        Some(ClassChar { value, escaped: false })
+                    }
+                }
+            };
+            match atom {
+                Some(atom) if range_pending => {
+                    // `a-z` ends the sequence before it and starts a new one
+                    sequences.push(std::mem::take(&mut sequence));
+                    sequence.push(atom);
+                }
+                Some(atom) => sequence.push(atom),
+                None => {
+                    if !sequence.is_empty() {
+                        sequences.push(std::mem::take(&mut sequence));
+                    }
+                }
+            }
+            range_pending = false;
+        }
+        if !sequence.is_empty() {
+            sequences.push(sequence);
+        }
+        CharacterClass { range: start..self.position, sequences }
+    }
+
+    /// Parses an escape after its `\`, returning `None` for sets such as `\d`. Leading code
+    /// units of the escape are pushed to `sequence`.
+    fn parse_escape(&mut self, sequence: &mut Vec<ClassChar>) -> Option<ClassChar> {
+        let c = self.next_char()?;
+        let value = match c {
+            'd' | 'D' | 'w' | 'W' | 's' | 'S' => return None,
+            'p' | 'P' | 'q' if self.unicode && self.peek() == Some('{') => {
+                let rest = &self.pattern[self.position..];
+                self.position += rest.find('}').map_or(rest.len(), |end| end + 1);
+                return None;
+            }
+            'u' if self.unicode && self.eat('{') => {
+                let value = self.read_hex(6, false);
+                self.eat('}');
+                value.unwrap_or('u' as u32)
+            }
+            'u' => match self.read_hex(4, true) {
+                Some(high @ 0xD800..=0xDBFF) if self.unicode => {
+                    let position = self.position;
+                    let low = if self.pattern[position..].starts_with("\\u") {
+                        self.position += 2;
+                        self.read_hex(4, true).filter(|low| (0xDC00..=0xDFFF).contains(low))
+                    } else {
+                        None
+                    };
+                    if let Some(low) = low {
+                        0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                    } else {
+                        self.position = position;
+                        high
+                    }
+                }
+                Some(value) => value,
+                None => 'u' as u32,
+            },
+            'x' => self.read_hex(2, true).unwrap_or('x' as u32),
+            'c' => match self.peek() {
+                Some(letter) if letter.is_ascii_alphabetic() => {
+                    self.position += 1;
+                    letter as u32 % 32
+                }
+                _ => '\\' as u32,
+            },
+            '0'..='7' => {
+                let rest = &self.pattern[self.position..];
+                let len = if self.unicode {
+                    0
+                } else {
+                    rest.bytes().take(2).take_while(|b| matches!(b, b'0'..=b'7')).count()
+                };
+                self.position += len;
+                let digits = &self.pattern[self.position - len - 1..self.position];
+                u32::from_str_radix(digits, 8).unwrap_or_default()
+            }
+            'b' => 0x08,
+            't' => 0x09,
+            'n' => 0x0A,
+            'v' => 0x0B,
+            'f' => 0x0C,
+            'r' => 0x0D,
+            c if !self.unicode && c as u32 > 0xFFFF => {
+                // `\👍` only escapes the high surrogate
+                let mut units = [0; 2];
+                c.encode_utf16(&mut units);
+                sequence.push(ClassChar { value: u32::from(units[0]), escaped: true });
+                return Some(ClassChar { value: u32::from(units[1]), escaped: false });
+            }
+            c => c as u32,
+        };
+        Some(ClassChar { value, escaped: true })
+    }
+}
+
+impl<'a> Iterator for CharacterClassParser<'a> {
+    type Item = CharacterClass;
+
+    fn next(&mut self) -> Option<CharacterClass> {
+        while let Some(c) = self.next_char() {
+            match c {
+                '\\' => {
+                    self.next_char();
+                }
+                '[' => return Some(self.parse_class(self.position - 1)),
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var r = /[👍]/u", None),
+        (r"var r = /[\uD83D\uDC4D]/u", None),
+        (r"var r = /[\u{1F44D}]/u", None),
+        ("var r = /❇️/", None),
+        ("var r = /A\u{301}/", None),
+        ("var r = /[❇]/", None),
+        ("var r = /👶🏻/u", None),
+        ("var r = /[👶]/u", None),
+        ("var r = /🇯🇵/", None),
+        ("var r = /[JP]/", None),
+        ("var r = /👨‍👩‍👦/", None),
+        (r"var r = /[\uD83D]/", None),
+        (r"var r = /[\uDC4D]/", None),
+        (r"var r = /[\uD83D]/u", None),
+        (r"var r = /[\u0301]/", None),
+        ("var r = /[A-\u{301}]/u", None),
+        ("var r = /\\[A\u{301}]/", None),
+        (r"var r = /[\q{👶🏻}]/v", None),
+        ("var r = /[\\d\u{301}]/u", None),
+        ("new RegExp('[👍]', 'u')", None),
+        ("new RegExp('[👍]', flags)", None),
+        ("var RegExp; new RegExp('[👍]')", None),
+        ("new RegExp('👍')", None),
+        (r"var r = /[A\u0301]/", Some(serde_json::json!([{ "allowEscape": true }]))),
+        (r"var r = /[\uD83D\uDC4D]/", Some(serde_json::json!([{ "allowEscape": true }]))),
+        (r"var r = /[👶\u{1f3fb}]/u", Some(serde_json::json!([{ "allowEscape": true }]))),
+        (r"var r = /[\u{1F1EF}\u{1F1F5}]/u", Some(serde_json::json!([{ "allowEscape": true }]))),
+        (r"var r = /[👨\u200d👩]/u", Some(serde_json::json!([{ "allowEscape": true }]))),
+        (r"var r = /[\u00B7\u0300-\u036F]/u", Some(serde_json::json!([{ "allowEscape": true }]))),
+    ];
+
+    let fail = vec![
+        ("var r = /[👍]/", None),
+        (r"var r = /[\uD83D\uDC4D]/", None),
+        ("var r = /[A\u{301}]/", None),
+        ("var r = /[A\u{301}]/u", None),
+        ("var r = /[^A\u{301}]/u", None),
+        ("var r = /[❇️]/", None),
+        ("var r = /[👶🏻]/u", None),
+        ("var r = /[🇯🇵]/u", None),
+        ("var r = /[👨‍👩‍👦]/u", None),
+        ("var r = /[👨‍👩‍👦]/", None),
+        (r"var r = /[A\u0301]/", None),
+        (r"var r = /[\u00B7\u0300-\u036F]/u", None),
+        ("var r = /[a][👍]/", None),
+        ("var r = /[👍][A\u{301}]/u", None),
+        ("var r = /[[👶🏻]]/v", None),
+        ("new RegExp(\"[👍]\")", None),
+        ("RegExp(\"[👍]\")", None),
+        ("new RegExp('[A\u{301}]', 'u')", None),
+        ("new RegExp(\"[🎵]\", \"\")", None),
+        (r"var r = /[\👍]/", Some(serde_json::json!([{ "allowEscape": true }]))),
+        ("var r = /[A\u{301}]/", Some(serde_json::json!([{ "allowEscape": true }]))),
+        (r#"new RegExp("[A\u0301]")"#, Some(serde_json::json!([{ "allowEscape": true }]))),
+    ];
+
+    Tester::new(NoMisleadingCharacterClass::NAME, pass, fail).test_and_snapshot();
+}
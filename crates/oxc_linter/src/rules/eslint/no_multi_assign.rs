@@ -0,0 +1,127 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{ast_util::outermost_paren_parent, context::LintContext, rule::Rule, AstNode};
+
+fn no_multi_assign_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected chained assignment.")
+        .with_help("Separate each assignment into its own statement.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMultiAssign {
+    ignore_non_declaration: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow use of chained assignment expressions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Chaining the assignment of variables can lead to unexpected results and be
+    /// difficult to read. For example, in `const a = b = c = 1;` only `a` is declared
+    /// with `const`, while `b` and `c` are assigned to outer or global variables.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var a = b = c = 5;
+    ///
+    /// const foo = bar = "baz";
+    ///
+    /// a = b = c;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `ignoreNonDeclaration` (default `false`): only report chained assignments in
+    /// variable declarations and class fields, allowing e.g. `a = b = c;`.
+    NoMultiAssign,
+    style
+);
+
+impl Rule for NoMultiAssign {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            ignore_non_declaration: value
+                .get(0)
+                .and_then(|config| config.get("ignoreNonDeclaration"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::AssignmentExpression(_) = node.kind() else {
+            return;
+        };
+        let Some(parent) = outermost_paren_parent(node, ctx) else {
+            return;
+        };
+        let outer_span = match parent.kind() {
+            AstKind::VariableDeclarator(decl) => decl.span,
+            AstKind::PropertyDefinition(prop) => prop.span,
+            AstKind::AssignmentExpression(expr) if !self.ignore_non_declaration => expr.span(),
+            _ => return,
+        };
+        ctx.diagnostic(no_multi_assign_diagnostic(outer_span));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("var a, b, c,\nd = 0;", None),
+        ("var a = 1; var b = 2; var c = 3;\nvar d = 0;", None),
+        ("var a = 1 + (b === 10 ? 5 : 4);", None),
+        ("const a = 1, b = 2, c = 3;", None),
+        ("const a = 1;\nconst b = 2;\n const c = 3;", None),
+        ("for(var a = 0, b = 0;;){}", None),
+        ("for(let a = 0, b = 0;;){}", None),
+        ("for(const a = 0, b = 0;;){}", None),
+        ("export let a, b;", None),
+        ("export let a,\n b = 0;", None),
+        (
+            "const x = {};const y = {};x.one = y.one = 1;",
+            Some(json!([{ "ignoreNonDeclaration": true }])),
+        ),
+        ("let a, b;a = b = 1", Some(json!([{ "ignoreNonDeclaration": true }]))),
+        ("class C { [foo = 0] = 0 }", None),
+        ("a = b === c", None),
+        ("foo(a = b)", None),
+        ("a = () => b = c", None),
+    ];
+
+    let fail = vec![
+        ("var a = b = c;", None),
+        ("var a = b = c = d;", None),
+        ("let foo = bar = cee = 100;", None),
+        ("a=b=c=d=e", None),
+        ("a=b=c", None),
+        ("a\n=b\n=c", None),
+        ("var a = (b) = (((c)))", None),
+        ("var a = ((b)) = (c)", None),
+        ("var a = b = ( (c * 12) + 2)", None),
+        ("var a =\n((b))\n = (c)", None),
+        ("a = b = '=' + c + 'foo';", None),
+        ("a = b = 7 * 12 + 5;", None),
+        ("const x = {};\nconst y = x.one = 1;", Some(json!([{ "ignoreNonDeclaration": true }]))),
+        ("let a, b;a = b = 1", Some(json!([{}]))),
+        ("let x, y;x = y = 'baz'", Some(json!([{ "ignoreNonDeclaration": false }]))),
+        ("const a = b = 1", Some(json!([{ "ignoreNonDeclaration": true }]))),
+        ("class C { field = foo = 0 }", None),
+        ("class C { field = foo = 0 }", Some(json!([{ "ignoreNonDeclaration": true }]))),
+        ("a.b = c[d] = e.f = 1", None),
+        ("let x = foo.bar = baz = 1", None),
+        ("obj[key] = (value = 5)", None),
+    ];
+
+    Tester::new(NoMultiAssign::NAME, pass, fail).test_and_snapshot();
+}
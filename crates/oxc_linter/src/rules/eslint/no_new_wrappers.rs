@@ -38,7 +38,7 @@ impl Rule for NoNewWrappers {
         let AstKind::NewExpression(expr) = node.kind() else {
             return;
         };
-        let Expression::Identifier(ident) = &expr.callee else {
+        let Expression::Identifier(ident) = expr.callee.without_parenthesized() else {
             return;
         };
         if (ident.name == "String" || ident.name == "Number" || ident.name == "Boolean")
@@ -56,6 +56,9 @@ fn test() {
     let pass = vec![
         "var a = new Object();",
         "var a = String('test'), b = String.fromCharCode(32);",
+        "var a = Number(x), b = Boolean(x);",
+        "var a = new foo.String('test');",
+        "const String = CustomString; const a = new String('test');",
         "function test(Number) { return new Number; }",
         r#"
             import String from "./string";
@@ -82,6 +85,8 @@ fn test() {
         "var a = new String('hello');",
         "var a = new Number(10);",
         "var a = new Boolean(false);",
+        "var a = new (String)('hello');",
+        "var a = new Number;",
         "
             const a = new String('bar');
             {
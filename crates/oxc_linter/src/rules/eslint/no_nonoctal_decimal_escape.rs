@@ -38,6 +38,9 @@ declare_oxc_lint!(
     /// "8"
     /// "\\9"
     /// ```
+    ///
+    /// A suggestion is provided to remove the backslash, e.g. `"\8"` to `"8"`, which
+    /// keeps the current value of the string.
     NoNonoctalDecimalEscape,
     correctness
 );
@@ -45,7 +48,7 @@ declare_oxc_lint!(
 impl Rule for NoNonoctalDecimalEscape {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::StringLiteral(literal) = node.kind() {
-            check_string(ctx, literal.span.source_text(ctx.source_text()));
+            check_string(ctx, literal.span);
         }
     }
 }
@@ -82,12 +85,13 @@ fn quick_test(s: &str) -> bool {
 }
 
 #[allow(clippy::cast_possible_truncation)]
-fn check_string(ctx: &LintContext<'_>, string: &str) {
+fn check_string(ctx: &LintContext<'_>, literal_span: Span) {
     lazy_static! {
         static ref NONOCTAL_REGEX: Regex =
             Regex::new(r"(?:[^\\]|(?P<previousEscape>\\.))*?(?P<decimalEscape>\\[89])").unwrap();
     }
 
+    let string = literal_span.source_text(ctx.source_text());
     // Need at least 2 characters
     if string.len() <= 1 {
         return;
@@ -101,25 +105,31 @@ fn check_string(ctx: &LintContext<'_>, string: &str) {
     while let (Some(captures), new_start) = NONOCTAL_REGEX.sticky_captures(string, start) {
         let previous_escape = captures.name("previousEscape");
         let decimal_escape = captures.name("decimalEscape").unwrap();
-        let decimal_escape_span =
-            Span::new(decimal_escape.start() as u32, decimal_escape.end() as u32);
+        let decimal_escape_span = Span::new(
+            literal_span.start + decimal_escape.start() as u32,
+            literal_span.start + decimal_escape.end() as u32,
+        );
         let decimal_escape_str = decimal_escape.as_str();
-
-        if let Some(prev_match) = previous_escape {
-            if prev_match.as_str().eq("\\0") {
-                ctx.diagnostic(replacement(
-                    &(prev_match.as_str().to_string() + decimal_escape_str),
-                    "\\u00008",
-                    Span::new(prev_match.start() as u32, decimal_escape_span.end),
-                ));
-                ctx.diagnostic(replacement(decimal_escape_str, "\\u0038", decimal_escape_span));
-            }
-        } else {
-            ctx.diagnostic(replacement(
+        let digit = &decimal_escape_str[1..];
+
+        if let Some(prev_match) = previous_escape.filter(|m| m.as_str() == "\\0") {
+            // `\0\8` can't become `\08`, which would be an octal escape
+            let span =
+                Span::new(literal_span.start + prev_match.start() as u32, decimal_escape_span.end);
+            report_replacement(
+                ctx,
+                &(prev_match.as_str().to_string() + decimal_escape_str),
+                &format!("\\u0000{digit}"),
+                span,
+            );
+            report_replacement(
+                ctx,
                 decimal_escape_str,
-                &decimal_escape_str[1..],
+                &format!("\\u003{digit}"),
                 decimal_escape_span,
-            ));
+            );
+        } else {
+            report_replacement(ctx, decimal_escape_str, digit, decimal_escape_span);
         }
 
         ctx.diagnostic(escape_backslash(
@@ -132,6 +142,14 @@ fn check_string(ctx: &LintContext<'_>, string: &str) {
     }
 }
 
+/// Reports an escape sequence along with a suggestion which keeps the current value.
+fn report_replacement(ctx: &LintContext<'_>, escape: &str, replacement_str: &str, span: Span) {
+    let fixed = replacement_str.to_string();
+    ctx.diagnostic_with_suggestion(replacement(escape, replacement_str, span), |fixer| {
+        fixer.replace(span, fixed)
+    });
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
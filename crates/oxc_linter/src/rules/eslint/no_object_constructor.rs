@@ -0,0 +1,141 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    ast_util::{get_starting_expression_statement, is_global_reference, needs_leading_semicolon},
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+fn no_object_constructor_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("The object literal notation `{}` is preferable.").with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoObjectConstructor;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow calls to the `Object` constructor without an argument.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Use of the `Object` constructor to construct a new empty object is generally
+    /// discouraged in favor of object literal notation because of conciseness and
+    /// because the `Object` global may be redefined.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// Object();
+    ///
+    /// new Object();
+    /// ```
+    NoObjectConstructor,
+    pedantic
+);
+
+impl Rule for NoObjectConstructor {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (span, callee, arguments) = match node.kind() {
+            AstKind::CallExpression(call) => (call.span, &call.callee, &call.arguments),
+            AstKind::NewExpression(new) => (new.span, &new.callee, &new.arguments),
+            _ => return,
+        };
+        if !arguments.is_empty() {
+            return;
+        }
+        let Expression::Identifier(ident) = callee.without_parenthesized() else {
+            return;
+        };
+        if ident.name != "Object" || !is_global_reference(ident, ctx) {
+            return;
+        }
+
+        let diagnostic = no_object_constructor_diagnostic(span);
+        if ctx.semantic().trivias().has_comments_between(span) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(span, get_fix(node, ctx)));
+    }
+}
+
+/// Returns `{}`, wrapped in parentheses if it would otherwise be parsed as a block.
+fn get_fix<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> &'static str {
+    if get_starting_expression_statement(node, ctx).is_none() {
+        return "{}";
+    }
+    // Without a semicolon, the parentheses would call the end of the previous statement
+    if needs_leading_semicolon(node, ctx) {
+        ";({})"
+    } else {
+        "({})"
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "new Object(x)",
+        "Object(x)",
+        "new globalThis.Object",
+        "const createObject = Object => new Object()",
+        "var Object; new Object;",
+        "
+            function foo() {
+                const Object = CustomObject;
+                return Object();
+            }
+        ",
+        "String()",
+        "new foo.Object()",
+        "Object.create(null)",
+    ];
+
+    let fail = vec![
+        "new Object",
+        "Object()",
+        "const fn = () => Object();",
+        "Object() instanceof Object;",
+        "const obj = Object?.();",
+        "(new Object() instanceof Object);",
+        "new (Object)();",
+        "foo(new Object())",
+        "
+            function foo() {
+                return new Object();
+            }
+        ",
+        "if (foo) { Object() }",
+        "foo\nObject()",
+        "Object(/* comment */)",
+    ];
+
+    let fix = vec![
+        ("new Object", "({})"),
+        ("Object()", "({})"),
+        ("var x = Object();", "var x = {};"),
+        ("const fn = () => Object();", "const fn = () => ({});"),
+        ("const fn = () => { return Object(); };", "const fn = () => { return {}; };"),
+        ("Object() instanceof Object;", "({}) instanceof Object;"),
+        ("const obj = Object?.();", "const obj = {};"),
+        ("(new Object() instanceof Object);", "({} instanceof Object);"),
+        ("new (Object)();", "({});"),
+        ("foo(new Object())", "foo({})"),
+        ("if (foo) { Object() }", "if (foo) { ({}) }"),
+        ("foo;\nObject()", "foo;\n({})"),
+        ("foo\nObject()", "foo\n;({})"),
+        ("const k = function () {}\nObject()", "const k = function () {}\n;({})"),
+        ("foo()\nnew Object().bar = 1", "foo()\n;({}).bar = 1"),
+        // not fixed
+        ("Object(/* comment */)", "Object(/* comment */)"),
+    ];
+
+    Tester::new(NoObjectConstructor::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
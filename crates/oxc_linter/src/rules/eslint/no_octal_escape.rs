@@ -0,0 +1,192 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_octal_escape_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Don't use octal: '\\{x0}'. Use '\\u....' instead."))
+        .with_label(span1)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoOctalEscape;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow octal escape sequences in string literals.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// As of the ECMAScript 5 specification, octal escape sequences in string literals
+    /// are deprecated and should not be used. Unicode escape sequences should be used
+    /// instead.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var foo = "Copyright \251";
+    ///
+    /// // Good
+    /// var foo = "Copyright \u00A9";
+    /// ```
+    NoOctalEscape,
+    restriction
+);
+
+impl Rule for NoOctalEscape {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::StringLiteral(literal) = node.kind() else {
+            return;
+        };
+        // The cooked value no longer tells which escape sequences were used
+        let raw = ctx.source_range(literal.span);
+        if let Some((start, octal)) = find_octal_escape(raw) {
+            #[allow(clippy::cast_possible_truncation)]
+            let start = literal.span.start + start as u32;
+            #[allow(clippy::cast_possible_truncation)]
+            let span = Span::new(start, start + 1 + octal.len() as u32);
+            ctx.diagnostic(no_octal_escape_diagnostic(octal, span));
+        }
+    }
+}
+
+/// Returns the offset of the first octal escape sequence in the raw string and its
+/// digits. `\0` is only an octal escape when followed by another digit.
+fn find_octal_escape(raw: &str) -> Option<(usize, &str)> {
+    let is_octal = |b: &u8| matches!(b, b'0'..=b'7');
+    let bytes = raw.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'\\' {
+            i += 1;
+            continue;
+        }
+        let digits_start = i + 1;
+        // `\0`-`\3` take up to two more digits, `\4`-`\7` only one more (up to `\377`)
+        let max_len = match bytes.get(digits_start) {
+            Some(b'0'..=b'3') => 3,
+            Some(b'4'..=b'7') => 2,
+            _ => {
+                // Skip the escaped character, e.g. the second backslash in `\\1`
+                i += 2;
+                continue;
+            }
+        };
+        let len = bytes[digits_start..].iter().take(max_len).take_while(|b| is_octal(b)).count();
+        let digits = &raw[digits_start..digits_start + len];
+        if digits != "0" || matches!(bytes.get(digits_start + 1), Some(b'8' | b'9')) {
+            return Some((i, digits));
+        }
+        i = digits_start + len;
+    }
+    None
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"var foo = "\x51";"#,
+        r#"var foo = "foo \\251 bar";"#,
+        r"var foo = /([abc]) \1/g;",
+        r"var foo = '\0';",
+        r"'\0'",
+        r"'\8'",
+        r"'\9'",
+        r"'\0 '",
+        r"' \0'",
+        r"'a\0'",
+        r"'\0a'",
+        r"'a\8a'",
+        r"'\0\8'",
+        r"'\8\0'",
+        r"'\80'",
+        r"'\81'",
+        r"'\\'",
+        r"'\\0'",
+        r"'\\01'",
+        r"'\\08'",
+        r"'\\1'",
+        r"'\\12'",
+        r"'\\\0'",
+        r"'\\\8'",
+        r"'\0\\'",
+        r"'0'",
+        r"'1'",
+        r"'8'",
+        r"'01'",
+        r"'08'",
+        r"'80'",
+        r"'12'",
+        r"'\a'",
+        r"'\n'",
+    ];
+
+    let fail = vec![
+        r#"var foo = "foo \01 bar";"#,
+        r#"var foo = "foo \000 bar";"#,
+        r#"var foo = "foo \377 bar";"#,
+        r#"var foo = "foo \378 bar";"#,
+        r#"var foo = "foo \37a bar";"#,
+        r#"var foo = "foo \381 bar";"#,
+        r#"var foo = "foo \3a1 bar";"#,
+        r#"var foo = "foo \251 bar";"#,
+        r#"var foo = "foo \258 bar";"#,
+        r#"var foo = "foo \25a bar";"#,
+        r#"var foo = "\3s51";"#,
+        r#"var foo = "\77";"#,
+        r#"var foo = "\78";"#,
+        r#"var foo = "\5a";"#,
+        r#"var foo = "\751";"#,
+        r#"var foo = "foo \400 bar";"#,
+        r#"var foo = "\t\1";"#,
+        r#"var foo = "\\\751";"#,
+        r"'\0\1'",
+        r"'\0 \1'",
+        r"'\0\01'",
+        r"'\0 \01'",
+        r"'\0a\1'",
+        r"'\0a\01'",
+        r"'\0\08'",
+        r"'\1'",
+        r"'\2'",
+        r"'\7'",
+        r"'\00'",
+        r"'\01'",
+        r"'\02'",
+        r"'\07'",
+        r"'\08'",
+        r"'\09'",
+        r"'\10'",
+        r"'\12'",
+        r"' \1'",
+        r"'\1 '",
+        r"'a\1'",
+        r"'\1a'",
+        r"'a\1a'",
+        r"' \01'",
+        r"'\01 '",
+        r"'a\01'",
+        r"'\01a'",
+        r"'a\01a'",
+        r"'a\08a'",
+        r"'\n\1'",
+        r"'\n\01'",
+        r"'\n\08'",
+        r"'\\\1'",
+        r"'\\\01'",
+        r"'\\\08'",
+        r"'\\n\1'",
+        r"'\01\02'",
+        r"'\02\01'",
+        r"'\01\2'",
+        r"'\2\01'",
+        r"'\08\1'",
+        r"'foo \1 bar \2'",
+    ];
+
+    Tester::new(NoOctalEscape::NAME, pass, fail).test_and_snapshot();
+}
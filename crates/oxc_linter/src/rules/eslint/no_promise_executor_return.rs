@@ -0,0 +1,259 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{operator::UnaryOperator, precedence::Precedence};
+
+use crate::{
+    ast_util::{get_precedence, is_global_reference},
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+fn no_promise_executor_return_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Return values from promise executor functions cannot be read.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoPromiseExecutorReturn {
+    allow_void: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow returning values from Promise executor functions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The `new Promise` constructor accepts a single argument, called an executor,
+    /// whose return value is ignored. Returning a value from an executor is a possible
+    /// error, because the returned value cannot be used and it doesn't affect the
+    /// promise in any way.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// new Promise((resolve, reject) => {
+    ///     if (someCondition) {
+    ///         return defaultResult;
+    ///     }
+    ///     getSomething((err, result) => {
+    ///         if (err) {
+    ///             reject(err);
+    ///         } else {
+    ///             resolve(result);
+    ///         }
+    ///     });
+    /// });
+    ///
+    /// new Promise((resolve, reject) => getSomething((err, data) => {
+    ///     if (err) {
+    ///         reject(err);
+    ///     } else {
+    ///         resolve(data);
+    ///     }
+    /// }));
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `allowVoid`: when `true`, returning a `void` expression such as
+    /// `return void resolve(1)` is allowed. Default `false`.
+    NoPromiseExecutorReturn,
+    suspicious
+);
+
+impl Rule for NoPromiseExecutorReturn {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_void: value
+                .get(0)
+                .and_then(|config| config.get("allowVoid"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ReturnStatement(return_stmt) => {
+                let Some(argument) = &return_stmt.argument else {
+                    return;
+                };
+                let Some(function) = ctx.nodes().iter_parents(node.id()).skip(1).find(|parent| {
+                    matches!(
+                        parent.kind(),
+                        AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+                    )
+                }) else {
+                    return;
+                };
+                if !is_promise_executor(function, ctx) {
+                    return;
+                }
+                let diagnostic = no_promise_executor_return_diagnostic(return_stmt.span);
+                if !self.allow_void {
+                    ctx.diagnostic(diagnostic);
+                } else if !is_void(argument) {
+                    ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                        fixer
+                            .replace(argument.span(), prepend_void(argument, ctx))
+                            .with_message("Prepend `void` to the expression")
+                    });
+                }
+            }
+            AstKind::ArrowFunctionExpression(arrow) if arrow.expression => {
+                let Some(expr) = arrow.get_expression() else {
+                    return;
+                };
+                if (self.allow_void && is_void(expr)) || !is_promise_executor(node, ctx) {
+                    return;
+                }
+                let diagnostic = no_promise_executor_return_diagnostic(expr.span());
+                if self.allow_void {
+                    ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                        fixer
+                            .replace(expr.span(), prepend_void(expr, ctx))
+                            .with_message("Prepend `void` to the expression")
+                    });
+                } else if matches!(expr, Expression::FunctionExpression(func) if func.id.is_none())
+                {
+                    // `{function () {}}` would be an invalid function declaration
+                    ctx.diagnostic(diagnostic);
+                } else {
+                    ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                        let text = ctx.source_range(expr.span());
+                        fixer
+                            .replace(expr.span(), format!("{{{text}}}"))
+                            .with_message("Wrap the expression in `{}`")
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Checks if the function is the first argument of `new Promise(...)`, where
+/// `Promise` is the global.
+fn is_promise_executor(node: &AstNode, ctx: &LintContext) -> bool {
+    for parent in ctx.nodes().iter_parents(node.id()).skip(1) {
+        match parent.kind() {
+            AstKind::ParenthesizedExpression(_) | AstKind::Argument(_) => {}
+            AstKind::NewExpression(new_expr) => {
+                let Expression::Identifier(callee) = new_expr.callee.without_parenthesized() else {
+                    return false;
+                };
+                return callee.name == "Promise"
+                    && is_global_reference(callee, ctx)
+                    && new_expr.arguments.first().and_then(Argument::as_expression).is_some_and(
+                        |executor| executor.without_parenthesized().span() == node.kind().span(),
+                    );
+            }
+            _ => return false,
+        }
+    }
+    false
+}
+
+fn is_void(expr: &Expression) -> bool {
+    matches!(
+        expr.without_parenthesized(),
+        Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::Void
+    )
+}
+
+fn prepend_void(expr: &Expression, ctx: &LintContext) -> String {
+    let text = ctx.source_range(expr.span());
+    // e.g. `return(foo)` or `=>foo`
+    let space = if ctx.source_text()[..expr.span().start as usize].ends_with(char::is_whitespace) {
+        ""
+    } else {
+        " "
+    };
+    if get_precedence(expr) < Precedence::Prefix {
+        format!("{space}void ({text})")
+    } else {
+        format!("{space}void {text}")
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo(resolve, reject) { return 1; }", None),
+        ("function Promise(resolve, reject) { return 1; }", None),
+        ("(function (resolve, reject) { return 1; })", None),
+        ("(function foo(resolve, reject) { return 1; })", None),
+        ("((resolve, reject) => { return 1; })", None),
+        ("new Promise(function (resolve, reject) {})", None),
+        ("new Promise(function (resolve, reject) { return; })", None),
+        ("new Promise((resolve, reject) => { return; })", None),
+        ("new Promise((resolve, reject) => {})", None),
+        ("new Promise(() => {})", None),
+        ("new Promise(function (resolve, reject) { if (foo) { return; } })", None),
+        ("new Promise(function (resolve, reject) { function foo() { return 1; } })", None),
+        ("new Promise((resolve, reject) => { function foo() { return 1; } })", None),
+        ("new Promise((resolve, reject) => { const foo = () => 1; })", None),
+        ("new Promise((resolve, reject) => { class C { foo() { return 1; } } })", None),
+        ("new Promise(function (resolve, reject) { return; function foo() { return 1; } })", None),
+        ("new Foo(function (resolve, reject) { return 1; })", None),
+        ("new foo.Promise(function (resolve, reject) { return 1; })", None),
+        ("new Promise(foo, function (resolve, reject) { return 1; })", None),
+        ("new Promise(foo, (resolve, reject) => 1)", None),
+        ("Promise(function (resolve, reject) { return 1; })", None),
+        ("Promise((resolve, reject) => 1)", None),
+        ("let Promise; new Promise(function (resolve, reject) { return 1; })", None),
+        ("function foo(Promise) { new Promise((resolve, reject) => 1); }", None),
+        ("new Promise(r => void 0)", Some(serde_json::json!([{ "allowVoid": true }]))),
+        ("new Promise(r => (void 0))", Some(serde_json::json!([{ "allowVoid": true }]))),
+        ("new Promise(r => void cbf(r))", Some(serde_json::json!([{ "allowVoid": true }]))),
+        ("new Promise(r => { return void 0 })", Some(serde_json::json!([{ "allowVoid": true }]))),
+        (
+            "new Promise(r => { if (foo) { return void 0 } return void 0 })",
+            Some(serde_json::json!([{ "allowVoid": true }])),
+        ),
+        ("new Promise(r => { return })", Some(serde_json::json!([{ "allowVoid": true }]))),
+    ];
+
+    let fail = vec![
+        ("new Promise(function (resolve, reject) { return 1; })", None),
+        ("new Promise(function foo(resolve, reject) { return 1; })", None),
+        ("new Promise((resolve, reject) => { return 1; })", None),
+        ("new Promise((resolve, reject) => resolve(1))", None),
+        ("new Promise(r => 1)", None),
+        ("new Promise(r => void 0)", None),
+        ("new Promise(r => { return void 0 })", None),
+        ("new Promise(async function (resolve, reject) { return 1; })", None),
+        ("new Promise(function (resolve, reject) { if (foo) { return 1; } })", None),
+        ("new Promise(function (resolve, reject) { try { return 1; } catch {} })", None),
+        (
+            "new Promise((resolve, reject) => { while (foo) { if (bar) break; else return 1; } })",
+            None,
+        ),
+        ("new Promise(function (resolve, reject) { function foo() {} return 1; })", None),
+        ("(new Promise(function (resolve, reject) { return 1; }))", None),
+        ("new (Promise)(function (resolve, reject) { return 1; })", None),
+        ("new Promise((function (resolve, reject) { return 1; }))", None),
+        ("new Promise(() => { return new Promise(() => { return 1 }); })", None),
+        ("new Promise(r => () => {})", None),
+        ("new Promise(r => function () {})", None),
+        ("new Promise(r => function foo() {})", None),
+        ("new Promise(r => ({}))", None),
+        ("function foo() { new Promise((resolve) => resolve(1)); }", None),
+        ("new Promise(r => r(0))", Some(serde_json::json!([{ "allowVoid": true }]))),
+        ("new Promise(r => { return r(0) })", Some(serde_json::json!([{ "allowVoid": true }]))),
+        ("new Promise(r => { return a || b })", Some(serde_json::json!([{ "allowVoid": true }]))),
+        ("new Promise(r => { return(a) })", Some(serde_json::json!([{ "allowVoid": true }]))),
+        ("new Promise(r =>a ** b)", Some(serde_json::json!([{ "allowVoid": true }]))),
+    ];
+
+    Tester::new(NoPromiseExecutorReturn::NAME, pass, fail).test_and_snapshot();
+}
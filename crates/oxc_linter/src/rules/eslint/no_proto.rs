@@ -33,6 +33,9 @@ declare_oxc_lint!(
     ///
     /// obj["__proto__"] = b;
     /// ```
+    ///
+    /// Setting the prototype in an object literal, e.g. `{ __proto__: null }`, is not a
+    /// property access and is allowed.
     NoProto,
     restriction
 );
@@ -44,10 +47,7 @@ impl Rule for NoProto {
         };
         if let Some(static_property_name) = member_expression.static_property_name() {
             if static_property_name == "__proto__" {
-                ctx.diagnostic(no_proto_diagnostic(Span::new(
-                    member_expression.span().start,
-                    member_expression.span().end,
-                )));
+                ctx.diagnostic(no_proto_diagnostic(member_expression.span()));
             }
         }
     }
@@ -64,6 +64,10 @@ fn test() {
         "foo[`__proto__
 			`] = null;",
         "class C { #__proto__; foo() { this.#__proto__; } }",
+        "var a = { __proto__: null };",
+        "var a = { '__proto__': b };",
+        "var { __proto__: a } = obj;",
+        "var a = Object.getPrototypeOf(obj);",
     ];
 
     let fail = vec![
@@ -71,6 +75,11 @@ fn test() {
         "var a = test['__proto__'];",
         "var a = test[`__proto__`];",
         "test[`__proto__`] = function () {};",
+        "obj.__proto__ = b;",
+        "obj['__proto__'] = b;",
+        "var a = obj?.__proto__;",
+        "var a = (obj).__proto__;",
+        "var a = { b: obj.__proto__ };",
     ];
 
     Tester::new(NoProto::NAME, pass, fail).test_and_snapshot();
@@ -36,6 +36,9 @@ declare_oxc_lint!(
     /// ```javascript
     /// var re = /foo   bar/;
     /// ```
+    ///
+    /// The fix replaces the spaces with a quantifier, e.g. `/foo {3}bar/`. Patterns
+    /// in string literals are only fixed when they contain no escape sequences.
     NoRegexSpaces,
     restriction,
 );
@@ -45,19 +48,19 @@ impl Rule for NoRegexSpaces {
         match node.kind() {
             AstKind::RegExpLiteral(lit) => {
                 if let Some(span) = Self::find_literal_to_report(lit) {
-                    ctx.diagnostic(no_regex_spaces_diagnostic(span)); // /a  b/
+                    Self::report(span, ctx); // /a  b/
                 }
             }
 
             AstKind::CallExpression(expr) if Self::is_regexp_call_expression(expr) => {
-                if let Some(span) = Self::find_expr_to_report(&expr.arguments) {
-                    ctx.diagnostic(no_regex_spaces_diagnostic(span)); // RegExp('a  b')
+                if let Some(span) = Self::find_expr_to_report(&expr.arguments, ctx) {
+                    Self::report(span, ctx); // RegExp('a  b')
                 }
             }
 
             AstKind::NewExpression(expr) if Self::is_regexp_new_expression(expr) => {
-                if let Some(span) = Self::find_expr_to_report(&expr.arguments) {
-                    ctx.diagnostic(no_regex_spaces_diagnostic(span)); // new RegExp('a  b')
+                if let Some(span) = Self::find_expr_to_report(&expr.arguments, ctx) {
+                    Self::report(span, ctx); // new RegExp('a  b')
                 }
             }
 
@@ -67,6 +70,23 @@ impl Rule for NoRegexSpaces {
 }
 
 impl NoRegexSpaces {
+    /// Reports the spaces in `span`, fixing them to a quantifier when `span` covers only
+    /// the spaces.
+    fn report(span: Span, ctx: &LintContext) {
+        let text = ctx.source_range(span);
+        if !text.bytes().all(|b| b == b' ') {
+            ctx.diagnostic(no_regex_spaces_diagnostic(span));
+            return;
+        }
+        ctx.diagnostic_with_fix(no_regex_spaces_diagnostic(span), |fixer| {
+            // In `/a   {3}/` the last space belongs to the quantifier
+            let quantified =
+                ctx.source_text()[span.end as usize..].starts_with(['+', '*', '{', '?']);
+            let fix_span = if quantified { Span::new(span.start, span.end - 1) } else { span };
+            fixer.replace(fix_span, format!(" {{{}}}", fix_span.size()))
+        });
+    }
+
     fn find_literal_to_report(literal: &RegExpLiteral) -> Option<Span> {
         if Self::has_exempted_char_class(&literal.regex.pattern) {
             return None;
@@ -84,7 +104,7 @@ impl NoRegexSpaces {
         None
     }
 
-    fn find_expr_to_report(args: &Vec<'_, Argument<'_>>) -> Option<Span> {
+    fn find_expr_to_report(args: &Vec<'_, Argument<'_>>, ctx: &LintContext) -> Option<Span> {
         if let Some(expr) = args.get(1).and_then(Argument::as_expression) {
             if !expr.is_string_literal() {
                 return None; // skip on indeterminate flag, e.g. RegExp('a  b', flags)
@@ -99,6 +119,11 @@ impl NoRegexSpaces {
             if let Some((idx_start, idx_end)) =
                 Self::find_consecutive_spaces_indices(&pattern.value)
             {
+                // Offsets in the cooked value only match the source without escapes
+                let raw = ctx.source_range(pattern.span);
+                if raw.get(1..raw.len() - 1) != Some(pattern.value.as_str()) {
+                    return Some(pattern.span);
+                }
                 let start = pattern.span.start + u32::try_from(idx_start).unwrap() + 1;
                 let end = pattern.span.start + u32::try_from(idx_end).unwrap() + 2;
 
@@ -261,5 +286,21 @@ fn test() {
         "var foo = new RegExp('[[    ]    ]    ', 'v');",
     ];
 
-    Tester::new(NoRegexSpaces::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("var foo = /bar  baz/;", "var foo = /bar {2}baz/;", None),
+        ("var foo = /bar    baz/;", "var foo = /bar {4}baz/;", None),
+        ("var foo = / a b  c d /;", "var foo = / a b {2}c d /;", None),
+        ("var foo = RegExp(' a b c d  ');", "var foo = RegExp(' a b c d {2}');", None),
+        ("var foo = new RegExp('bar    baz');", "var foo = new RegExp('bar {4}baz');", None),
+        ("var foo = /bar   {3}baz/;", "var foo = /bar {2} {3}baz/;", None),
+        ("var foo = /bar    ?baz/;", "var foo = /bar {3} ?baz/;", None),
+        ("var foo = RegExp('bar   +baz')", "var foo = RegExp('bar {2} +baz')", None),
+        ("var foo = /(?:  )/;", "var foo = /(?: {2})/;", None),
+        ("var foo = /  foo   /;", "var foo = / {2}foo   /;", None),
+        ("var foo = /[   ]  /;", "var foo = /[   ] {2}/;", None),
+        ("var foo = new RegExp('[   ]  ');", "var foo = new RegExp('[   ] {2}');", None),
+        (r"var foo = new RegExp('\\d  ')", r"var foo = new RegExp('\\d  ')", None),
+        (r"var foo = RegExp('\\u0041   ')", r"var foo = RegExp('\\u0041   ')", None),
+    ];
+    Tester::new(NoRegexSpaces::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
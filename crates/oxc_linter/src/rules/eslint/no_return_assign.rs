@@ -0,0 +1,170 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn return_assignment_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Return statement should not contain assignment.")
+        .with_help("Did you mean to use `==` or `===`? Otherwise, move the assignment out of the return statement.")
+        .with_label(span0)
+}
+
+fn arrow_assignment_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Arrow function should not return assignment.")
+        .with_help("Wrap the function body in braces, or compare with `==` or `===`.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoReturnAssign {
+    /// Report assignments even when they are wrapped in parentheses.
+    always: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow assignment operators in `return` statements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// It is difficult to tell the intent of a `return` statement containing an
+    /// assignment, such as `return foo = bar + 2;`. The author may have meant to
+    /// return the result of `bar + 2`, or to compare `foo` with `bar + 2` using `==`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function doSomething() {
+    ///     return foo = bar + 2;
+    /// }
+    ///
+    /// const doSomethingElse = () => foo = bar + 2;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `"except-parens"` (default): allow assignments wrapped in parentheses,
+    ///   e.g. `return (foo = bar + 2);`.
+    /// - `"always"`: disallow all assignments in `return` statements.
+    NoReturnAssign,
+    style
+);
+
+impl Rule for NoReturnAssign {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            always: value
+                .get(0)
+                .and_then(serde_json::Value::as_str)
+                .is_some_and(|option| option != "except-parens"),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::AssignmentExpression(_) = node.kind() else {
+            return;
+        };
+        if !self.always
+            && matches!(
+                ctx.nodes().parent_kind(node.id()),
+                Some(AstKind::ParenthesizedExpression(_))
+            )
+        {
+            return;
+        }
+
+        // Find the statement or function containing the assignment
+        let Some(parent) = ctx.nodes().iter_parents(node.id()).skip(1).find(|parent| {
+            let kind = parent.kind();
+            kind.is_statement()
+                || matches!(
+                    kind,
+                    AstKind::FunctionBody(_)
+                        | AstKind::Function(_)
+                        | AstKind::ArrowFunctionExpression(_)
+                        | AstKind::Class(_)
+                )
+        }) else {
+            return;
+        };
+
+        match parent.kind() {
+            AstKind::ReturnStatement(stmt) => {
+                ctx.diagnostic(return_assignment_diagnostic(stmt.span));
+            }
+            // The concise body of an arrow function is wrapped in an expression statement
+            AstKind::ExpressionStatement(_) => {
+                let Some(body) = ctx.nodes().parent_node(parent.id()) else {
+                    return;
+                };
+                if let Some(AstKind::ArrowFunctionExpression(arrow)) =
+                    ctx.nodes().parent_kind(body.id())
+                {
+                    if arrow.expression {
+                        ctx.diagnostic(arrow_assignment_diagnostic(arrow.span));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("module.exports = {'a': 1};", None),
+        ("var result = a * b;", None),
+        ("function x() { var result = a * b; return result; }", None),
+        ("function x() { return (result = a * b); }", None),
+        ("function x() { var result = a * b; return result; }", Some(json!(["except-parens"]))),
+        ("function x() { return (result = a * b); }", Some(json!(["except-parens"]))),
+        ("function x() { var result = a * b; return result; }", Some(json!(["always"]))),
+        ("function x() { return function y() { result = a * b }; }", Some(json!(["always"]))),
+        ("() => { return (result = a * b); }", Some(json!(["except-parens"]))),
+        ("() => (result = a * b)", Some(json!(["except-parens"]))),
+        ("const foo = (a,b,c) => ((a = b), c)", None),
+        (
+            "function foo(){
+                for (let i = 0; i < 10; i++) {
+                    if (i % 2) {
+                        return i;
+                    }
+                }
+            }",
+            None,
+        ),
+        ("() => { a = b; }", None),
+        ("() => { a = b; }", Some(json!(["always"]))),
+        ("function x() { return () => { a = b; }; }", Some(json!(["always"]))),
+        ("function x() { return class { constructor() { a = b; } }; }", Some(json!(["always"]))),
+        ("() => a === b", None),
+    ];
+
+    let fail = vec![
+        ("function x() { return result = a * b; };", None),
+        ("function x() { return (result) = (a * b); };", None),
+        ("function x() { return result = a * b; };", Some(json!(["except-parens"]))),
+        ("function x() { return (result) = (a * b); };", Some(json!(["except-parens"]))),
+        ("() => { return result = a * b; }", None),
+        ("() => result = a * b", None),
+        ("function x() { return result = a * b; };", Some(json!(["always"]))),
+        ("function x() { return (result = a * b); };", Some(json!(["always"]))),
+        ("function x() { return result || (result = a * b); };", Some(json!(["always"]))),
+        ("function foo(){ return a = b }", None),
+        ("function doSomething() { return foo = bar && foo > 0; }", None),
+        ("function doSomething() { return foo = function(){ return (bar = bar1) } }", None),
+        ("function doSomething() { return foo = () => a }", None),
+        ("function doSomething() { return () => a = () => b }", None),
+        ("function foo(a){ return function bar(b){ return a = b } }", None),
+        ("const foo = (a) => (b) => a = b", None),
+        ("() => (result = a * b)", Some(json!(["always"]))),
+    ];
+
+    Tester::new(NoReturnAssign::NAME, pass, fail).test_and_snapshot();
+}
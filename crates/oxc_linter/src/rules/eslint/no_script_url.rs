@@ -28,6 +28,8 @@ declare_oxc_lint!(
     /// location.href = "javascript:void(0)";
     ///
     /// location.href = `javascript:void(0)`;
+    ///
+    /// location.href = `javascript:${code}`;
     /// ```
     NoScriptUrl,
     style
@@ -36,25 +38,20 @@ declare_oxc_lint!(
 impl Rule for NoScriptUrl {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
-            AstKind::StringLiteral(literal)
-                if literal.value.to_lowercase().starts_with("javascript:") =>
-            {
-                emit_diagnostic(ctx, literal.span);
+            AstKind::StringLiteral(literal) if is_script_url(&literal.value) => {
+                ctx.diagnostic(no_script_url_diagnostic(literal.span));
             }
             AstKind::TemplateLiteral(literal)
                 if !is_tagged_template_expression(ctx, node, literal.span) =>
             {
-                if literal.quasis.len() == 1
-                    && literal
-                        .quasis
-                        .first()
-                        .unwrap()
-                        .value
-                        .raw
-                        .to_lowercase()
-                        .starts_with("javascript:")
-                {
-                    emit_diagnostic(ctx, literal.span);
+                // Only the leading quasi can start the URL, e.g. `javascript:${code}`
+                let is_script_url = literal
+                    .quasis
+                    .first()
+                    .and_then(|quasi| quasi.value.cooked.as_ref())
+                    .is_some_and(|cooked| is_script_url(cooked));
+                if is_script_url {
+                    ctx.diagnostic(no_script_url_diagnostic(literal.span));
                 }
             }
             _ => {}
@@ -62,8 +59,10 @@ impl Rule for NoScriptUrl {
     }
 }
 
-fn emit_diagnostic(ctx: &LintContext, span: Span) {
-    ctx.diagnostic(no_script_url_diagnostic(Span::new(span.start, span.end)));
+/// Browsers ignore leading whitespace and the case of the scheme in URLs.
+fn is_script_url(value: &str) -> bool {
+    const SCHEME: &str = "javascript:";
+    value.trim_start().get(..SCHEME.len()).is_some_and(|prefix| prefix.eq_ignore_ascii_case(SCHEME))
 }
 
 fn is_tagged_template_expression(ctx: &LintContext, node: &AstNode, literal_span: Span) -> bool {
@@ -84,6 +83,9 @@ fn test() {
         "var url = `xjavascript:`",
         "var url = `${foo}javascript:`",
         "var a = foo`javaScript:`;",
+        "var a = foo`javascript:${bar}`;",
+        "var url = 'java script:'",
+        "var url = 'https://example.com/javascript:'",
     ];
 
     let fail = vec![
@@ -91,6 +93,12 @@ fn test() {
         "var a = 'javascript:';",
         "var a = `javascript:`;",
         "var a = `JavaScript:`;",
+        "var a = 'JAVASCRIPT:alert(1)';",
+        "var a = '  javascript:void(0)';",
+        "var a = `\tjavascript:void(0)`;",
+        "var a = `javascript:${code}`;",
+        "var a = `javascript:void(${foo})`;",
+        "location.href = 'javascript:void(0)';",
     ];
 
     Tester::new(NoScriptUrl::NAME, pass, fail).test_and_snapshot();
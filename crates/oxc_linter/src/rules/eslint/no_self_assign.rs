@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use oxc_ast::{
     ast::{
         match_assignment_target, match_simple_assignment_target, ArrayExpressionElement,
@@ -95,7 +97,8 @@ impl NoSelfAssign {
 
                 if let Some(member_target) = simple_assignment_target.as_member_expression() {
                     if let Some(member_expr) = right.without_parenthesized().get_member_expr() {
-                        if self.is_member_expression_same_reference(member_expr, member_target) {
+                        if self.is_member_expression_same_reference(member_expr, member_target, ctx)
+                        {
                             ctx.diagnostic(no_self_assign_diagnostic(member_expr.span()));
                         }
                     }
@@ -163,7 +166,12 @@ impl NoSelfAssign {
         }
     }
 
-    fn is_same_reference<'a>(&self, left: &'a Expression<'a>, right: &'a Expression<'a>) -> bool {
+    fn is_same_reference<'a>(
+        &self,
+        left: &'a Expression<'a>,
+        right: &'a Expression<'a>,
+        ctx: &LintContext<'a>,
+    ) -> bool {
         let left = left.get_inner_expression();
         let right = right.get_inner_expression();
 
@@ -180,7 +188,7 @@ impl NoSelfAssign {
         }
 
         if let (Some(member1), Some(member2)) = (left.get_member_expr(), right.get_member_expr()) {
-            self.is_member_expression_same_reference(member1, member2)
+            self.is_member_expression_same_reference(member1, member2, ctx)
         } else {
             false
         }
@@ -190,26 +198,27 @@ impl NoSelfAssign {
         &self,
         member1: &'a MemberExpression<'a>,
         member2: &'a MemberExpression<'a>,
+        ctx: &LintContext<'a>,
     ) -> bool {
         if !self.props {
             return false;
         }
-        let member1_static_property_name = member1.static_property_name();
+        let member1_static_property_name = static_property_name(member1, ctx);
         if member1_static_property_name.is_some()
-            && member1_static_property_name == member2.static_property_name()
+            && member1_static_property_name == static_property_name(member2, ctx)
         {
-            return self.is_same_reference(member1.object(), member2.object());
+            return self.is_same_reference(member1.object(), member2.object(), ctx);
         }
 
         if matches!(member1, MemberExpression::ComputedMemberExpression(_))
             == matches!(member2, MemberExpression::ComputedMemberExpression(_))
-            && self.is_same_reference(member1.object(), member2.object())
+            && self.is_same_reference(member1.object(), member2.object(), ctx)
         {
             return match (member1, member2) {
                 (
                     MemberExpression::ComputedMemberExpression(computed1),
                     MemberExpression::ComputedMemberExpression(computed2),
-                ) => self.is_same_reference(&computed1.expression, &computed2.expression),
+                ) => self.is_same_reference(&computed1.expression, &computed2.expression, ctx),
                 (
                     MemberExpression::PrivateFieldExpression(private1),
                     MemberExpression::PrivateFieldExpression(private2),
@@ -269,6 +278,26 @@ impl NoSelfAssign {
     }
 }
 
+/// Like [`MemberExpression::static_property_name`], but also names numeric and regular expression
+/// keys the way they are converted to property keys, e.g. `a[0]` is `a['0']`.
+fn static_property_name<'a>(
+    member: &'a MemberExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<Cow<'a, str>> {
+    if let MemberExpression::ComputedMemberExpression(computed) = member {
+        match &computed.expression {
+            Expression::NumericLiteral(number) => {
+                return Some(Cow::Owned(number.value.to_string()))
+            }
+            Expression::RegExpLiteral(regex) => {
+                return Some(Cow::Borrowed(ctx.source_range(regex.span)))
+            }
+            _ => {}
+        }
+    }
+    member.static_property_name().map(Cow::Borrowed)
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -317,6 +346,10 @@ fn test() {
         ("this.x = this.x", Some(serde_json::json!([{ "props": false }]))),
         ("class C { #field; foo() { this['#field'] = this.#field; } }", None),
         ("class C { #field; foo() { this.#field = this['#field']; } }", None),
+        ("a[0] = a[1]", None),
+        ("a[b] = a[c]", None),
+        ("a[b].c = a[d].c", None),
+        ("a['/(?<zero>0)/'] = a[/(?<zero>1)/]", None),
     ];
 
     let fail = vec![
@@ -354,8 +387,9 @@ fn test() {
         ("a['b'] = a['b']", Some(serde_json::json!([{ "props": true }]))),
         ("a[\n    'b'\n] = a[\n    'b'\n]", Some(serde_json::json!([{ "props": true }]))),
         ("this.x = this.x", Some(serde_json::json!([{ "props": true }]))),
-        // TODO: <https://github.com/eslint/eslint/blob/eb3d7946e1e9f70254008744dba2397aaa730114/lib/rules/utils/ast-utils.js#L362>
-        // ("a['/(?<zero>0)/'] = a[/(?<zero>0)/]", Some(serde_json::json!([{ "props": true }]))),
+        ("a['/(?<zero>0)/'] = a[/(?<zero>0)/]", Some(serde_json::json!([{ "props": true }]))),
+        ("a[0] = a['0']", None),
+        ("a[b].c = a[b].c", None),
         ("(a?.b).c = (a?.b).c", None),
         ("a.b = a?.b", None),
         ("class C { #field; foo() { this.#field = this.#field; } }", None),
@@ -2,12 +2,13 @@ use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::BinaryOperator;
 
 use crate::{ast_util::calculate_hash, context::LintContext, rule::Rule, AstNode};
 
-fn no_self_compare_diagnostic(span0: Span, span1: Span) -> OxcDiagnostic {
+fn no_self_compare_diagnostic(help: String, span0: Span, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Disallow comparisons where both sides are exactly the same")
-        .with_help("If you are testing for NaN, you can use Number.isNaN function.")
+        .with_help(help)
         .with_labels([span0, span1])
 }
 
@@ -47,12 +48,32 @@ impl Rule for NoSelfCompare {
         let left = calculate_hash(&binary_expr.left);
         let right = calculate_hash(&binary_expr.right);
 
-        if left == right {
-            ctx.diagnostic(no_self_compare_diagnostic(
-                binary_expr.left.span(),
-                binary_expr.right.span(),
-            ));
+        if left != right {
+            return;
         }
+
+        // `x !== x` is the only way to test for NaN without a function call, so point to the
+        // clearer alternative. Relational comparisons against itself have no such use.
+        let help = if binary_expr.operator.is_equality() {
+            let operand = ctx.source_range(binary_expr.left.span());
+            let negation = if matches!(
+                binary_expr.operator,
+                BinaryOperator::Equality | BinaryOperator::StrictEquality
+            ) {
+                "!"
+            } else {
+                ""
+            };
+            format!("If you are testing for NaN, you can use `{negation}Number.isNaN({operand})`.")
+        } else {
+            "Comparing a value with itself always gives the same result, unless it is NaN."
+                .to_string()
+        };
+        ctx.diagnostic(no_self_compare_diagnostic(
+            help,
+            binary_expr.left.span(),
+            binary_expr.right.span(),
+        ));
     }
 }
 
@@ -67,6 +88,10 @@ fn test() {
         ("foo.bar.baz === foo.bar.qux", None),
         ("class C { #field; foo() { this.#field === this['#field']; } }", None),
         ("class C { #field; foo() { this['#field'] === this.#field; } }", None),
+        ("foo.bar === foo.baz", None),
+        ("a[0] === a[1]", None),
+        ("x === X", None),
+        ("x + x", None),
     ];
 
     let fail = vec![
@@ -85,6 +110,9 @@ fn test() {
         ("x <= x", None),
         ("foo.bar().baz.qux >= foo.bar ().baz .qux", None),
         ("class C { #field; foo() { this.#field === this.#field; } }", None),
+        ("foo.bar !== foo.bar", None),
+        ("a[0] === a[0]", None),
+        ("if (value != value) { }", None),
     ];
 
     Tester::new(NoSelfCompare::NAME, pass, fail).test_and_snapshot();
@@ -0,0 +1,181 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_sequences_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected use of comma operator.")
+        .with_help(
+            "Split the expressions into separate statements, or wrap the sequence in parentheses.",
+        )
+        .with_label(span0)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoSequences {
+    allow_in_parentheses: bool,
+}
+
+impl Default for NoSequences {
+    fn default() -> Self {
+        Self { allow_in_parentheses: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow comma operators.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The comma operator evaluates each of its operands and returns the value of
+    /// the last one. It is frequently used to hide side effects, and its use is
+    /// often an accident.
+    ///
+    /// Sequences are allowed in the initialization and update parts of a `for` loop.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// foo = doSomething(), val;
+    ///
+    /// while (val = foo(), val < 42);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `allowInParentheses` (default `true`): allow sequences wrapped in parentheses,
+    /// e.g. `foo = (doSomething(), val);`. Where parentheses are already required by
+    /// the syntax, as in `if`, `while`, `do...while`, `switch` and `with` statements
+    /// or in arrow function bodies, the sequence must be wrapped in a second pair.
+    NoSequences,
+    restriction
+);
+
+impl Rule for NoSequences {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_in_parentheses: value
+                .get(0)
+                .and_then(|config| config.get("allowInParentheses"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::SequenceExpression(expr) = node.kind() else {
+            return;
+        };
+        let Some(parent) = ctx.nodes().parent_node(node.id()) else {
+            return;
+        };
+
+        match parent.kind() {
+            // for (i = 0, j = 0; ; i++, j++)
+            AstKind::ForStatementInit(_) => return,
+            AstKind::ForStatement(stmt)
+                if stmt.update.as_ref().is_some_and(|update| update.span() == expr.span) =>
+            {
+                return;
+            }
+            AstKind::ParenthesizedExpression(paren) if self.allow_in_parentheses => {
+                let Some(grandparent) = ctx.nodes().parent_node(parent.id()) else {
+                    return;
+                };
+                if matches!(grandparent.kind(), AstKind::ParenthesizedExpression(_))
+                    || !is_arrow_body(grandparent, paren.span, ctx)
+                {
+                    return;
+                }
+            }
+            _ => {}
+        }
+
+        let Some(first) = expr.expressions.first() else {
+            return;
+        };
+        let Some(offset) = ctx.source_range(Span::new(first.span().end, expr.span.end)).find(',')
+        else {
+            return;
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        let comma = first.span().end + offset as u32;
+        ctx.diagnostic(no_sequences_diagnostic(Span::new(comma, comma + 1)));
+    }
+}
+
+/// Checks if the parentheses around an expression are required by the syntax of
+/// an arrow function body, as in `() => (a, b)`.
+fn is_arrow_body(node: &AstNode, span: Span, ctx: &LintContext) -> bool {
+    let AstKind::ExpressionStatement(stmt) = node.kind() else {
+        return false;
+    };
+    if stmt.expression.span() != span {
+        return false;
+    }
+    let Some(body) = ctx.nodes().parent_node(node.id()) else {
+        return false;
+    };
+    matches!(
+        ctx.nodes().parent_kind(body.id()),
+        Some(AstKind::ArrowFunctionExpression(arrow)) if arrow.expression
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("var arr = [1, 2];", None),
+        ("var obj = {a: 1, b: 2};", None),
+        ("var a = 1, b = 2;", None),
+        ("var foo = (1, 2);", None),
+        ("(0,eval)(\"foo()\");", None),
+        ("for (i = 1, j = 2;; i++, j++);", None),
+        ("foo(a, b);", None),
+        ("while ((next = next.parentNode, next)) {}", None),
+        ("if ((a, b));", None),
+        ("switch ((a, b)) {}", None),
+        ("with ((a, b)) {}", None),
+        ("do {} while ((a, b));", None),
+        ("() => ((a, b));", None),
+        ("for ((a, b);;);", None),
+        ("for (;(a, b););", None),
+        ("for (;; (a, b));", None),
+        ("for (i = 1, j = 2;; i++, j++);", Some(json!([{ "allowInParentheses": false }]))),
+        ("new Foo(a, b)", None),
+        ("function foo(a, b) {}", None),
+    ];
+
+    let fail = vec![
+        ("1, 2;", None),
+        ("a = 1, 2", None),
+        ("do {} while (doSomething(), !!test);", None),
+        ("for (; doSomething(), !!test; );", None),
+        ("if (doSomething(), !!test);", None),
+        ("switch (doSomething(), val) {}", None),
+        ("while (doSomething(), !!test);", None),
+        ("with (doSomething(), val) {}", None),
+        ("a => (doSomething(), a)", None),
+        ("(1), 2", None),
+        ("((1)) , (2)", None),
+        ("while((1) , 2);", None),
+        ("var foo = (1, 2);", Some(json!([{ "allowInParentheses": false }]))),
+        ("(0,eval)(\"foo()\");", Some(json!([{ "allowInParentheses": false }]))),
+        ("foo(a, (b, c), d);", Some(json!([{ "allowInParentheses": false }]))),
+        ("do {} while ((doSomething(), !!test));", Some(json!([{ "allowInParentheses": false }]))),
+        ("for (;(doSomething(), !!test););", Some(json!([{ "allowInParentheses": false }]))),
+        ("if ((doSomething(), !!test));", Some(json!([{ "allowInParentheses": false }]))),
+        ("switch ((doSomething(), val)) {}", Some(json!([{ "allowInParentheses": false }]))),
+        ("while ((doSomething(), !!test));", Some(json!([{ "allowInParentheses": false }]))),
+        ("with ((doSomething(), val)) {}", Some(json!([{ "allowInParentheses": false }]))),
+        ("a => ((doSomething(), a))", Some(json!([{ "allowInParentheses": false }]))),
+    ];
+
+    Tester::new(NoSequences::NAME, pass, fail).test_and_snapshot();
+}
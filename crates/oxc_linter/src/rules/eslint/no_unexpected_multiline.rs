@@ -0,0 +1,221 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{identifier::is_line_terminator, operator::BinaryOperator};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn function_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected newline between function and ( of function call.")
+        .with_label(span0)
+}
+
+fn property_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected newline between object and [ of property access.")
+        .with_label(span0)
+}
+
+fn tagged_template_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected newline between template tag and template literal.")
+        .with_label(span0)
+}
+
+fn division_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected newline between numerator and division operator.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnexpectedMultiline;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow confusing multiline expressions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Semicolons are usually optional in JavaScript, because of automatic semicolon
+    /// insertion (ASI). However, a newline does not end a statement when the next line
+    /// starts with `(`, `[`, `` ` `` or `/`, so the two lines are parsed as a single
+    /// function call, property access, tagged template or division.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var foo = bar
+    /// (1 || 2).baz();
+    ///
+    /// var hello = 'world'
+    /// [1, 2, 3].forEach(addNumber);
+    ///
+    /// let x = function() {}
+    /// `hello`
+    ///
+    /// let y = function() {}
+    /// y
+    /// `hello`
+    ///
+    /// let z = foo
+    /// /regex/g.test(bar)
+    /// ```
+    NoUnexpectedMultiline,
+    suspicious
+);
+
+impl Rule for NoUnexpectedMultiline {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::CallExpression(call) if !call.optional && !call.arguments.is_empty() => {
+                let start = call
+                    .type_parameters
+                    .as_ref()
+                    .map_or_else(|| call.callee.span().end, |params| params.span.end);
+                if let Some(span) = find_token_after_newline(ctx, start, b'(') {
+                    ctx.diagnostic(function_diagnostic(span));
+                }
+            }
+            AstKind::MemberExpression(member) if member.is_computed() && !member.optional() => {
+                if let Some(span) = find_token_after_newline(ctx, member.object().span().end, b'[')
+                {
+                    ctx.diagnostic(property_diagnostic(span));
+                }
+            }
+            AstKind::TaggedTemplateExpression(tagged) => {
+                let start = tagged
+                    .type_parameters
+                    .as_ref()
+                    .map_or_else(|| tagged.tag.span().end, |params| params.span.end);
+                if let Some(span) = find_token_after_newline(ctx, start, b'`') {
+                    ctx.diagnostic(tagged_template_diagnostic(span));
+                }
+            }
+            AstKind::BinaryExpression(binary) if binary.operator == BinaryOperator::Division => {
+                // `foo\n/bar/g.test(baz)` is parsed as `foo / bar / g.test(baz)`
+                let Expression::BinaryExpression(numerator) = &binary.left else {
+                    return;
+                };
+                if numerator.operator != BinaryOperator::Division
+                    || !starts_with_regex_flags(ctx, binary.right.span())
+                {
+                    return;
+                }
+                if let Some(span) = find_token_after_newline(ctx, numerator.left.span().end, b'/') {
+                    ctx.diagnostic(division_diagnostic(span));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Finds the first token after `start`, skipping comments and closing parentheses,
+/// and returns its span if it is `expected` and starts a new line.
+fn find_token_after_newline(ctx: &LintContext, start: u32, expected: u8) -> Option<Span> {
+    let source = ctx.source_text();
+    let mut newline = false;
+    let mut i = start as usize;
+    loop {
+        let rest = &source[i..];
+        if rest.starts_with("//") {
+            i += rest.find(is_line_terminator).unwrap_or(rest.len());
+        } else if rest.starts_with("/*") {
+            let len = rest.find("*/").map_or(rest.len(), |end| end + 2);
+            newline |= rest[..len].contains(is_line_terminator);
+            i += len;
+        } else if rest.starts_with(')') {
+            newline = false;
+            i += 1;
+        } else {
+            let c = rest.chars().next()?;
+            if !c.is_whitespace() {
+                break;
+            }
+            newline |= is_line_terminator(c);
+            i += c.len_utf8();
+        }
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    let i = i as u32;
+    (newline && source.as_bytes()[i as usize] == expected).then(|| Span::new(i, i + 1))
+}
+
+/// Checks for `/g` in `foo\n/bar/g`, i.e. regex flags directly after the slash.
+fn starts_with_regex_flags(ctx: &LintContext, span: Span) -> bool {
+    let source = ctx.source_text();
+    let Some(start) = (span.start as usize).checked_sub(1) else {
+        return false;
+    };
+    if source.as_bytes()[start] != b'/' {
+        return false;
+    }
+    let text = ctx.source_range(span);
+    let len = text.find(|c: char| !c.is_ascii_alphanumeric() && c != '_' && c != '$');
+    let flags = &text[..len.unwrap_or(text.len())];
+    !flags.is_empty() && flags.chars().all(|c| "dgimsuvy".contains(c))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "(x || y).aFunction()",
+        "[a, b, c].forEach(doSomething)",
+        "var a = b;\n(x || y).doSomething()",
+        "var a = b\n;(x || y).doSomething()",
+        "var a = b\nvoid (x || y).doSomething()",
+        "var a = b;\n[1, 2, 3].forEach(console.log)",
+        "var a = b\nvoid [1, 2, 3].forEach(console.log)",
+        "\"abc\\\n(123)\"",
+        "var a = (\n(123)\n)",
+        "f(\n(x)\n)",
+        "(\nfunction () {}\n)[1]",
+        "let x = function() {};\n   `hello`",
+        "let x = function() {}\nx `hello`",
+        "String.raw `Hi\n${2+3}!`;",
+        "x\n.y\nz `Valid Test Case`",
+        "f(x\n)`Valid Test Case`",
+        "x.\ny `Valid Test Case`",
+        "(x\n)`Valid Test Case`",
+        "foo\n/ bar/2",
+        "foo\n/ bar/ g",
+        "foo /\n bar/g.test(baz)",
+        "foo\n/bar/\ng",
+        "foo /* comment */ / bar /g",
+        "var a = b\n  ?.(x || y)",
+        "var a = b\n  ?.[a, b, c].forEach(doSomething)",
+        "var a = b?.\n  (x || y)",
+        "var a = b?.\n  [a, b, c].forEach(doSomething)",
+        "a\n()",
+        "new foo\n(bar)",
+        "foo(\n  bar\n)",
+        "foo\n.bar\n.baz(qux)",
+        "foo // comment\n.bar(qux)",
+    ];
+
+    let fail = vec![
+        "var a = b\n(x || y).doSomething()",
+        "var a = (a || b)\n(x || y).doSomething()",
+        "var a = (a || b)\n(x).doSomething()",
+        "var a = b\n[a, b, c].forEach(doSomething)",
+        "var a = b\n    (x || y).doSomething()",
+        "var a = b\n  [a, b, c].forEach(doSomething)",
+        "let x = function() {}\n `hello`",
+        "let x = function() {}\nx\n`hello`",
+        "x\n.y\nz\n`Invalid Test Case`",
+        "foo\n/bar/g.test(baz)",
+        "hello\n/regex/g.test('bar')",
+        "hello\n/regex/giys.test('bar')",
+        "foo\n/bar/gym.test(baz)",
+        "var a = b // comment\n(x || y).doSomething()",
+        "var a = b /* comment */\n(x || y).doSomething()",
+        "var a = b /*\n*/ (x || y).doSomething()",
+        "var a = (b\n)\n(x || y).doSomething()",
+        "foo<T>\n(bar)",
+        "const x = aaaa<\n  test\n>\n`foo`",
+        "const x = aaaa<\n  test\n>/*\ntest\n*/`foo`",
+    ];
+
+    Tester::new(NoUnexpectedMultiline::NAME, pass, fail).test_and_snapshot();
+}
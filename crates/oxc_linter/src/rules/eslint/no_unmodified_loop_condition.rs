@@ -0,0 +1,331 @@
+use oxc_ast::{
+    ast::{
+        ArrowFunctionExpression, CallExpression, Class, Function, NewExpression,
+        TaggedTemplateExpression, VariableDeclarationKind, YieldExpression,
+    },
+    AstKind, Visit,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{AstNodeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::scope::ScopeFlags;
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, rule::Rule};
+
+fn no_unmodified_loop_condition_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{x0}' is not modified in this loop."))
+        .with_help("Modify the variable inside the loop, or change the loop condition.")
+        .with_label(span1)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnmodifiedLoopCondition;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unmodified loop conditions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Variables in a loop condition often are modified in the loop.
+    /// If not, it's possibly a mistake, and the loop may never terminate.
+    ///
+    /// Conditions are only checked when they are made of plain variables.
+    /// If a condition contains function calls, `new` or `yield` expressions,
+    /// it can change without its variables being written.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// let node = something;
+    ///
+    /// while (node) {
+    ///     doSomething(node);
+    /// }
+    ///
+    /// for (let j = 0; j < items.length; ++i) {
+    ///     doSomething(items[j]);
+    /// }
+    /// ```
+    NoUnmodifiedLoopCondition,
+    suspicious
+);
+
+/// A read of a variable in the test of a `while`, `do-while` or `for` loop.
+struct LoopCondition {
+    span: Span,
+    symbol_id: SymbolId,
+    loop_id: AstNodeId,
+    /// The outermost binary or conditional expression in the test containing this
+    /// reference. Grouped conditions are reported only if none of them is modified.
+    group: Option<AstNodeId>,
+    modified: bool,
+}
+
+impl Rule for NoUnmodifiedLoopCondition {
+    fn run_once(&self, ctx: &LintContext) {
+        let mut groups: FxHashMap<AstNodeId, Vec<LoopCondition>> = FxHashMap::default();
+
+        for symbol_id in ctx.symbols().iter() {
+            let mut conditions = ctx
+                .symbols()
+                .get_resolved_references(symbol_id)
+                .filter_map(|reference| {
+                    to_loop_condition(reference.node_id(), reference.span(), symbol_id, ctx)
+                })
+                .collect::<Vec<_>>();
+            if conditions.is_empty() {
+                continue;
+            }
+
+            let modifiers = get_modifiers(symbol_id, ctx);
+            for condition in &mut conditions {
+                condition.modified = modifiers.iter().any(|&(node_id, span)| {
+                    is_in_loop(condition.loop_id, span, ctx)
+                        || node_id.is_some_and(|id| is_modified_by_call(id, condition.loop_id, ctx))
+                });
+            }
+
+            for condition in conditions {
+                match condition.group {
+                    Some(group) => groups.entry(group).or_default().push(condition),
+                    None if !condition.modified => report(&condition, ctx),
+                    None => {}
+                }
+            }
+        }
+
+        for conditions in groups.values() {
+            if conditions.iter().all(|condition| !condition.modified) {
+                for condition in conditions {
+                    report(condition, ctx);
+                }
+            }
+        }
+    }
+}
+
+fn report(condition: &LoopCondition, ctx: &LintContext) {
+    let name = ctx.symbols().get_name(condition.symbol_id);
+    ctx.diagnostic(no_unmodified_loop_condition_diagnostic(name, condition.span));
+}
+
+/// Walks up from a reference and returns a condition if it is part of a loop test.
+fn to_loop_condition(
+    node_id: AstNodeId,
+    span: Span,
+    symbol_id: SymbolId,
+    ctx: &LintContext,
+) -> Option<LoopCondition> {
+    let mut group = None;
+    let mut child_span = span;
+
+    for node in ctx.nodes().iter_parents(node_id).skip(1) {
+        let kind = node.kind();
+        if is_sentinel(kind) {
+            let test_span = match kind {
+                AstKind::WhileStatement(stmt) => Some(stmt.test.span()),
+                AstKind::DoWhileStatement(stmt) => Some(stmt.test.span()),
+                AstKind::ForStatement(stmt) => stmt.test.as_ref().map(GetSpan::span),
+                _ => None,
+            };
+            return (test_span == Some(child_span)).then_some(LoopCondition {
+                span,
+                symbol_id,
+                loop_id: node.id(),
+                group,
+                modified: false,
+            });
+        }
+
+        match kind {
+            AstKind::BinaryExpression(expr) => {
+                let mut finder = DynamicExpressionFinder::default();
+                finder.visit_binary_expression(expr);
+                if finder.found {
+                    return None;
+                }
+                group = Some(node.id());
+            }
+            AstKind::ConditionalExpression(expr) => {
+                let mut finder = DynamicExpressionFinder::default();
+                finder.visit_conditional_expression(expr);
+                if finder.found {
+                    return None;
+                }
+                group = Some(node.id());
+            }
+            _ => {}
+        }
+
+        child_span = kind.span();
+    }
+
+    None
+}
+
+/// Nodes at which the search for an enclosing loop test stops.
+fn is_sentinel(kind: AstKind) -> bool {
+    kind.is_statement()
+        || kind.is_declaration()
+        || matches!(
+            kind,
+            AstKind::CallExpression(_)
+                | AstKind::Class(_)
+                | AstKind::Function(_)
+                | AstKind::ArrowFunctionExpression(_)
+                | AstKind::MemberExpression(_)
+                | AstKind::NewExpression(_)
+                | AstKind::YieldExpression(_)
+        )
+}
+
+/// Returns the node and span of every write to the symbol. The declarations of
+/// a `var` are included, as their initializers are re-run on every iteration.
+fn get_modifiers(symbol_id: SymbolId, ctx: &LintContext) -> Vec<(Option<AstNodeId>, Span)> {
+    let symbols = ctx.symbols();
+    let mut modifiers = symbols
+        .get_resolved_references(symbol_id)
+        .filter(|reference| reference.is_write())
+        .map(|reference| (Some(reference.node_id()), reference.span()))
+        .collect::<Vec<_>>();
+
+    let declaration = symbols.get_declaration(symbol_id);
+    if let AstKind::VariableDeclarator(declarator) = ctx.nodes().kind(declaration) {
+        if declarator.kind == VariableDeclarationKind::Var {
+            if declarator.init.is_some() {
+                modifiers.push((None, declarator.span));
+            }
+            modifiers.extend(
+                symbols.get_redeclare_variables(symbol_id).iter().map(|&span| (None, span)),
+            );
+        }
+    }
+
+    modifiers
+}
+
+fn is_in_loop(loop_id: AstNodeId, span: Span, ctx: &LintContext) -> bool {
+    match ctx.nodes().kind(loop_id) {
+        AstKind::WhileStatement(stmt) => contains(stmt.span, span),
+        AstKind::DoWhileStatement(stmt) => contains(stmt.span, span),
+        AstKind::ForStatement(stmt) => {
+            contains(stmt.span, span)
+                && !stmt.init.as_ref().is_some_and(|init| contains(init.span(), span))
+        }
+        _ => false,
+    }
+}
+
+/// Checks if the modifier is inside a function declaration which is called
+/// (or otherwise referenced) inside the loop.
+fn is_modified_by_call(modifier_id: AstNodeId, loop_id: AstNodeId, ctx: &LintContext) -> bool {
+    let Some(func) = ctx.nodes().iter_parents(modifier_id).find_map(|node| match node.kind() {
+        AstKind::Function(func) if func.is_declaration() => Some(func),
+        _ => None,
+    }) else {
+        return false;
+    };
+    let Some(symbol_id) = func.id.as_ref().and_then(|id| id.symbol_id.get()) else {
+        return false;
+    };
+    ctx.symbols()
+        .get_resolved_references(symbol_id)
+        .any(|reference| is_in_loop(loop_id, reference.span(), ctx))
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// Finds expressions whose value may change without a variable being written.
+#[derive(Default)]
+struct DynamicExpressionFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for DynamicExpressionFinder {
+    fn visit_call_expression(&mut self, _it: &CallExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_new_expression(&mut self, _it: &NewExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_tagged_template_expression(&mut self, _it: &TaggedTemplateExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_yield_expression(&mut self, _it: &YieldExpression<'a>) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _it: &Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var foo = 0; while (foo) { ++foo; }",
+        "var foo = 0; while (foo) { foo += 1; }",
+        "var foo = 0; while (foo++) { }",
+        "var foo = 0; while (foo = next()) { }",
+        "var foo = 0; while (ok(foo)) { }",
+        "var foo = 0, bar = 0; while (++foo < bar) { }",
+        "var foo = 0, f = {}, bar = {}; while (foo === f(bar)) { }",
+        "var foo = 0, f = {}; while (foo === f()) { }",
+        "var foo = 0, tag = 0; while (foo === tag`abc`) { }",
+        "function* foo() { var foo = 0; while (yield foo) { } }",
+        "function* foo() { var foo = 0; while (foo === (yield)) { } }",
+        "var foo = 0; while (foo.ok) { }",
+        "var foo = 0; while (foo) { update(); } function update() { ++foo; }",
+        "var foo = 0, bar = 9; while (foo < bar) { foo += 1; }",
+        "var foo = 0, bar = 1, baz = 2; while (foo ? 1 : 0) { ++foo; }",
+        "var foo = 0, bar = 1, baz = 2; while (foo ? bar : baz) { ++bar; }",
+        "var foo = 0, bar = 1, baz = 2; while (foo ? bar : baz) { ++baz; }",
+        "var foo = 0; while (foo) { foo = 1; }",
+        "var foo = 0; do { ++foo; } while (foo);",
+        "var foo = 0; do { } while (foo++);",
+        "for (var foo = 0; foo; ++foo) { }",
+        "for (var foo = 0; foo;) { ++foo }",
+        "var foo = 0, bar = 0; for (bar; foo;) { ++foo }",
+        "var foo; if (foo) { }",
+        "var a = [1, 2, 3]; var len = 1; while (a.length > len) { len++; }",
+        "function* baz() { var foo = 0; while (yield foo) { } }",
+        "function* baz() { var foo = 0; while (foo === (yield)) { } }",
+        "var foo = 0; while (foo || bar) { ++foo; }",
+        "var bar = 0; while (foo || bar) { ++bar; }",
+        "for (var i = 0; i < 10; ) { var i = i + 1; }",
+        "while (x) { }",
+        "var foo = 0; while (function() { return foo; }()) { }",
+        "var foo = 0; while (() => foo) { foo++; }",
+    ];
+
+    let fail = vec![
+        "var foo = 0; while (foo) { } foo = 1;",
+        "var foo = 0; while (!foo) { } foo = 1;",
+        "var foo = 0; while (foo != null) { } foo = 1;",
+        "var foo = 0, bar = 9; while (foo < bar) { } foo = 1;",
+        "var foo = 0, bar = 0; while (foo && bar) { ++bar; } foo = 1;",
+        "var foo = 0, bar = 0; while (foo && bar) { ++foo; } foo = 1;",
+        "var a, b, c; while (a < c && b < c) { ++a; } foo = 1;",
+        "var foo = 0; while (foo ? 1 : 0) { } foo = 1;",
+        "var foo = 0; while (foo) { update(); } function update(foo) { ++foo; }",
+        "var foo; do { } while (foo);",
+        "for (var foo = 0; foo < 10; ) { } foo = 1;",
+        "var foo = 0; while (foo) { } function f() { foo = 1; }",
+        "let node = something; while (node) { doSomething(node); }",
+        "var items = []; for (let j = 0; j < items.length; ++i) { doSomething(items[j]); }",
+    ];
+
+    Tester::new(NoUnmodifiedLoopCondition::NAME, pass, fail).test_and_snapshot();
+}
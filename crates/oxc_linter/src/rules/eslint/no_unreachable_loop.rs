@@ -0,0 +1,248 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_cfg::{
+    graph::{
+        visit::{depth_first_search, Control, DfsEvent},
+        Direction,
+    },
+    BasicBlockId, EdgeType, Instruction, InstructionKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_unreachable_loop_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Invalid loop. Its body allows only one iteration.")
+        .with_help("Remove the loop, or make sure its body can run again.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnreachableLoop(Box<NoUnreachableLoopConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnreachableLoopConfig {
+    /// Loop types to skip, e.g. `WhileStatement` or `ForOfStatement`.
+    ignore: Vec<CompactStr>,
+}
+
+impl std::ops::Deref for NoUnreachableLoop {
+    type Target = NoUnreachableLoopConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow loops with a body that allows only one iteration.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A loop that can never reach the second iteration is a possible error in the
+    /// code. In rare cases where only one iteration (or at most one iteration) is
+    /// intended behavior, the code should be refactored to use `if` conditionals
+    /// instead of loops.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// while (foo) {
+    ///     doSomething(foo);
+    ///     foo = foo.parent;
+    ///     break;
+    /// }
+    ///
+    /// for (const item of list) {
+    ///     if (item.valid) {
+    ///         return item;
+    ///     } else {
+    ///         throw new Error();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `ignore`: a list of loop types to skip, any of `WhileStatement`,
+    /// `DoWhileStatement`, `ForStatement`, `ForInStatement` and `ForOfStatement`.
+    NoUnreachableLoop,
+    suspicious
+);
+
+impl Rule for NoUnreachableLoop {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(NoUnreachableLoopConfig {
+            ignore: value
+                .get(0)
+                .and_then(|config| config.get("ignore"))
+                .and_then(serde_json::Value::as_array)
+                .map(|ignore| {
+                    ignore
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(CompactStr::from)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        // The rule runs on the body of the loop, since the basic block starting it is where
+        // every iteration begins.
+        let Some(loop_node) = ctx.nodes().parent_node(node.id()) else { return };
+        let (loop_type, body) = match loop_node.kind() {
+            AstKind::WhileStatement(stmt) => ("WhileStatement", &stmt.body),
+            AstKind::DoWhileStatement(stmt) => ("DoWhileStatement", &stmt.body),
+            AstKind::ForStatement(stmt) => ("ForStatement", &stmt.body),
+            AstKind::ForInStatement(stmt) => ("ForInStatement", &stmt.body),
+            AstKind::ForOfStatement(stmt) => ("ForOfStatement", &stmt.body),
+            _ => return,
+        };
+        if body.span() != node.kind().span() || self.ignore.iter().any(|it| it == loop_type) {
+            return;
+        }
+
+        let cfg = ctx.cfg();
+        let graph = cfg.graph();
+        let body_start = node.cfg_id();
+        // Blocks created before the loop are outside of it, following them could only lead back
+        // to the body through the loop's entry, e.g. through the next iteration of an outer loop.
+        let before_loop = loop_node.cfg_id();
+
+        let loops_again = depth_first_search(graph, Some(body_start), |event| match event {
+            DfsEvent::TreeEdge(a, b) => {
+                if b.index() <= before_loop.index() {
+                    return Control::Prune;
+                }
+                let taken = graph.edges_connecting(a, b).any(|edge| match edge.weight() {
+                    EdgeType::NewFunction | EdgeType::Unreachable => false,
+                    // Leaving a loop which never ends on its own.
+                    EdgeType::Normal => !is_infinite_loop_test(a, ctx),
+                    _ => true,
+                });
+                if taken {
+                    Control::Continue
+                } else {
+                    Control::Prune
+                }
+            }
+            // Reaching the start of the body again is the next iteration.
+            DfsEvent::BackEdge(_, b) if b == body_start => Control::Break(true),
+            _ => Control::Continue,
+        })
+        .break_value()
+        .unwrap_or(false);
+
+        if !loops_again {
+            ctx.diagnostic(no_unreachable_loop_diagnostic(loop_node.kind().span()));
+        }
+    }
+}
+
+/// Checks if the basic block tests the condition of a loop which is always true.
+fn is_infinite_loop_test(block: BasicBlockId, ctx: &LintContext) -> bool {
+    let cfg = ctx.cfg();
+    let instructions = cfg.basic_block(block).instructions();
+    if instructions.is_empty() {
+        // The test of a `for` statement without a condition is an empty block with a back edge
+        // from the update, which unlike the update doesn't have a back edge of its own.
+        let graph = cfg.graph();
+        return graph
+            .edges_directed(block, Direction::Incoming)
+            .any(|edge| matches!(edge.weight(), EdgeType::Backedge))
+            && !graph
+                .edges_directed(block, Direction::Outgoing)
+                .any(|edge| matches!(edge.weight(), EdgeType::Backedge));
+    }
+    instructions.iter().any(|instruction| match instruction {
+        Instruction { kind: InstructionKind::Condition, node_id: Some(id) } => {
+            match ctx.nodes().parent_kind(*id) {
+                Some(AstKind::WhileStatement(stmt)) => is_always_true(Some(&stmt.test)),
+                Some(AstKind::DoWhileStatement(stmt)) => is_always_true(Some(&stmt.test)),
+                Some(AstKind::ForStatement(stmt)) => is_always_true(stmt.test.as_ref()),
+                _ => false,
+            }
+        }
+        _ => false,
+    })
+}
+
+fn is_always_true(test: Option<&Expression>) -> bool {
+    test.map_or(true, |test| test.without_parenthesized().get_boolean_value() == Some(true))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("while (foo) { if (bar) { break; } }", None),
+        ("while (foo) { if (bar) { break; } else { baz(); } }", None),
+        ("function f() { while (foo) { if (bar) return; } }", None),
+        ("while (foo) { continue; }", None),
+        ("while (foo) { if (bar) { break; } continue; }", None),
+        ("for (a of b) { if (c) { break; } else { continue; } }", None),
+        ("do { if (foo) break; } while (bar)", None),
+        ("do { continue; } while (bar)", None),
+        ("for (;;) { if (a) break; }", None),
+        ("for (a in b) { foo(); }", None),
+        ("for (a of b) {}", None),
+        ("while (a) { switch (b) { case 1: break; default: throw err; } }", None),
+        ("while (a) { switch (b) { case 1: throw err; } }", None),
+        ("while (a) { switch (b) { case 1: throw err; default: } }", None),
+        ("function f() { while (a) { try { return; } catch (e) {} } }", None),
+        ("while (a) { try { foo(); } finally { bar(); } }", None),
+        ("while (a) { try { break; } finally { continue; } }", None),
+        ("while (a) { label: { if (b) break label; throw err; } }", None),
+        ("while (a) { while (b) { if (c) break; } }", None),
+        ("while (a) { for (;;) { if (b) break; } }", None),
+        ("outer: while (a) { while (b) { if (c) continue outer; } }", None),
+        ("outer: inner: while (a) { if (b) { continue outer; } break; }", None),
+        ("while (foo) { function f() { return; } }", None),
+        ("while (foo) { const f = () => { return; }; }", None),
+        ("while (foo) { class C { m() { return; } } }", None),
+        ("while (a) { return; }", Some(serde_json::json!([{ "ignore": ["WhileStatement"] }]))),
+        (
+            "for (a of b) { break; }",
+            Some(serde_json::json!([{ "ignore": ["ForInStatement", "ForOfStatement"] }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("while (foo) { break; }", None),
+        ("function f() { while (foo) { return; } }", None),
+        ("while (foo) { throw err; }", None),
+        ("while (foo) { bar(); baz(); break; }", None),
+        ("do { break; } while (foo)", None),
+        ("for (;;) { break; }", None),
+        ("for (let i = 0; i < 10; i++) { break; }", None),
+        ("for (a in b) { break; }", None),
+        ("for (a of b) { break; }", None),
+        ("for await (a of b) { break; }", None),
+        ("function f() { while (a) { if (b) { return; } else { break; } } }", None),
+        ("function f() { for (a of b) { if (a.valid) return a; else throw new Error(); } }", None),
+        ("function f() { while (a) { try { return; } finally { foo(); } } }", None),
+        ("function f() { while (a) { try { foo(); } finally { return; } } }", None),
+        ("function f() { while (a) { try { return; } catch (e) { return; } } }", None),
+        ("while (a) { switch (b) { default: throw err; } }", None),
+        ("while (a) { switch (b) { case 1: foo(); default: throw err; } }", None),
+        ("while (a) { label: { break label; } break; }", None),
+        ("outer: while (a) { while (b) { break outer; } }", None),
+        ("outer: while (a) { while (b) { continue outer; } }", None),
+        ("outer: while (a) { while (b) { continue outer; } break; }", None),
+        ("function f() { while (a) { while (b) { } return; } }", None),
+        ("function f() { for (const a of b) { for (const c of d) { break; } return; } }", None),
+        ("while (a) { while (true) { } foo(); }", None),
+        ("while (a) { do { foo(); } while (true); }", None),
+        ("while (a) { with (b) { break; } }", None),
+        ("while (a) { { break; } foo(); }", None),
+        ("while (a) break;", None),
+        ("do { break; } while (a)", Some(serde_json::json!([{ "ignore": ["WhileStatement"] }]))),
+    ];
+
+    Tester::new(NoUnreachableLoop::NAME, pass, fail).test_and_snapshot();
+}
@@ -42,6 +42,9 @@ declare_oxc_lint!(
     ///
     /// // > 3
     /// ```
+    ///
+    /// Statements inside nested functions and classes are allowed, as are `break` and
+    /// `continue` statements whose target loop, switch or label is inside the finally block.
     NoUnsafeFinally,
     correctness
 );
@@ -52,10 +55,12 @@ impl Rule for NoUnsafeFinally {
 
         let sentinel_node_type = match kind {
             AstKind::BreakStatement(stmt) if stmt.label.is_none() => SentinelNodeType::Break,
-            AstKind::ContinueStatement(_) => SentinelNodeType::Continue,
+            AstKind::ContinueStatement(stmt) if stmt.label.is_none() => SentinelNodeType::Continue,
+            // Labeled jumps are resolved by looking for their label below
             AstKind::ReturnStatement(_)
             | AstKind::ThrowStatement(_)
-            | AstKind::BreakStatement(_) => SentinelNodeType::ReturnThrow,
+            | AstKind::BreakStatement(_)
+            | AstKind::ContinueStatement(_) => SentinelNodeType::ReturnThrow,
             _ => return,
         };
 
@@ -159,6 +164,14 @@ fn test() {
             "var foo = function() { try { return 1; } finally { class bar { constructor() {} static ehm() { return 'Hola!'; } } } };",
             None,
         ),
+        ("var foo = function() { try {} finally { a: while (true) { continue a; } } }", None),
+        (
+            "var foo = function() { try {} finally { a: while (true) { while (true) { continue a; } } } }",
+            None,
+        ),
+        ("var foo = function() { try {} finally { a: { break a; } } }", None),
+        ("var foo = function() { try {} finally { for (const x of y) { continue; } } }", None),
+        ("try {} finally { class C { static { function f() { return 1; } } } }", None),
     ];
 
     let fail = vec![
@@ -213,6 +226,16 @@ fn test() {
             "var foo = function() { a: switch (true) { case true: try {} finally { switch (true) { case true: break a; } } } }",
             None,
         ),
+        (
+            "var foo = function() { a: while (true) try {} finally { while (true) { continue a; } } }",
+            None,
+        ),
+        (
+            "var foo = function() { a: while (true) try {} finally { b: for (;;) { continue a; } } }",
+            None,
+        ),
+        ("var foo = function() { for (const x of y) try {} finally { continue; } }", None),
+        ("var foo = function() { try {} finally { throw new Error(); } }", None),
     ];
 
     Tester::new(NoUnsafeFinally::NAME, pass, fail).test_and_snapshot();
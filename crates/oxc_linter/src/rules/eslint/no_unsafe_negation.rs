@@ -7,7 +7,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
 
-use crate::{context::LintContext, fixer::RuleFixer, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, AstNode};
 
 fn no_unsafe_negation_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("Unexpected logical not in the left hand side of '{x0}' operator"))
@@ -61,7 +61,7 @@ impl Rule for NoUnsafeNegation {
                 return;
             };
             if left.operator == UnaryOperator::LogicalNot {
-                Self::report_with_fix(expr, ctx);
+                Self::report_with_suggestion(expr, ctx);
             }
         }
     }
@@ -74,28 +74,16 @@ impl NoUnsafeNegation {
 
     /// Precondition:
     /// expr.left is `UnaryExpression` whose operator is '!'
-    fn report_with_fix<'a>(expr: &BinaryExpression, ctx: &LintContext<'a>) {
-        use oxc_codegen::{Context, Gen};
+    fn report_with_suggestion(expr: &BinaryExpression, ctx: &LintContext) {
         // Diagnostic points at the unexpected negation
         let diagnostic = no_unsafe_negation_diagnostic(expr.operator.as_str(), expr.left.span());
 
-        let fix_producer = |fixer: RuleFixer<'_, 'a>| {
-            // modify `!a instance of B` to `!(a instanceof B)`
-            let modified_code = {
-                let mut codegen = fixer.codegen();
-                codegen.print_char(b'!');
-                let Expression::UnaryExpression(left) = &expr.left else { unreachable!() };
-                codegen.print_char(b'(');
-                codegen.print_expression(&left.argument);
-                expr.operator.gen(&mut codegen, Context::default());
-                codegen.print_expression(&expr.right);
-                codegen.print_char(b')');
-                codegen.into_source_text()
-            };
-            fixer.replace(expr.span, modified_code)
-        };
-
-        ctx.diagnostic_with_fix(diagnostic, fix_producer);
+        // modify `!a instanceof B` to `!(a instanceof B)`, keeping the original source of both operands
+        let Expression::UnaryExpression(left) = &expr.left else { unreachable!() };
+        let negated = Span::new(left.argument.span().start, expr.span.end);
+        ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+            fixer.replace(expr.span, format!("!({})", fixer.source_range(negated)))
+        });
     }
 }
 
@@ -136,6 +124,8 @@ fn test() {
         ("foo = ! a <= b;", Some(serde_json::json!([{ "enforceForOrderingRelations": true }]))),
         ("foo = ! a >= b;", Some(serde_json::json!([{ "enforceForOrderingRelations": true }]))),
         ("! a <= b", Some(serde_json::json!([{ "enforceForOrderingRelations": true }]))),
+        ("if (!key in obj && other) {}", None),
+        ("!/* comment */ a.b in c", None),
     ];
 
     Tester::new(NoUnsafeNegation::NAME, pass, fail).test_and_snapshot();
@@ -1,5 +1,8 @@
 use oxc_ast::{
-    ast::{match_assignment_target_pattern, Argument, AssignmentTarget, Expression},
+    ast::{
+        match_assignment_target_pattern, Argument, ArrayExpressionElement, AssignmentTarget,
+        Expression,
+    },
     AstKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
@@ -110,7 +113,8 @@ impl Rule for NoUnsafeOptionalChaining {
             AstKind::AssignmentPattern(pat) if pat.left.kind.is_destructuring_pattern() => {
                 Self::check_unsafe_usage(&pat.right, ctx);
             }
-            AstKind::Argument(Argument::SpreadElement(elem)) => {
+            AstKind::Argument(Argument::SpreadElement(elem))
+            | AstKind::ArrayExpressionElement(ArrayExpressionElement::SpreadElement(elem)) => {
                 Self::check_unsafe_usage(&elem.argument, ctx);
             }
             AstKind::VariableDeclarator(decl) if decl.id.kind.is_destructuring_pattern() => {
@@ -268,6 +272,88 @@ fn test() {
         ("with (obj?.foo) {};", None),
         ("async function foo() { with ( await obj?.foo) {}; }", None),
         ("(foo ? obj?.foo : obj?.bar).bar", None),
+        ("(obj?.foo)();", None),
+        ("(obj.foo ?? bar?.baz)();", None),
+        ("(obj.foo || bar?.baz)();", None),
+        ("(obj?.foo && bar)();", None),
+        ("(bar && obj?.foo)();", None),
+        ("(obj?.foo?.())();", None),
+        ("(obj?.foo).bar", None),
+        ("(obj?.foo)[1];", None),
+        ("(obj?.foo)`template`", None),
+        ("new (obj?.foo)();", None),
+        ("new (obj?.foo?.())()", None),
+        ("new (obj?.foo?.() || obj?.bar)()", None),
+        ("async function foo() { (await obj?.foo)(); }", None),
+        ("async function foo() { (await obj?.foo).bar; }", None),
+        ("async function foo() { (bar?.baz ?? await obj?.foo)(); }", None),
+        ("async function foo() { (bar && await obj?.foo)(); }", None),
+        ("async function foo() { (await (bar && obj?.foo))(); }", None),
+        ("[...obj?.foo];", None),
+        ("bar(...obj?.foo);", None),
+        ("new Bar(...obj?.foo);", None),
+        ("const {foo} = obj?.bar;", None),
+        ("const {foo} = obj?.bar();", None),
+        ("const {foo: bar} = obj?.bar();", None),
+        ("const [foo] = obj?.bar;", None),
+        ("const [foo] = obj?.bar?.();", None),
+        ("[{ foo } = obj?.bar] = [];", None),
+        ("({bar: [ foo ] = obj?.prop} = {});", None),
+        ("[[ foo ] = obj?.bar] = [];", None),
+        ("async function foo() { const {foo} = await obj?.bar; }", None),
+        ("async function foo() { const {foo} = await obj?.bar(); }", None),
+        ("async function foo() { const [foo] = await obj?.bar || await obj?.baz; }", None),
+        ("({foo} = obj?.bar);", None),
+        ("({foo} = obj?.bar());", None),
+        ("([foo] = obj?.bar);", None),
+        ("([foo] = obj?.bar?.());", None),
+        ("async function foo() { ({foo} = await obj?.bar); }", None),
+        ("async function foo() { ({foo} = await obj?.bar()); }", None),
+        ("async function foo() { ([foo] = await obj?.bar); }", None),
+        ("async function foo() { ([foo] = await obj?.bar?.()); }", None),
+        ("for (const foo of obj?.bar);", None),
+        ("for (foo of obj?.bar);", None),
+        ("for (const foo of bar?.baz ?? obj?.bar);", None),
+        ("async function foo() { for (const foo of await obj?.bar); }", None),
+        ("function foo({ a } = obj?.bar) {}", None),
+        ("class A extends obj?.foo {}", None),
+        ("var a = class A extends obj?.foo {};", None),
+        ("async function foo() { class A extends (await obj?.foo) {} }", None),
+        ("1 in foo?.bar;", None),
+        ("1 in foo?.bar();", None),
+        ("bar instanceof foo?.bar;", None),
+        ("bar instanceof foo?.bar();", None),
+        ("(bar, obj?.foo)();", None),
+        ("(bar, obj?.foo).baz;", None),
+        ("(foo ? obj?.foo : bar)();", None),
+        ("(foo ? bar : obj?.foo)();", None),
+        ("(obj?.foo as any)();", None),
+        ("obj?.foo + bar;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("obj?.foo - bar;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("obj?.foo * bar;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("obj?.foo / bar;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("obj?.foo % bar;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("obj?.foo ** bar;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("+obj?.foo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("-obj?.foo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("bar += obj?.foo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("bar -= obj?.foo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("bar %= obj?.foo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("bar **= obj?.foo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("bar *= obj?.boo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("bar /= obj?.boo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        (
+            "async function func() { await obj?.foo + await obj?.bar; }",
+            Some(serde_json::json!([{ "disallowArithmeticOperators": true }])),
+        ),
+        (
+            "(foo || obj?.foo) + bar;",
+            Some(serde_json::json!([{ "disallowArithmeticOperators": true }])),
+        ),
+        (
+            "bar + (foo || obj?.foo);",
+            Some(serde_json::json!([{ "disallowArithmeticOperators": true }])),
+        ),
     ];
 
     Tester::new(NoUnsafeOptionalChaining::NAME, pass, fail).test_and_snapshot();
@@ -0,0 +1,241 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_unused_expressions_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Expected an assignment or function call and instead saw an expression.")
+        .with_help("Consider removing this expression")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedExpressions {
+    allow_short_circuit: bool,
+    allow_ternary: bool,
+    allow_tagged_templates: bool,
+    enforce_for_jsx: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unused expressions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// An expression statement whose value is not used and which has no side effects
+    /// has no effect on the state of the program. This usually indicates a mistake,
+    /// such as a missing assignment or function call.
+    ///
+    /// Calls, `new` expressions, assignments, updates, `await`, `yield`, `delete`
+    /// and `void` are always allowed.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// 0;
+    ///
+    /// a && b;
+    ///
+    /// foo.bar;
+    ///
+    /// "use strict"; // not a directive, as it is not at the start of the file
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `allowShortCircuit` (default `false`): allow short circuit evaluations, e.g. `a && b()`.
+    /// - `allowTernary` (default `false`): allow ternary operators, e.g. `a ? b() : c()`.
+    /// - `allowTaggedTemplates` (default `false`): allow tagged template literals, e.g. ``tag`x` ``.
+    /// - `enforceForJSX` (default `false`): also report unused JSX elements and fragments.
+    NoUnusedExpressions,
+    suspicious
+);
+
+impl Rule for NoUnusedExpressions {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let get_bool = |name: &str| {
+            config
+                .and_then(|config| config.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default()
+        };
+        Self {
+            allow_short_circuit: get_bool("allowShortCircuit"),
+            allow_ternary: get_bool("allowTernary"),
+            allow_tagged_templates: get_bool("allowTaggedTemplates"),
+            enforce_for_jsx: get_bool("enforceForJSX"),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(stmt) = node.kind() else {
+            return;
+        };
+        // The concise body of an arrow function is its return value
+        if let Some(body) = ctx.nodes().parent_node(node.id()) {
+            if matches!(
+                ctx.nodes().parent_kind(body.id()),
+                Some(AstKind::ArrowFunctionExpression(arrow)) if arrow.expression
+            ) {
+                return;
+            }
+        }
+        if self.is_disallowed(&stmt.expression) {
+            ctx.diagnostic(no_unused_expressions_diagnostic(stmt.span));
+        }
+    }
+}
+
+impl NoUnusedExpressions {
+    fn is_disallowed(&self, expr: &Expression) -> bool {
+        match expr {
+            Expression::ArrayExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::BinaryExpression(_)
+            | Expression::ClassExpression(_)
+            | Expression::FunctionExpression(_)
+            | Expression::Identifier(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::RegExpLiteral(_)
+            | Expression::StringLiteral(_)
+            | Expression::MetaProperty(_)
+            | Expression::ObjectExpression(_)
+            | Expression::PrivateInExpression(_)
+            | Expression::SequenceExpression(_)
+            | Expression::TemplateLiteral(_)
+            | Expression::ThisExpression(_) => true,
+            expr if expr.is_member_expression() => true,
+            Expression::ChainExpression(chain) => chain.expression.as_member_expression().is_some(),
+            Expression::ConditionalExpression(cond) => {
+                !self.allow_ternary
+                    || self.is_disallowed(&cond.consequent)
+                    || self.is_disallowed(&cond.alternate)
+            }
+            Expression::LogicalExpression(logical) => {
+                !self.allow_short_circuit || self.is_disallowed(&logical.right)
+            }
+            Expression::TaggedTemplateExpression(_) => !self.allow_tagged_templates,
+            Expression::UnaryExpression(unary) => {
+                !matches!(unary.operator, UnaryOperator::Void | UnaryOperator::Delete)
+            }
+            Expression::JSXElement(_) | Expression::JSXFragment(_) => self.enforce_for_jsx,
+            Expression::ParenthesizedExpression(paren) => self.is_disallowed(&paren.expression),
+            Expression::TSAsExpression(ts) => self.is_disallowed(&ts.expression),
+            Expression::TSSatisfiesExpression(ts) => self.is_disallowed(&ts.expression),
+            Expression::TSNonNullExpression(ts) => self.is_disallowed(&ts.expression),
+            Expression::TSTypeAssertion(ts) => self.is_disallowed(&ts.expression),
+            Expression::TSInstantiationExpression(ts) => self.is_disallowed(&ts.expression),
+            _ => false,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("function f(){}", None),
+        ("a = b", None),
+        ("new a", None),
+        ("{}", None),
+        ("f(); g()", None),
+        ("i++", None),
+        ("a()", None),
+        ("a && a()", Some(json!([{ "allowShortCircuit": true }]))),
+        ("a() || (b = c)", Some(json!([{ "allowShortCircuit": true }]))),
+        ("a ? b() : c()", Some(json!([{ "allowTernary": true }]))),
+        (
+            "a ? b() || (c = d) : e()",
+            Some(json!([{ "allowShortCircuit": true, "allowTernary": true }])),
+        ),
+        ("delete foo.bar", None),
+        ("void new C", None),
+        ("\"use strict\";", None),
+        ("\"directive one\"; \"directive two\"; f();", None),
+        ("function foo() {\"use strict\"; return true; }", None),
+        ("var foo = () => {\"use strict\"; return true; }", None),
+        ("function foo() {\"directive one\"; \"directive two\"; f(); }", None),
+        ("function foo() { var foo = \"use strict\"; return true; }", None),
+        ("function* foo(){ yield 0; }", None),
+        ("async function foo() { await 5; }", None),
+        ("async function foo() { await foo.bar; }", None),
+        (
+            "async function foo() { bar && await baz; }",
+            Some(json!([{ "allowShortCircuit": true }])),
+        ),
+        (
+            "async function foo() { foo ? await bar : await baz; }",
+            Some(json!([{ "allowTernary": true }])),
+        ),
+        ("tag`tagged template literal`", Some(json!([{ "allowTaggedTemplates": true }]))),
+        (
+            "shouldNotBeAffectedByAllowTemplateTagsOption()",
+            Some(json!([{ "allowTaggedTemplates": true }])),
+        ),
+        ("import(\"foo\")", None),
+        ("func?.(\"foo\")", None),
+        ("obj?.foo(\"bar\")", None),
+        ("<div />", None),
+        ("<></>", None),
+        ("var partial = <div />", None),
+        ("var partial = <div />", Some(json!([{ "enforceForJSX": true }]))),
+        ("var partial = <></>", Some(json!([{ "enforceForJSX": true }]))),
+        ("const f = () => a + b", None),
+        ("const f = () => foo.bar", None),
+        ("(a = b) as any", None),
+        ("foo!()", None),
+    ];
+
+    let fail = vec![
+        ("0", None),
+        ("a", None),
+        ("f(), 0", None),
+        ("{0}", None),
+        ("[]", None),
+        ("a && b();", None),
+        ("a() || false", None),
+        ("a || (b = c)", None),
+        ("a ? b() || (c = d) : e", None),
+        ("`untagged template literal`", None),
+        ("tag`tagged template literal`", None),
+        ("a && b()", Some(json!([{ "allowTernary": true }]))),
+        ("a ? b() : c()", Some(json!([{ "allowShortCircuit": true }]))),
+        ("a || b", Some(json!([{ "allowShortCircuit": true }]))),
+        ("a() && b", Some(json!([{ "allowShortCircuit": true }]))),
+        ("a ? b : 0", Some(json!([{ "allowTernary": true }]))),
+        ("a ? b : c()", Some(json!([{ "allowTernary": true }]))),
+        ("foo.bar;", None),
+        ("!a", None),
+        ("+a", None),
+        ("\"directive one\"; f(); \"directive two\";", None),
+        ("function foo() {\"directive one\"; f(); \"directive two\"; }", None),
+        ("if (0) { \"not a directive\"; f(); }", None),
+        ("function foo() { var foo = true; \"use strict\"; }", None),
+        ("var foo = () => { var foo = true; \"use strict\"; }", None),
+        ("`untagged template literal`", Some(json!([{ "allowTaggedTemplates": true }]))),
+        ("`untagged template literal`", Some(json!([{ "allowTaggedTemplates": false }]))),
+        ("tag`tagged template literal`", Some(json!([{ "allowTaggedTemplates": false }]))),
+        ("obj?.foo", None),
+        ("obj?.foo.bar", None),
+        ("obj?.foo().bar", None),
+        ("<div />", Some(json!([{ "enforceForJSX": true }]))),
+        ("<></>", Some(json!([{ "enforceForJSX": true }]))),
+        ("class C {}; new.target", None),
+        ("import.meta", None),
+        ("a as any", None),
+        ("foo!", None),
+        ("(a, b)", None),
+    ];
+
+    Tester::new(NoUnusedExpressions::NAME, pass, fail).test_and_snapshot();
+}
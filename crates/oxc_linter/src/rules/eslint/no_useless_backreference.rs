@@ -0,0 +1,483 @@
+use std::ops::Range;
+
+use oxc_ast::{
+    ast::{Argument, Expression, RegExpFlags},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{ast_util::get_regexp_constructor_pattern, context::LintContext, rule::Rule, AstNode};
+
+fn no_useless_backreference_diagnostic(bref: &UselessBackreference, span0: Span) -> OxcDiagnostic {
+    let other_groups = match bref.other_groups {
+        0 => String::new(),
+        1 => " and another group".to_string(),
+        n => format!(" and other {n} groups"),
+    };
+    let reason = match bref.problem {
+        Problem::Nested => "from within that group",
+        Problem::Forward => "which appears later in the pattern",
+        Problem::Backward => "which appears before in the same lookbehind",
+        Problem::Disjunctive => "which is in another alternative",
+        Problem::IntoNegativeLookaround => "which is in a negative lookaround",
+    };
+    OxcDiagnostic::warn(format!(
+        "Backreference '{}' will be ignored. It references group '{}'{other_groups} {reason}.",
+        bref.bref, bref.group
+    ))
+    .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessBackreference;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow useless backreferences in regular expressions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A backreference that refers to a group which hasn't matched yet, or can never
+    /// match at that point, always matches the empty string. Such backreferences are
+    /// most likely a mistake: they are a reference to a group that appears later, to a
+    /// group in another alternative, to a group in a negative lookaround, or to the
+    /// group the backreference itself is in.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// /^(?:(a)|\1b)$/; // reference to (a) from another alternative
+    /// /^(?:(a)|b(?:c|\1))$/; // reference to (a) from another alternative
+    /// /^(?:a|b(?:(c)|\1))$/; // reference to (c) from another alternative
+    /// /\1(a)/; // forward reference to (a)
+    /// RegExp('(a)\\2(b)'); // forward reference to (b)
+    /// /(?:a)(b)\2(c)/; // forward reference to (c)
+    /// /\k<foo>(?<foo>a)/; // forward reference to (?<foo>a)
+    /// /(?<=(a)\1)b/; // backward reference to (a) from within the same lookbehind
+    /// /(?<!(a)\1)b/; // backward reference to (a) from within the same lookbehind
+    /// new RegExp('(\\1)'); // nested reference to (\1)
+    /// /^((a)\1)$/; // nested reference to ((a)\1)
+    /// /a(?<foo>(.)b\1)/; // nested reference to (?<foo>(.)b\1)
+    /// /a(?!(b)).\1/; // reference to (b) into a negative lookahead
+    /// /(?<!(a))b\1/; // reference to (a) into a negative lookbehind
+    /// ```
+    NoUselessBackreference,
+    suspicious
+);
+
+impl Rule for NoUselessBackreference {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::RegExpLiteral(literal) => {
+                let unicode_sets = literal.regex.flags.contains(RegExpFlags::V);
+                for bref in find_useless_backreferences(&literal.regex.pattern, unicode_sets) {
+                    // The pattern starts after the opening `/`
+                    #[allow(clippy::cast_possible_truncation)]
+                    let span = Span::new(
+                        literal.span.start + 1 + bref.span.start as u32,
+                        literal.span.start + 1 + bref.span.end as u32,
+                    );
+                    ctx.diagnostic(no_useless_backreference_diagnostic(&bref, span));
+                }
+            }
+            AstKind::NewExpression(new_expr) => {
+                check_regexp_constructor(&new_expr.callee, &new_expr.arguments, ctx);
+            }
+            AstKind::CallExpression(call) => {
+                check_regexp_constructor(&call.callee, &call.arguments, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Checks `new RegExp("\\1(a)")` and `RegExp("\\1(a)")`.
+fn check_regexp_constructor<'a>(
+    callee: &Expression<'a>,
+    arguments: &'a oxc_allocator::Vec<'a, Argument<'a>>,
+    ctx: &LintContext<'a>,
+) {
+    let Some((pattern, flags)) = get_regexp_constructor_pattern(callee, arguments, ctx) else {
+        return;
+    };
+    let unicode_sets = flags.is_some_and(|flags| flags.contains(RegExpFlags::V));
+    for bref in find_useless_backreferences(&pattern.value, unicode_sets) {
+        ctx.diagnostic(no_useless_backreference_diagnostic(&bref, pattern.span));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Problem {
+    Nested,
+    Forward,
+    Backward,
+    Disjunctive,
+    IntoNegativeLookaround,
+}
+
+struct UselessBackreference<'s> {
+    problem: Problem,
+    bref: &'s str,
+    group: &'s str,
+    /// Number of other groups with the same name that are also unreachable
+    other_groups: usize,
+    span: Range<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RegexNodeKind {
+    Pattern,
+    Alternative,
+    Group,
+    CapturingGroup,
+    Lookaround { behind: bool, negative: bool },
+}
+
+/// A node of the group structure of a regex pattern. Everything but groups,
+/// alternatives and lookarounds is left out.
+struct RegexNode<'s> {
+    kind: RegexNodeKind,
+    name: Option<&'s str>,
+    parent: Option<usize>,
+    span: Range<usize>,
+}
+
+enum Reference<'s> {
+    Index(usize),
+    Name(&'s str),
+}
+
+struct Backreference<'s> {
+    /// The alternative containing the backreference
+    parent: usize,
+    reference: Reference<'s>,
+    span: Range<usize>,
+}
+
+struct RegexTree<'s> {
+    nodes: Vec<RegexNode<'s>>,
+    /// Capturing groups in the order of their opening parentheses
+    capturing_groups: Vec<usize>,
+    backreferences: Vec<Backreference<'s>>,
+}
+
+impl<'s> RegexTree<'s> {
+    /// Parses the groups, alternatives and backreferences of `pattern`.
+    /// Character classes only nest with the `v` flag.
+    fn parse(pattern: &'s str, unicode_sets: bool) -> Self {
+        let bytes = pattern.as_bytes();
+        let mut tree = Self { nodes: vec![], capturing_groups: vec![], backreferences: vec![] };
+        let root = tree.push(RegexNodeKind::Pattern, None, None, 0);
+        let mut alternative = tree.push(RegexNodeKind::Alternative, None, Some(root), 0);
+        let mut class_depth = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\\' if class_depth == 0 => {
+                    let start = i;
+                    i += 1;
+                    match bytes.get(i) {
+                        Some(b'1'..=b'9') => {
+                            let len = bytes[i..].iter().take_while(|b| b.is_ascii_digit()).count();
+                            i += len;
+                            if let Ok(index) = pattern[start + 1..i].parse() {
+                                tree.backreferences.push(Backreference {
+                                    parent: alternative,
+                                    reference: Reference::Index(index),
+                                    span: start..i,
+                                });
+                            }
+                            continue;
+                        }
+                        Some(b'k') if bytes.get(i + 1) == Some(&b'<') => {
+                            if let Some(len) = pattern[i + 2..].find('>') {
+                                let name = &pattern[i + 2..i + 2 + len];
+                                i += len + 3;
+                                tree.backreferences.push(Backreference {
+                                    parent: alternative,
+                                    reference: Reference::Name(name),
+                                    span: start..i,
+                                });
+                                continue;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                b'\\' => i += 1,
+                b'[' if class_depth == 0 || unicode_sets => class_depth += 1,
+                b']' if class_depth > 0 => class_depth -= 1,
+                _ if class_depth > 0 => {}
+                b'(' => {
+                    let rest = &pattern[i + 1..];
+                    let (kind, name) = if let Some(rest) = rest.strip_prefix("?<") {
+                        match rest.as_bytes().first() {
+                            Some(b'=') => {
+                                (RegexNodeKind::Lookaround { behind: true, negative: false }, None)
+                            }
+                            Some(b'!') => {
+                                (RegexNodeKind::Lookaround { behind: true, negative: true }, None)
+                            }
+                            _ => (RegexNodeKind::CapturingGroup, rest.split('>').next()),
+                        }
+                    } else if rest.starts_with("?=") {
+                        (RegexNodeKind::Lookaround { behind: false, negative: false }, None)
+                    } else if rest.starts_with("?!") {
+                        (RegexNodeKind::Lookaround { behind: false, negative: true }, None)
+                    } else if rest.starts_with('?') {
+                        (RegexNodeKind::Group, None)
+                    } else {
+                        (RegexNodeKind::CapturingGroup, None)
+                    };
+                    let group = tree.push(kind, name, Some(alternative), i);
+                    if kind == RegexNodeKind::CapturingGroup {
+                        tree.capturing_groups.push(group);
+                    }
+                    alternative = tree.push(RegexNodeKind::Alternative, None, Some(group), i + 1);
+                }
+                b'|' => {
+                    let parent = tree.nodes[alternative].parent;
+                    tree.nodes[alternative].span.end = i;
+                    alternative = tree.push(RegexNodeKind::Alternative, None, parent, i + 1);
+                }
+                b')' => {
+                    let Some(group) = tree.nodes[alternative].parent.filter(|&g| g != root) else {
+                        // Unbalanced parenthesis
+                        return tree;
+                    };
+                    tree.nodes[alternative].span.end = i;
+                    tree.nodes[group].span.end = i + 1;
+                    alternative = tree.nodes[group].parent.unwrap_or(root);
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        tree.nodes[alternative].span.end = bytes.len();
+        tree.nodes[root].span.end = bytes.len();
+        tree
+    }
+
+    fn push(
+        &mut self,
+        kind: RegexNodeKind,
+        name: Option<&'s str>,
+        parent: Option<usize>,
+        start: usize,
+    ) -> usize {
+        self.nodes.push(RegexNode { kind, name, parent, span: start..start });
+        self.nodes.len() - 1
+    }
+
+    /// Returns the ancestors of `node`, starting from the root and ending with `node`.
+    fn path_from_root(&self, node: usize) -> Vec<usize> {
+        let mut path = vec![node];
+        let mut node = node;
+        while let Some(parent) = self.nodes[node].parent {
+            path.push(parent);
+            node = parent;
+        }
+        path.reverse();
+        path
+    }
+
+    fn resolve(&self, reference: &Reference) -> Vec<usize> {
+        match reference {
+            Reference::Index(index) => index
+                .checked_sub(1)
+                .and_then(|i| self.capturing_groups.get(i))
+                .into_iter()
+                .copied()
+                .collect(),
+            Reference::Name(name) => self
+                .capturing_groups
+                .iter()
+                .copied()
+                .filter(|&group| self.nodes[group].name == Some(name))
+                .collect(),
+        }
+    }
+
+    /// Returns why `bref` can never match `group`, if it can't.
+    fn find_problem(&self, bref: &Backreference, group: usize) -> Option<Problem> {
+        let bref_path = self.path_from_root(bref.parent);
+        if bref_path.contains(&group) {
+            return Some(Problem::Nested);
+        }
+        let group_path = self.path_from_root(group);
+        let common = bref_path.iter().zip(&group_path).take_while(|(a, b)| a == b).count();
+        // The nodes between the lowest common ancestor and the group
+        let group_cut = &group_path[common..];
+        if self.nodes[group_cut[0]].kind == RegexNodeKind::Alternative {
+            return Some(Problem::Disjunctive);
+        }
+        let is_matching_backward =
+            group_path[..common].iter().rev().find_map(|&node| match self.nodes[node].kind {
+                RegexNodeKind::Lookaround { behind, .. } => Some(behind),
+                _ => None,
+            }) == Some(true);
+        let group_span = &self.nodes[group].span;
+        if !is_matching_backward && bref.span.end <= group_span.start {
+            return Some(Problem::Forward);
+        }
+        if is_matching_backward && group_span.end <= bref.span.start {
+            return Some(Problem::Backward);
+        }
+        if group_cut.iter().any(|&node| {
+            matches!(self.nodes[node].kind, RegexNodeKind::Lookaround { negative: true, .. })
+        }) {
+            return Some(Problem::IntoNegativeLookaround);
+        }
+        None
+    }
+}
+
+fn find_useless_backreferences(pattern: &str, unicode_sets: bool) -> Vec<UselessBackreference> {
+    let tree = RegexTree::parse(pattern, unicode_sets);
+    let mut useless = vec![];
+    for bref in &tree.backreferences {
+        let groups = tree.resolve(&bref.reference);
+        let mut problems = vec![];
+        for group in groups {
+            // A backreference is only useless if it can't match any of its groups
+            let Some(problem) = tree.find_problem(bref, group) else {
+                problems.clear();
+                break;
+            };
+            problems.push((problem, group));
+        }
+        // Prefer problems in the same alternative as the backreference
+        if problems.iter().any(|(problem, _)| *problem != Problem::Disjunctive) {
+            problems.retain(|(problem, _)| *problem != Problem::Disjunctive);
+        }
+        let Some(&(problem, group)) = problems.first() else {
+            continue;
+        };
+        useless.push(UselessBackreference {
+            problem,
+            bref: &pattern[bref.span.clone()],
+            group: &pattern[tree.nodes[group].span.clone()],
+            other_groups: problems.len() - 1,
+            span: bref.span.clone(),
+        });
+    }
+    useless
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r"'\1(a)'",
+        r"regExp('\\1(a)')",
+        r"new Regexp('\\1(a)', 'u')",
+        r"RegExp.foo('\\1(a)', 'u')",
+        r"new foo.RegExp('\\1(a)')",
+        "RegExp(p)",
+        "new RegExp(p, 'u')",
+        r"RegExp('\\1(a)' + suffix)",
+        r"new RegExp(`${prefix}\\1(a)`)",
+        r"let RegExp; new RegExp('\\1(a)');",
+        r"function foo() { var RegExp; RegExp('\\1(a)', 'u'); }",
+        r"function foo(RegExp) { new RegExp('\\1(a)'); }",
+        r"if (foo) { const RegExp = bar; RegExp('\\1(a)'); }",
+        "/(?:)/",
+        "/(?:a)/",
+        "new RegExp('')",
+        "RegExp('(?:a)|[b]')",
+        r"/^ab|[cd].\n$/",
+        r"/\1/",
+        r"/\1/u",
+        r"/(a)\1/",
+        r"/(a).\1/",
+        r"RegExp('(a)\\1(b)')",
+        r"/(a)(b)\2(c)/",
+        r"/(?<foo>a)\k<foo>/",
+        r"new RegExp('(?<foo>a)\\k<foo>', 'u')",
+        r"/(a)\1|b/",
+        r"/(a|b)\1/",
+        r"/(?:(a)\1)/",
+        r"/(?=(a)\1)/",
+        r"/(a)(?=\1)/",
+        r"/(a)(?!\1)/",
+        r"/(?!(a)\1)/",
+        r"/(?<=\1(a))/",
+        r"/(?<!\1(a))/",
+        r"/(?<=(?:\1|b)(a))/",
+        r"/\k<a>(?<b>b)/",
+        r"/[\1(a)]/",
+        r"/\(\1)/",
+        r"/\10(a)/",
+        r"/[(a)\1]/v",
+        r"/(?:(?<a>x)|(?<a>y))\k<a>/",
+        r"/(?:(?<a>x)\k<a>|(?<a>y))/",
+    ];
+
+    let fail = vec![
+        r"/\1(a)/",
+        r"/\1.(a)/",
+        r"/(?:\1)(?:(a))/",
+        r"/(?:\1)(?:((a)))/",
+        r"/(?:\2)(?:((a)))/",
+        r"/(?:\1)(?:((?:a)))/",
+        r"/(\2)(a)/",
+        r"RegExp('(a)\\2(b)')",
+        r"/(?:a)(b)\2(c)/",
+        r"/\k<foo>(?<foo>a)/",
+        r"/(?:a(b)\2)(c)/",
+        r"new RegExp('(a)(b)\\3(c)')",
+        r"/\1(?<=(a))./",
+        r"/\1(?<!(a))./",
+        r"/(?<=\1)(?<=(a))/",
+        r"/(?<!\1)(?<!(a))/",
+        r"/(?=\1(a))./",
+        r"/(?!\1(a))./",
+        r"/(?<=(a)\1)b/",
+        r"/(?<!.(a).\1.)b/",
+        r"/(.)(?<!(b|c)\2)d/",
+        r"/(?<=(?:(a)\1))b/",
+        r"/(?<=(?:(a))\1)b/",
+        r"/(?<=(a)(?:\1))b/",
+        r"/(?<!(?:(a))(?:\1))b/",
+        r"/(?<!(?:(a))(?:\1)|.)b/",
+        r"/.(?!x|(?<!(a)\1))b/",
+        r"/(a\1)/",
+        r"/(a\1)b/",
+        r"/(a(b)c|\2)/",
+        r"/(?:a|(\1))/",
+        r"/(?:((\1)))/",
+        r"/(?=(\1))/",
+        r"/(?<!(\1))/",
+        r"/(a)|\1b/",
+        r"/^(?:(a)|\1b)$/",
+        r"/^(?:(a)|b(?:c|\1))$/",
+        r"/^(?:a|b(?:(c)|\1))$/",
+        r"/^(?:(a(?!b))|\1b)+$/",
+        r"/^(?:(?:(a)(?!b))|\1b)+$/",
+        r"/^(?:(a(?=a))|\1b)+$/",
+        r"/^(?:(a)(?=a)|\1b)+$/",
+        r"/.(?:a|(b)).|(?:(\1)|c)./",
+        r"/.(?!(a)|\1)./",
+        r"/.(?<=\1|(a))./",
+        r"/a(?!(b)).\1/",
+        r"/(?<!(a))b\1/",
+        r"/(?<!(a))(?:\1)/",
+        r"/.(?<!a|(b)).\1/",
+        r"/.(?!(a)).(?!\1)./",
+        r"/.(?<!(a)).(?<!\1)./",
+        r"/.(?=(?!(a))\1)./",
+        r"/.(?<!\1(?!(a)))/",
+        r"/\1(a)(b)\2/",
+        r"/\1(a)\1/",
+        r"/\1(a)\2(b)/",
+        r"/\1.(?<=(a)\1)/",
+        r"/(?!\1(a)).\1/",
+        r"/(a)\2(b)/; RegExp('(\\1)');",
+        r"/\k<a>(?:(?<a>x)|(?<a>y))/",
+    ];
+
+    Tester::new(NoUselessBackreference::NAME, pass, fail).test_and_snapshot();
+}
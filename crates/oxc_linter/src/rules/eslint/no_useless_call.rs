@@ -0,0 +1,197 @@
+use oxc_ast::{ast::Argument, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    ast_util::{can_be_adjacent, get_member_expression},
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+fn no_useless_call_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unnecessary `.{x1}()`."))
+        .with_help("Call the function directly instead.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessCall;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unnecessary calls to `.call()` and `.apply()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `Function.prototype.call()` and `Function.prototype.apply()` can be used to
+    /// call a function with a given `this` value. When that value is the object the
+    /// function is accessed on, or `null`/`undefined` for a plain function, the call
+    /// is equivalent to a normal function invocation and only makes the code slower
+    /// and harder to read.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// foo.call(undefined, 1, 2, 3);
+    /// foo.apply(undefined, [1, 2, 3]);
+    /// foo.call(null, 1, 2, 3);
+    /// foo.apply(null, [1, 2, 3]);
+    ///
+    /// obj.foo.call(obj, 1, 2, 3);
+    /// obj.foo.apply(obj, [1, 2, 3]);
+    /// ```
+    NoUselessCall,
+    suspicious
+);
+
+impl Rule for NoUselessCall {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else {
+            return;
+        };
+        let Some(callee) = get_member_expression(&call_expr.callee) else {
+            return;
+        };
+        let Some(name) = callee.static_property_name() else {
+            return;
+        };
+        // `.apply()` with a non-literal array may spread arguments which aren't known statically
+        let is_call_or_non_variadic_apply = match name {
+            "call" => !call_expr.arguments.is_empty(),
+            "apply" => matches!(call_expr.arguments.as_slice(), [_, Argument::ArrayExpression(_)]),
+            _ => false,
+        };
+        if !is_call_or_non_variadic_apply {
+            return;
+        }
+        let Some(this_arg) = call_expr.arguments[0].as_expression() else {
+            return;
+        };
+
+        let is_useless = match get_member_expression(callee.object()) {
+            Some(applied) => {
+                let expected_this = applied.object().without_parenthesized();
+                get_tokens(expected_this.span(), ctx)
+                    == get_tokens(this_arg.without_parenthesized().span(), ctx)
+            }
+            None => this_arg.is_null_or_undefined(),
+        };
+        if is_useless {
+            ctx.diagnostic(no_useless_call_diagnostic(call_expr.span, name));
+        }
+    }
+}
+
+/// Returns the source text of the span without comments and with whitespace only
+/// kept where it separates two tokens, so that equivalent expressions written with
+/// different formatting compare equal.
+fn get_tokens(span: Span, ctx: &LintContext) -> String {
+    let mut source = String::new();
+    let mut start = span.start;
+    for comment in ctx.semantic().trivias().comments_range(span.start..span.end) {
+        let comment_start = comment.span.start - 2;
+        source.push_str(ctx.source_range(Span::new(start, comment_start)));
+        source.push(' ');
+        start = if comment.kind.is_multi_line() { comment.span.end + 2 } else { comment.span.end };
+    }
+    source.push_str(ctx.source_range(Span::new(start, span.end)));
+
+    let mut tokens = String::with_capacity(source.len());
+    let mut quote = None;
+    let mut escaped = false;
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        if let Some(q) = quote {
+            tokens.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+        } else if c.is_whitespace() {
+            let (Some(prev), Some(&next)) = (tokens.chars().next_back(), chars.peek()) else {
+                continue;
+            };
+            if !next.is_whitespace() && !can_be_adjacent(prev, Some(next)) {
+                tokens.push(' ');
+            }
+        } else {
+            if matches!(c, '"' | '\'' | '`') {
+                quote = Some(c);
+            }
+            tokens.push(c);
+        }
+    }
+    tokens
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "foo.apply(obj, 1, 2);",
+        "obj.foo.apply(null, 1, 2);",
+        "obj.foo.apply(otherObj, 1, 2);",
+        "a.b(x, y).c.foo.apply(a.b(x, z).c, 1, 2);",
+        "foo.apply(obj, [1, 2]);",
+        "obj.foo.apply(null, [1, 2]);",
+        "obj.foo.apply(otherObj, [1, 2]);",
+        "a.b(x, y).c.foo.apply(a.b(x, z).c, [1, 2]);",
+        "a.b.foo.apply(a.b.c, [1, 2]);",
+        "foo.apply(null, args);",
+        "obj.foo.apply(obj, args);",
+        "var call; foo[call](null, 1, 2);",
+        "var apply; foo[apply](null, [1, 2]);",
+        "foo.call();",
+        "obj.foo.call();",
+        "foo.apply();",
+        "obj.foo.apply();",
+        "obj?.foo.bar.call(obj.foo, 1, 2);",
+        "class C { #call; wrap(foo) { foo.#call(undefined, 1, 2); } }",
+        "foo.call(obj, 1, 2);",
+        "obj.foo.call(null, 1, 2);",
+        "obj.foo.call(undefined, 1, 2);",
+        "foo.call(...args);",
+        "obj.foo.call(obj.bar, 1, 2);",
+        "a['b c'].foo.call(a['bc'], 1, 2);",
+        "foo.bind(null, 1, 2);",
+    ];
+
+    let fail = vec![
+        "foo.call(undefined, 1, 2);",
+        "foo.call(void 0, 1, 2);",
+        "foo.call(null, 1, 2);",
+        "obj.foo.call(obj, 1, 2);",
+        "a.b.c.foo.call(a.b.c, 1, 2);",
+        "a.b(x, y).c.foo.call(a.b(x, y).c, 1, 2);",
+        "foo.apply(undefined, [1, 2]);",
+        "foo.apply(void 0, [1, 2]);",
+        "foo.apply(null, [1, 2]);",
+        "obj.foo.apply(obj, [1, 2]);",
+        "a.b.c.foo.apply(a.b.c, [1, 2]);",
+        "a.b(x, y).c.foo.apply(a.b(x, y).c, [1, 2]);",
+        "[].concat.apply([ ], [1, 2]);",
+        "[].concat.apply([\n/*empty*/\n], [1, 2]);",
+        "abc.get(\"foo\", 0).concat.apply(abc . get(\"foo\",  0 ), [1, 2]);",
+        "foo.call?.(undefined, 1, 2);",
+        "foo?.call(undefined, 1, 2);",
+        "(foo?.call)(undefined, 1, 2);",
+        "obj.foo.call?.(obj, 1, 2);",
+        "obj?.foo.call(obj, 1, 2);",
+        "(obj?.foo).call(obj, 1, 2);",
+        "(obj?.foo.call)(obj, 1, 2);",
+        "obj?.foo.bar.call(obj?.foo, 1, 2);",
+        "(obj?.foo).bar.call(obj?.foo, 1, 2);",
+        "obj.foo?.bar.call(obj.foo, 1, 2);",
+        "foo['call'](null, 1, 2);",
+        "this.foo.call(this, 1, 2);",
+        "obj.foo.call(obj);",
+    ];
+
+    Tester::new(NoUselessCall::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,305 @@
+use oxc_ast::{
+    ast::{Expression, PropertyKey},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{ast_util::can_be_adjacent, context::LintContext, rule::Rule, AstNode};
+
+fn no_useless_computed_key_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unnecessarily computed property `[{x1}]` found."))
+        .with_help("Remove the brackets around the key.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoUselessComputedKey {
+    enforce_for_class_members: bool,
+}
+
+impl Default for NoUselessComputedKey {
+    fn default() -> Self {
+        Self { enforce_for_class_members: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unnecessary computed property keys in objects and classes.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// It's unnecessary to use computed properties with literals such as
+    /// `const foo = { ["a"]: "b" };`, which can be written as `const foo = { "a": "b" };`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// const a = { ['0']: 0 };
+    /// const b = { ['0+1,234']: 0 };
+    /// const c = { [0]: 0 };
+    /// const d = { ['x']: 0 };
+    /// const e = { ['x']() {} };
+    ///
+    /// class Foo {
+    ///     ['foo'] = 'bar';
+    ///     static ['baz']() {}
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `enforceForClassMembers` (default `true`): also check class fields and methods.
+    /// `__proto__` in object literals, `constructor` in classes and static `prototype`
+    /// members are allowed, as their computed forms have different semantics.
+    NoUselessComputedKey,
+    style
+);
+
+impl Rule for NoUselessComputedKey {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            enforce_for_class_members: value
+                .get(0)
+                .and_then(|config| config.get("enforceForClassMembers"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ObjectProperty(prop) if prop.computed => {
+                let Some((literal_span, name)) = get_literal_key(&prop.key) else {
+                    return;
+                };
+                if name != "__proto__" {
+                    report(prop.span, &prop.key, literal_span, ctx);
+                }
+            }
+            AstKind::ObjectPattern(pattern) => {
+                for prop in pattern.properties.iter().filter(|prop| prop.computed) {
+                    if let Some((literal_span, _)) = get_literal_key(&prop.key) {
+                        report(prop.span, &prop.key, literal_span, ctx);
+                    }
+                }
+            }
+            AstKind::MethodDefinition(method) if method.computed => {
+                if !self.enforce_for_class_members {
+                    return;
+                }
+                let Some((literal_span, name)) = get_literal_key(&method.key) else {
+                    return;
+                };
+                let is_allowed =
+                    if method.r#static { name == "prototype" } else { name == "constructor" };
+                if !is_allowed {
+                    report(method.span, &method.key, literal_span, ctx);
+                }
+            }
+            AstKind::PropertyDefinition(prop) if prop.computed => {
+                if !self.enforce_for_class_members {
+                    return;
+                }
+                let Some((literal_span, name)) = get_literal_key(&prop.key) else {
+                    return;
+                };
+                if name != "constructor" && !(prop.r#static && name == "prototype") {
+                    report(prop.span, &prop.key, literal_span, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns the span and property name of a string or number literal key, which may
+/// be wrapped in parentheses.
+fn get_literal_key(key: &PropertyKey) -> Option<(Span, String)> {
+    match key.as_expression()?.without_parenthesized() {
+        Expression::StringLiteral(lit) => Some((lit.span, lit.value.to_string())),
+        Expression::NumericLiteral(lit) => Some((lit.span, lit.value.to_string())),
+        _ => None,
+    }
+}
+
+fn report(member_span: Span, key: &PropertyKey, literal_span: Span, ctx: &LintContext) {
+    let key_span = key.span();
+    let literal = ctx.source_range(literal_span);
+    let diagnostic = no_useless_computed_key_diagnostic(key_span, ctx.source_range(key_span));
+
+    let before_key = ctx.source_range(Span::new(member_span.start, key_span.start));
+    let after_key = ctx.source_range(Span::new(key_span.end, member_span.end));
+    let (Some(open), Some(close)) = (before_key.rfind('['), after_key.find(']')) else {
+        ctx.diagnostic(diagnostic);
+        return;
+    };
+    let brackets_span = Span::new(
+        member_span.start + u32::try_from(open).unwrap_or_default(),
+        key_span.end + u32::try_from(close).unwrap_or_default() + 1,
+    );
+    if ctx.semantic().trivias().has_comments_between(brackets_span) {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+
+    // Keep the key separate from a preceding keyword, e.g. `get[2]() {}`
+    let before = ctx.source_text()[..brackets_span.start as usize].chars().next_back();
+    let fixed = if before.is_some_and(|c| !can_be_adjacent(c, literal.chars().next())) {
+        format!(" {literal}")
+    } else {
+        literal.to_string()
+    };
+    ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(brackets_span, fixed));
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("({ 'a': 0, b(){} })", None),
+        ("({ [x]: 0 });", None),
+        ("({ a: 0, [b](){} })", None),
+        ("({ ['__proto__']: [] })", None),
+        ("var { 'a': foo } = obj", None),
+        ("var { [a]: b } = obj;", None),
+        ("var { a } = obj;", None),
+        ("var { a: a } = obj;", None),
+        ("var { a: b } = obj;", None),
+        ("class Foo { a() {} }", None),
+        ("class Foo { 'a'() {} }", None),
+        ("class Foo { [x]() {} }", None),
+        ("class Foo { ['constructor']() {} }", None),
+        ("class Foo { static ['prototype']() {} }", None),
+        ("(class { 'a'() {} })", None),
+        ("(class { [x]() {} })", None),
+        ("(class { ['constructor']() {} })", None),
+        ("(class { static ['prototype']() {} })", None),
+        ("class Foo { 'x'() {} }", None),
+        ("(class { [x]() {} })", None),
+        ("class Foo { static constructor() {} }", None),
+        ("class Foo { prototype() {} }", None),
+        ("class Foo { ['x']() {} }", Some(json!([{ "enforceForClassMembers": false }]))),
+        ("(class { ['x']() {} })", Some(json!([{ "enforceForClassMembers": false }]))),
+        (
+            "class Foo { static ['constructor']() {} }",
+            Some(json!([{ "enforceForClassMembers": false }])),
+        ),
+        ("class Foo { ['prototype']() {} }", Some(json!([{ "enforceForClassMembers": false }]))),
+        ("class Foo { a }", None),
+        ("class Foo { ['constructor'] }", None),
+        ("class Foo { static ['constructor'] }", None),
+        ("class Foo { static ['prototype'] }", None),
+        ("class Foo { ['x'] }", Some(json!([{ "enforceForClassMembers": false }]))),
+        ("({ [`a`]: 0 })", None),
+        ("({ [-1]: 0 })", None),
+        ("({ [1n]: 0 })", None),
+    ];
+
+    let fail = vec![
+        ("({ ['0']: 0 })", None),
+        ("var { ['0']: a } = obj", None),
+        ("({ ['0+1,234']: 0 })", None),
+        ("({ [0]: 0 })", None),
+        ("var { [0]: a } = obj", None),
+        ("({ ['x']: 0 })", None),
+        ("var { ['x']: a } = obj", None),
+        ("var { ['__proto__']: a } = obj", None),
+        ("({ ['x']() {} })", None),
+        ("({ [/* this comment prevents a fix */ 'x']: 0 })", None),
+        ("({ ['x' /* this comment also prevents a fix */]: 0 })", None),
+        ("({ [('x')]: 0 })", None),
+        ("var { [('x')]: a } = obj", None),
+        ("({ *['x']() {} })", None),
+        ("({ async ['x']() {} })", None),
+        ("({ get[.2]() {} })", None),
+        ("({ set[.2](value) {} })", None),
+        ("({ async[.2]() {} })", None),
+        ("({ [2]() {} })", None),
+        ("({ get [2]() {} })", None),
+        ("({ set [2](value) {} })", None),
+        ("({ async [2]() {} })", None),
+        ("({ get[2]() {} })", None),
+        ("({ set[2](value) {} })", None),
+        ("({ async[2]() {} })", None),
+        ("({ get['foo']() {} })", None),
+        ("({ *[2]() {} })", None),
+        ("({ async*[2]() {} })", None),
+        ("({ ['constructor']: 1 })", None),
+        ("({ ['prototype']: 1 })", None),
+        ("class Foo { ['0']() {} }", None),
+        ("class Foo { ['0+1,234']() {} }", None),
+        ("class Foo { ['x']() {} }", None),
+        ("class Foo { [/* this comment prevents a fix */ 'x']() {} }", None),
+        ("class Foo { ['x' /* this comment also prevents a fix */]() {} }", None),
+        ("class Foo { *['x']() {} }", None),
+        ("class Foo { async ['x']() {} }", None),
+        ("class Foo { get[.2]() {} }", None),
+        ("class Foo { set[.2](value) {} }", None),
+        ("class Foo { async[.2]() {} }", None),
+        ("class Foo { [2]() {} }", None),
+        ("class Foo { get [2]() {} }", None),
+        ("class Foo { set [2](value) {} }", None),
+        ("class Foo { async [2]() {} }", None),
+        ("class Foo { get[2]() {} }", None),
+        ("class Foo { set[2](value) {} }", None),
+        ("class Foo { async[2]() {} }", None),
+        ("class Foo { get['foo']() {} }", None),
+        ("class Foo { *[2]() {} }", None),
+        ("class Foo { async*[2]() {} }", None),
+        ("class Foo { static ['constructor']() {} }", None),
+        ("class Foo { ['prototype']() {} }", None),
+        ("(class { ['x']() {} })", None),
+        ("(class { ['__proto__']() {} })", None),
+        ("(class { static ['__proto__']() {} })", None),
+        ("(class { static ['constructor']() {} })", None),
+        ("(class { ['prototype']() {} })", None),
+        ("class Foo { ['0'] }", None),
+        ("class Foo { ['0'] = 0 }", None),
+        ("class Foo { static[0] }", None),
+        ("class Foo { ['#foo'] }", None),
+        ("(class { ['__proto__'] })", None),
+        ("(class { static ['__proto__'] })", None),
+        ("(class { ['prototype'] })", None),
+        ("class Foo { ['x']() {} }", Some(json!([{ "enforceForClassMembers": true }]))),
+    ];
+
+    let fix = vec![
+        ("({ ['0']: 0 })", "({ '0': 0 })", None),
+        ("var { ['0']: a } = obj", "var { '0': a } = obj", None),
+        ("({ ['0+1,234']: 0 })", "({ '0+1,234': 0 })", None),
+        ("({ [0]: 0 })", "({ 0: 0 })", None),
+        ("({ ['x']: 0 })", "({ 'x': 0 })", None),
+        ("({ ['x']() {} })", "({ 'x'() {} })", None),
+        ("({ *['x']() {} })", "({ *'x'() {} })", None),
+        ("({ async ['x']() {} })", "({ async 'x'() {} })", None),
+        ("({ get[.2]() {} })", "({ get.2() {} })", None),
+        ("({ get [2]() {} })", "({ get 2() {} })", None),
+        ("({ get[2]() {} })", "({ get 2() {} })", None),
+        ("({ async[2]() {} })", "({ async 2() {} })", None),
+        ("({ get['foo']() {} })", "({ get'foo'() {} })", None),
+        ("({ async*[2]() {} })", "({ async*2() {} })", None),
+        ("({ [ 'x' ]: 0 })", "({ 'x': 0 })", None),
+        ("({ [('x')]: 0 })", "({ 'x': 0 })", None),
+        ("class Foo { ['x']() {} }", "class Foo { 'x'() {} }", None),
+        ("class Foo { static[0] }", "class Foo { static 0 }", None),
+        ("class Foo { ['0'] = 0 }", "class Foo { '0' = 0 }", None),
+        (
+            "class Foo { static ['constructor']() {} }",
+            "class Foo { static 'constructor'() {} }",
+            None,
+        ),
+        // not fixed
+        (
+            "({ [/* this comment prevents a fix */ 'x']: 0 })",
+            "({ [/* this comment prevents a fix */ 'x']: 0 })",
+            None,
+        ),
+    ];
+
+    Tester::new(NoUselessComputedKey::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
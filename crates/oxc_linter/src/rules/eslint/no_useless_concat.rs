@@ -31,6 +31,9 @@ declare_oxc_lint!(
     /// ```javascript
     /// var foo = "a" + "b";
     /// ```
+    ///
+    /// Concatenations spanning multiple lines are allowed. When both literals use
+    /// the same quotes, the fix merges them into one literal.
     NoUselessConcat,
     suspicious
 );
@@ -57,11 +60,33 @@ impl Rule for NoUselessConcat {
                 return;
             }
             let span = Span::new(left_span.start, right_span.end);
-            ctx.diagnostic(no_useless_concat_diagnostic(span));
+            match merge_literals(left, right, ctx) {
+                Some(merged) => ctx
+                    .diagnostic_with_fix(no_useless_concat_diagnostic(span), |fixer| {
+                        fixer.replace(span, merged)
+                    }),
+                None => ctx.diagnostic(no_useless_concat_diagnostic(span)),
+            }
         }
     }
 }
 
+/// Merges two adjacent literals into one, if they use the same quotes and
+/// nothing but the `+` operator (such as a comment) is between them.
+fn merge_literals(left: &Expression, right: &Expression, ctx: &LintContext) -> Option<String> {
+    let between = ctx.source_range(Span::new(left.span().end, right.span().start));
+    if between.trim() != "+" {
+        return None;
+    }
+    let left = ctx.source_range(left.span());
+    let right = ctx.source_range(right.span());
+    let quote = left.chars().next()?;
+    if right.chars().next()? != quote || !matches!(quote, '"' | '\'' | '`') {
+        return None;
+    }
+    Some(format!("{}{}", &left[..left.len() - 1], &right[1..]))
+}
+
 fn get_left<'a>(expr: &'a BinaryExpression<'a>) -> &'a Expression<'a> {
     let mut left = &expr.left;
     loop {
@@ -133,5 +158,18 @@ fn test() {
         "'a' + 'b' + 'c' + 'd' + 'e' + foo",
     ];
 
-    Tester::new(NoUselessConcat::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("'a' + 'b'", "'ab'"),
+        ("\"a\" + \"b\"", "\"ab\""),
+        ("foo + 'a' + 'b'", "foo + 'ab'"),
+        ("`a` + `b`", "`ab`"),
+        ("`a${foo}` + `b`", "`a${foo}b`"),
+        ("'\\'' + '\"'", "'\\'\"'"),
+        // different quotes or comments are not fixed
+        ("'a' + \"b\"", "'a' + \"b\""),
+        ("`a` + 'b'", "`a` + 'b'"),
+        ("'a' /* foo */ + 'b'", "'a' /* foo */ + 'b'"),
+    ];
+
+    Tester::new(NoUselessConcat::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -1,5 +1,8 @@
 use oxc_ast::{
-    ast::{AssignmentTarget, AssignmentTargetProperty, BindingPatternKind},
+    ast::{
+        AssignmentTarget, AssignmentTargetMaybeDefault, AssignmentTargetProperty,
+        BindingPatternKind,
+    },
     AstKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
@@ -56,6 +59,11 @@ declare_oxc_lint!(
     /// const { bar: renamed } = obj;
     /// export { baz };
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// `ignoreDestructuring`, `ignoreImport` and `ignoreExport` (all default `false`)
+    /// allow renames in destructuring, imports and exports respectively.
     NoUselessRename,
     correctness
 );
@@ -111,7 +119,7 @@ impl Rule for NoUselessRename {
                     };
 
                     if key == renamed_key {
-                        ctx.diagnostic(no_useless_rename_diagnostic(property.span));
+                        report(property.span, property.span, property.value.span(), ctx);
                     }
                 }
             }
@@ -135,8 +143,12 @@ impl Rule for NoUselessRename {
                         continue;
                     };
 
-                    if key == renamed_key {
-                        ctx.diagnostic(no_useless_rename_diagnostic(property.span));
+                    if key != renamed_key {
+                        continue;
+                    }
+                    match get_target_replacement(&property.binding, ctx) {
+                        Some(replacement) => report(property.span, property.span, replacement, ctx),
+                        None => ctx.diagnostic(no_useless_rename_diagnostic(property.span)),
                     }
                 }
             }
@@ -145,7 +157,12 @@ impl Rule for NoUselessRename {
                     && import_specifier.imported.span() != import_specifier.local.span
                     && import_specifier.local.name == import_specifier.imported.name()
                 {
-                    ctx.diagnostic(no_useless_rename_diagnostic(import_specifier.local.span));
+                    // Start at the imported name to keep an inline `type` modifier
+                    let span = Span::new(
+                        import_specifier.imported.span().start,
+                        import_specifier.span.end,
+                    );
+                    report(import_specifier.local.span, span, import_specifier.local.span, ctx);
                 }
             }
             AstKind::ExportNamedDeclaration(export_named_decl) => {
@@ -156,7 +173,8 @@ impl Rule for NoUselessRename {
                     if specifier.local.span() != specifier.exported.span()
                         && specifier.local.name() == specifier.exported.name()
                     {
-                        ctx.diagnostic(no_useless_rename_diagnostic(specifier.local.span()));
+                        let local = specifier.local.span();
+                        report(local, Span::new(local.start, specifier.span.end), local, ctx);
                     }
                 }
             }
@@ -165,6 +183,40 @@ impl Rule for NoUselessRename {
     }
 }
 
+/// Reports a rename labeled at `label`, fixing `span` to the text of `replacement`.
+fn report(label: Span, span: Span, replacement: Span, ctx: &LintContext) {
+    let diagnostic = no_useless_rename_diagnostic(label);
+    // Comments between the key and the local name would be lost
+    let trivias = ctx.semantic().trivias();
+    if trivias.comments_range(span.start..span.end).count()
+        > trivias.comments_range(replacement.start..replacement.end).count()
+    {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+    let replacement = ctx.source_range(replacement).to_string();
+    ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(span, replacement));
+}
+
+/// Returns the span of the local name of a destructuring assignment property, along
+/// with its default value. Parentheses around the name can't be kept in a shorthand
+/// property, so there is no replacement for `({ foo: (foo) = a } = obj)`.
+fn get_target_replacement(
+    binding: &AssignmentTargetMaybeDefault,
+    ctx: &LintContext,
+) -> Option<Span> {
+    match binding {
+        AssignmentTargetMaybeDefault::AssignmentTargetWithDefault(target) => {
+            let left = target.binding.span();
+            if ctx.source_text()[..left.start as usize].trim_end().ends_with('(') {
+                return None;
+            }
+            Some(Span::new(left.start, target.init.span().end))
+        }
+        _ => Some(binding.as_assignment_target()?.span()),
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -394,5 +446,44 @@ fn test() {
         ),
     ];
 
-    Tester::new(NoUselessRename::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r"let {foo: foo} = obj;", r"let {foo} = obj;"),
+        (r"({foo: (foo)} = obj);", r"({foo} = obj);"),
+        (r"let {\u0061: a} = obj;", r"let {a} = obj;"),
+        (r"let {a: \u0061} = obj;", r"let {\u0061} = obj;"),
+        (r"let {a, foo: foo} = obj;", r"let {a, foo} = obj;"),
+        (r"let {foo: bar, baz: baz} = obj;", r"let {foo: bar, baz} = obj;"),
+        (r"let {foo: foo, bar: bar} = obj;", r"let {foo, bar} = obj;"),
+        (r"let {foo: {bar: bar}} = obj;", r"let {foo: {bar}} = obj;"),
+        (r"let {'foo': foo} = obj;", r"let {foo} = obj;"),
+        (
+            r"let {foo: foo = 1, 'bar': bar = 1, baz: baz} = obj;",
+            r"let {foo = 1, bar = 1, baz} = obj;",
+        ),
+        (r"let {foo: foo = (a, b)} = obj;", r"let {foo = (a, b)} = obj;"),
+        (r"function func({foo: foo}) {}", r"function func({foo}) {}"),
+        (r"({foo: foo}) => {}", r"({foo}) => {}"),
+        (r"const {foo: foo, ...stuff} = myObject;", r"const {foo, ...stuff} = myObject;"),
+        (r"({foo: foo = 1} = obj);", r"({foo = 1} = obj);"),
+        (r"import {foo as foo} from 'foo';", r"import {foo} from 'foo';"),
+        (r"import {'foo' as foo} from 'foo';", r"import {foo} from 'foo';"),
+        (r"import {foo as bar, baz as baz} from 'foo';", r"import {foo as bar, baz} from 'foo';"),
+        (r"var foo = 0; export {foo as foo};", r"var foo = 0; export {foo};"),
+        (r"var foo = 0; export {foo as 'foo'};", r"var foo = 0; export {foo};"),
+        (r"export {'foo' as 'foo'} from 'bar';", r"export {'foo'} from 'bar';"),
+        (r"export {foo as foo, bar as bar} from 'foo';", r"export {foo, bar} from 'foo';"),
+        (r"import { type foo as foo } from 'foo';", r"import { type foo } from 'foo';"),
+        (r"export { type bar as bar } from 'bar';", r"export { type bar } from 'bar';"),
+        (r"({foo: foo = /* comment */ 1} = {});", r"({foo = /* comment */ 1} = {});"),
+        (r"({/* comment */foo: foo} = {});", r"({/* comment */foo} = {});"),
+        (r"let foo; export {foo as foo/* comment*/};", r"let foo; export {foo/* comment*/};"),
+        // not fixed
+        (r"({foo: (foo) = a} = obj);", r"({foo: (foo) = a} = obj);"),
+        (r"({foo/**/ : foo} = {});", r"({foo/**/ : foo} = {});"),
+        (r"({foo: (/**/foo)} = {});", r"({foo: (/**/foo)} = {});"),
+        (r"import {foo as/**/foo} from 'foo';", r"import {foo as/**/foo} from 'foo';"),
+        (r"let foo; export {foo/**/as foo};", r"let foo; export {foo/**/as foo};"),
+    ];
+
+    Tester::new(NoUselessRename::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
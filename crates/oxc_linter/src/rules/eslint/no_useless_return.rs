@@ -0,0 +1,375 @@
+use oxc_ast::{ast::Statement, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_useless_return_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unnecessary return statement.")
+        .with_help("Remove this return statement.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessReturn;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow redundant return statements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A `return;` statement with nothing after it is redundant, and has no effect on
+    /// the runtime behavior of a function. This can be confusing, so it's better to
+    /// disallow these redundant statements.
+    ///
+    /// Returns inside loops and `finally` blocks are allowed, as are returns which
+    /// skip the code after the statement containing them.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function foo() { return; }
+    ///
+    /// function bar() {
+    ///     doSomething();
+    ///     return;
+    /// }
+    ///
+    /// function baz() {
+    ///     if (condition) {
+    ///         qux();
+    ///         return;
+    ///     } else {
+    ///         quux();
+    ///     }
+    /// }
+    /// ```
+    NoUselessReturn,
+    pedantic
+);
+
+impl Rule for NoUselessReturn {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ReturnStatement(return_stmt) = node.kind() else {
+            return;
+        };
+        if return_stmt.argument.is_some() {
+            return;
+        }
+        let Some(parent) = ctx.nodes().parent_node(node.id()) else {
+            return;
+        };
+        let statements = match parent.kind() {
+            AstKind::BlockStatement(block) => Some(&block.body),
+            AstKind::FunctionBody(body) => Some(&body.statements),
+            AstKind::SwitchCase(case) => Some(&case.consequent),
+            AstKind::IfStatement(_) | AstKind::LabeledStatement(_) => None,
+            _ => return,
+        };
+        if statements.is_some_and(|statements| is_unreachable(statements, return_stmt.span)) {
+            return;
+        }
+        if !is_last_in_function(node, ctx) {
+            return;
+        }
+
+        let diagnostic = no_useless_return_diagnostic(return_stmt.span);
+        // Only a statement in a statement list can be removed, unlike e.g. `if (foo) return;`
+        if statements.is_some() && !ctx.semantic().trivias().has_comments_between(return_stmt.span)
+        {
+            ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.delete_range(return_stmt.span));
+        } else {
+            ctx.diagnostic(diagnostic);
+        }
+    }
+}
+
+/// Checks if the statement at `span` directly follows a statement which exits.
+fn is_unreachable(statements: &[Statement], span: Span) -> bool {
+    statements.windows(2).any(|pair| {
+        pair[1].span() == span
+            && matches!(
+                pair[0],
+                Statement::ReturnStatement(_)
+                    | Statement::ThrowStatement(_)
+                    | Statement::BreakStatement(_)
+                    | Statement::ContinueStatement(_)
+            )
+    })
+}
+
+/// Checks if the statement at `span` is only followed by other empty returns.
+fn is_last(statements: &[Statement], span: Span) -> bool {
+    let Some(index) = statements.iter().position(|stmt| stmt.span() == span) else {
+        return false;
+    };
+    statements[index + 1..].iter().all(
+        |stmt| matches!(stmt, Statement::ReturnStatement(return_stmt) if return_stmt.argument.is_none()),
+    )
+}
+
+/// Checks if the function would end right after the node, without any other code
+/// running in between.
+fn is_last_in_function(node: &AstNode, ctx: &LintContext) -> bool {
+    let mut current = node;
+    while let Some(parent) = ctx.nodes().parent_node(current.id()) {
+        let span = current.kind().span();
+        let is_last = match parent.kind() {
+            AstKind::FunctionBody(body) => return is_last(&body.statements, span),
+            AstKind::BlockStatement(block) => is_last(&block.body, span),
+            // Removing the return would fall through to the next case
+            AstKind::SwitchCase(case) => {
+                is_last(&case.consequent, span)
+                    && matches!(
+                        ctx.nodes().parent_kind(parent.id()),
+                        Some(AstKind::SwitchStatement(switch))
+                            if switch.cases.last().is_some_and(|last| last.span == case.span)
+                    )
+            }
+            // The `finally` block may override the completion of a `try` or `catch` block
+            AstKind::TryStatement(try_stmt) => {
+                !try_stmt.finalizer.as_ref().is_some_and(|finalizer| finalizer.span == span)
+            }
+            AstKind::IfStatement(_)
+            | AstKind::LabeledStatement(_)
+            | AstKind::SwitchStatement(_)
+            | AstKind::CatchClause(_) => true,
+            _ => false,
+        };
+        if !is_last {
+            return false;
+        }
+        current = parent;
+    }
+    false
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function foo() { return 5; }",
+        "function foo() { return null; }",
+        "function foo() { return doSomething(); }",
+        "
+          function foo() {
+            if (bar) {
+              doSomething();
+              return;
+            } else {
+              doSomethingElse();
+            }
+            qux();
+          }
+        ",
+        "
+          function foo() {
+            switch (bar) {
+              case 1:
+                doSomething();
+                return;
+              default:
+                doSomethingElse();
+            }
+          }
+        ",
+        "
+          function foo() {
+            switch (bar) {
+              default:
+                doSomething();
+                return;
+              case 1:
+                doSomething();
+            }
+          }
+        ",
+        "
+          function foo() {
+            switch (bar) {
+              case 1:
+                if (a) {
+                  doSomething();
+                  return;
+                } else {
+                  doSomething();
+                  return;
+                }
+              default:
+                doSomethingElse();
+            }
+          }
+        ",
+        "
+          function foo() {
+            for (const foo of bar) {
+              return;
+            }
+          }
+        ",
+        "
+          function foo() {
+            try {
+              return 5;
+            } finally {
+              return; // This is allowed because it can override the returned value of 5
+            }
+          }
+        ",
+        "
+          function foo() {
+            try {
+              bar();
+              return;
+            } catch (err) {}
+            baz();
+          }
+        ",
+        "
+          function foo() {
+            if (something) {
+              try {
+                bar();
+                return;
+              } catch (err) {}
+            }
+            baz();
+          }
+        ",
+        "
+          function foo() {
+            return;
+            doSomething();
+          }
+        ",
+        "function foo() { throw err; return; }",
+        "
+          function foo() {
+            while (foo) {
+              if (bar) {
+                return;
+              }
+            }
+          }
+        ",
+        "
+          function foo() {
+            do {
+              doSomething();
+              return;
+            } while (foo);
+          }
+        ",
+        "
+          function foo() {
+            label: {
+              if (bar) {
+                break label;
+              }
+              return;
+            }
+            baz();
+          }
+        ",
+        "() => { if (foo) return; bar(); }",
+        "foo(); return;",
+    ];
+
+    let fail = vec![
+        "function foo() { return; }",
+        "function foo() { doSomething(); return; }",
+        "function foo() { if (condition) { bar(); return; } else { baz(); } }",
+        "function foo() { if (foo) return; }",
+        "function foo() { bar(); return/**/; }",
+        "function foo() { bar(); return//\n; }",
+        "foo(); function foo() { return; }",
+        "function foo() { if (foo) { return; } else { return; } }",
+        "function foo() { if (foo) { return; } return; }",
+        "function foo() { if (foo) { bar(); return; } else if (baz) { qux(); } }",
+        "
+          function foo() {
+            switch (bar) {
+              case 1:
+                doSomething();
+              default:
+                doSomethingElse();
+                return;
+            }
+          }
+        ",
+        "
+          function foo() {
+            switch (bar) {
+              default:
+                doSomething();
+              case 1:
+                doSomething();
+                return;
+            }
+          }
+        ",
+        "
+          function foo() {
+            try {
+              bar();
+              return;
+            } catch (err) {}
+          }
+        ",
+        "
+          function foo() {
+            try {
+              bar();
+            } catch (err) {
+              return;
+            }
+          }
+        ",
+        "
+          function foo() {
+            try {
+              bar();
+              return;
+            } finally {
+              baz();
+            }
+          }
+        ",
+        "
+          function foo() {
+            label: {
+              bar();
+              return;
+            }
+          }
+        ",
+        "var foo = () => { return; };",
+        "var foo = function() { return; };",
+        "var foo = function() { if (foo) { bar(); return; } };",
+        "class Foo { bar() { return; } }",
+        "class Foo { static { function foo() { return; } } }",
+    ];
+
+    let fix = vec![
+        ("function foo() { return; }", "function foo() {  }"),
+        ("function foo() { doSomething(); return; }", "function foo() { doSomething();  }"),
+        (
+            "function foo() { if (condition) { bar(); return; } else { baz(); } }",
+            "function foo() { if (condition) { bar();  } else { baz(); } }",
+        ),
+        ("var foo = () => { return; };", "var foo = () => {  };"),
+        (
+            "function foo() { try { bar(); return; } catch (err) {} }",
+            "function foo() { try { bar();  } catch (err) {} }",
+        ),
+        ("function foo() { if (foo) { return; } return; }", "function foo() { if (foo) {  }  }"),
+        // not fixed
+        ("function foo() { if (foo) return; }", "function foo() { if (foo) return; }"),
+        ("function foo() { bar(); return/**/; }", "function foo() { bar(); return/**/; }"),
+    ];
+
+    Tester::new(NoUselessReturn::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
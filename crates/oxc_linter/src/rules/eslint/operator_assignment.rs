@@ -0,0 +1,382 @@
+use oxc_ast::{
+    ast::{
+        AssignmentExpression, AssignmentTarget, Expression, MemberExpression,
+        SimpleAssignmentTarget,
+    },
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{
+    operator::{AssignmentOperator, BinaryOperator},
+    precedence::GetPrecedence,
+};
+
+use crate::{
+    ast_util::get_precedence, context::LintContext, rule::Rule, utils::is_same_member_expression,
+    AstNode,
+};
+
+fn replaced_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Assignment (=) can be replaced with operator assignment ({x0})."))
+        .with_help(format!("Use the `{x0}` operator."))
+        .with_label(span1)
+}
+
+fn unexpected_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Unexpected operator assignment ({x0}) statement."))
+        .with_help("Use a plain assignment instead.")
+        .with_label(span1)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct OperatorAssignment {
+    never: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require or disallow assignment operator shorthand where possible.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JavaScript provides shorthand operators that combine variable assignment and
+    /// some simple mathematical operations, e.g. `x += 4` for `x = x + 4`. Using them,
+    /// or not, consistently makes the code easier to read.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // with the default "always" option
+    /// x = x + y;
+    /// x = y * x;
+    /// x[0] = x[0] / y;
+    ///
+    /// // with the "never" option
+    /// x *= y;
+    /// x ^= (y + z) / foo();
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `"always"` (default): require operator assignment shorthand where possible.
+    /// - `"never"`: disallow operator assignment shorthand.
+    ///
+    /// Expressions are only fixed when evaluating their target twice has no side
+    /// effects, e.g. `foo.bar` or `foo[0]`, but not `foo[bar()]` or `foo().bar`.
+    OperatorAssignment,
+    style
+);
+
+impl Rule for OperatorAssignment {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self { never: value.get(0).and_then(serde_json::Value::as_str) == Some("never") }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::AssignmentExpression(assign) = node.kind() else {
+            return;
+        };
+
+        if self.never {
+            let Some(operator) = assign.operator.to_binary_operator() else {
+                return;
+            };
+            let diagnostic = unexpected_diagnostic(assign.operator.as_str(), assign.span);
+            match expand_operator_assignment(assign, operator, ctx) {
+                Some(fixed) => {
+                    ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(assign.span, fixed));
+                }
+                None => ctx.diagnostic(diagnostic),
+            }
+            return;
+        }
+
+        if assign.operator != AssignmentOperator::Assign {
+            return;
+        }
+        let Expression::BinaryExpression(binary) = assign.right.without_parenthesized() else {
+            return;
+        };
+        let Some(operator) = binary.operator.to_assignment_operator() else {
+            return;
+        };
+
+        let binary_left = binary.left.without_parenthesized();
+        if is_same_reference(&assign.left, binary_left, ctx) {
+            let diagnostic = replaced_diagnostic(operator.as_str(), assign.span);
+            if !can_be_fixed_target(&assign.left) || !can_be_fixed(binary_left) {
+                ctx.diagnostic(diagnostic);
+                return;
+            }
+            // Comments between the operators would be removed
+            let between = Span::new(assign.left.span().end, binary.right.span().start);
+            if ctx.semantic().trivias().has_comments_between(between) {
+                ctx.diagnostic(diagnostic);
+                return;
+            }
+            let Some(equals) = find_operator(assign.left.span().end, "=", ctx) else {
+                return;
+            };
+            let Some(binary_operator) =
+                find_operator(binary.left.span().end, binary.operator.as_str(), ctx)
+            else {
+                return;
+            };
+            ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                let left = ctx.source_range(Span::new(assign.span.start, equals.start));
+                let right = ctx.source_range(Span::new(binary_operator.end, binary.span.end));
+                fixer.replace(assign.span, format!("{left}{}{right}", operator.as_str()))
+            });
+        } else if is_commutative(binary.operator)
+            && is_same_reference(&assign.left, binary.right.without_parenthesized(), ctx)
+        {
+            ctx.diagnostic(replaced_diagnostic(operator.as_str(), assign.span));
+        }
+    }
+}
+
+/// Rewrites `x += y` as `x = x + y`.
+fn expand_operator_assignment(
+    assign: &AssignmentExpression,
+    operator: BinaryOperator,
+    ctx: &LintContext,
+) -> Option<String> {
+    if !can_be_fixed_target(&assign.left) {
+        return None;
+    }
+    // Comments in the target would be duplicated
+    if ctx
+        .semantic()
+        .trivias()
+        .has_comments_between(Span::new(assign.span.start, assign.right.span().start))
+    {
+        return None;
+    }
+    let operator_span = find_operator(assign.left.span().end, assign.operator.as_str(), ctx)?;
+    let left = ctx.source_range(Span::new(assign.span.start, operator_span.start));
+    let target = ctx.source_range(assign.left.span());
+    let right_span = assign.right.span();
+
+    let right = if get_precedence(&assign.right) <= operator.precedence() {
+        format!(
+            "{}({})",
+            ctx.source_range(Span::new(operator_span.end, right_span.start)),
+            ctx.source_range(right_span)
+        )
+    } else {
+        let right = ctx.source_range(Span::new(operator_span.end, assign.span.end));
+        // `x+=+y` should become `x= x + +y` rather than `x= x ++y`
+        let operator_char = operator.as_str().chars().last();
+        if matches!(operator_char, Some('+' | '-' | '/')) && right.chars().next() == operator_char {
+            format!(" {right}")
+        } else {
+            right.to_string()
+        }
+    };
+    Some(format!("{left}= {target} {}{right}", operator.as_str()))
+}
+
+/// Returns the span of the first `operator` after `start`.
+fn find_operator(start: u32, operator: &str, ctx: &LintContext) -> Option<Span> {
+    let offset = u32::try_from(ctx.source_text()[start as usize..].find(operator)?).ok()?;
+    let len = u32::try_from(operator.len()).ok()?;
+    Some(Span::new(start + offset, start + offset + len))
+}
+
+fn is_commutative(operator: BinaryOperator) -> bool {
+    matches!(
+        operator,
+        BinaryOperator::Multiplication
+            | BinaryOperator::BitwiseAnd
+            | BinaryOperator::BitwiseXOR
+            | BinaryOperator::BitwiseOR
+    )
+}
+
+fn is_same_reference(target: &AssignmentTarget, expr: &Expression, ctx: &LintContext) -> bool {
+    match target.as_simple_assignment_target() {
+        Some(SimpleAssignmentTarget::AssignmentTargetIdentifier(ident)) => {
+            matches!(expr, Expression::Identifier(right) if right.name == ident.name)
+        }
+        Some(target) => match (target.as_member_expression(), expr.as_member_expression()) {
+            (Some(left), Some(right)) => is_same_member_expression(left, right, ctx),
+            _ => false,
+        },
+        None => false,
+    }
+}
+
+fn can_be_fixed_target(target: &AssignmentTarget) -> bool {
+    match target.as_simple_assignment_target() {
+        Some(SimpleAssignmentTarget::AssignmentTargetIdentifier(_)) => true,
+        Some(target) => target.as_member_expression().is_some_and(can_be_fixed_member),
+        None => false,
+    }
+}
+
+/// Checks if the expression can be evaluated twice without side effects.
+fn can_be_fixed(expr: &Expression) -> bool {
+    match expr {
+        Expression::Identifier(_) => true,
+        _ => expr.as_member_expression().is_some_and(can_be_fixed_member),
+    }
+}
+
+fn can_be_fixed_member(member: &MemberExpression) -> bool {
+    if !matches!(member.object(), Expression::Identifier(_) | Expression::ThisExpression(_)) {
+        return false;
+    }
+    match member {
+        MemberExpression::StaticMemberExpression(_) => true,
+        MemberExpression::ComputedMemberExpression(computed) => matches!(
+            computed.expression,
+            Expression::StringLiteral(_)
+                | Expression::NumericLiteral(_)
+                | Expression::BooleanLiteral(_)
+                | Expression::NullLiteral(_)
+        ),
+        MemberExpression::PrivateFieldExpression(_) => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("x = y", None),
+        ("x = y + x", None),
+        ("x += x + y", None),
+        ("x = (x + y) - z", None),
+        ("x -= y", None),
+        ("x = y - x", None),
+        ("x *= x", None),
+        ("x = y * z", None),
+        ("x = (x * y) * z", None),
+        ("x = y / x", None),
+        ("x /= y", None),
+        ("x %= y", None),
+        ("x <<= y", None),
+        ("x >>= x >> y", None),
+        ("x >>>= y", None),
+        ("x &= y", None),
+        ("x **= y", None),
+        ("x ^= y ^ z", None),
+        ("x |= x | y", None),
+        ("x = x && y", None),
+        ("x = x || y", None),
+        ("x = x < y", None),
+        ("x = x > y", None),
+        ("x = x <= y", None),
+        ("x = x >= y", None),
+        ("x = x instanceof y", None),
+        ("x = x in y", None),
+        ("x = x == y", None),
+        ("x = x != y", None),
+        ("x = x === y", None),
+        ("x = x !== y", None),
+        ("x[y] = x['y'] + z", None),
+        ("x.y = z + x.y", None),
+        ("x[fn()] = x[fn()] + y", None),
+        ("x += x + y", Some(json!(["always"]))),
+        ("x = x + y", Some(json!(["never"]))),
+        ("x = x ** y", Some(json!(["never"]))),
+        ("x = y ** x", None),
+        ("x = x * y + z", None),
+        ("this.x = this.y + z", None),
+        ("this.x = foo.x + y", None),
+        ("this.x = foo.this.x + y", None),
+        ("const foo = 0; class C { foo = foo + 1; }", None),
+        ("x = x && y", Some(json!(["never"]))),
+        ("x &&= y", Some(json!(["never"]))),
+        ("x ||= y", Some(json!(["never"]))),
+        ("x ??= y", Some(json!(["never"]))),
+        ("x = x?.y + 1", None),
+    ];
+
+    let fail = vec![
+        ("x = x + y", None),
+        ("x = x - y", None),
+        ("x = x * y", None),
+        ("x = y * x", None),
+        ("x = (y * z) * x", None),
+        ("x = x / y", None),
+        ("x = x % y", None),
+        ("x = x << y", None),
+        ("x = x >> y", None),
+        ("x = x >>> y", None),
+        ("x = x & y", None),
+        ("x = x ^ y", None),
+        ("x = x | y", None),
+        ("x[0] = x[0] - y", None),
+        ("x.y[z['a']][0].b = x.y[z['a']][0].b * 2", None),
+        ("x = x + y", Some(json!(["always"]))),
+        ("x = (x + y)", Some(json!(["always"]))),
+        ("x = x + (y)", Some(json!(["always"]))),
+        ("x += (y)", Some(json!(["never"]))),
+        ("x += y", Some(json!(["never"]))),
+        ("foo.bar = foo.bar + baz", None),
+        ("foo.bar += baz", Some(json!(["never"]))),
+        ("this.foo = this.foo + bar", None),
+        ("this.foo += bar", Some(json!(["never"]))),
+        ("foo.bar.baz = foo.bar.baz + qux", None),
+        ("foo.bar.baz += qux", Some(json!(["never"]))),
+        ("this.foo.bar = this.foo.bar + baz", None),
+        ("this.foo.bar += baz", Some(json!(["never"]))),
+        ("foo[bar] = foo[bar] + baz", None),
+        ("this[foo] = this[foo] + bar", None),
+        ("foo[bar] >>>= baz", Some(json!(["never"]))),
+        ("this[foo] >>>= bar", Some(json!(["never"]))),
+        ("foo[5] = foo[5] / baz", None),
+        ("this[5] = this[5] / foo", None),
+        ("x[fn()] += y", Some(json!(["never"]))),
+        ("x = x ** y", None),
+        ("x **= y", Some(json!(["never"]))),
+    ];
+
+    let fix = vec![
+        ("x = x + y", "x += y", None),
+        ("x = x - y", "x -= y", None),
+        ("x = x * y", "x *= y", None),
+        ("x = y * x", "x = y * x", None),
+        ("x = x / y", "x /= y", None),
+        ("x = x % y", "x %= y", None),
+        ("x = x << y", "x <<= y", None),
+        ("x = x >> y", "x >>= y", None),
+        ("x = x >>> y", "x >>>= y", None),
+        ("x = x & y", "x &= y", None),
+        ("x = x ^ y", "x ^= y", None),
+        ("x = x | y", "x |= y", None),
+        ("x = x ** y", "x **= y", None),
+        ("x[0] = x[0] - y", "x[0] -= y", None),
+        ("x = (x + y)", "x += y", None),
+        ("x = x + (y)", "x += (y)", None),
+        ("foo.bar = foo.bar + baz", "foo.bar += baz", None),
+        ("this.foo = this.foo + bar", "this.foo += bar", None),
+        ("foo[5] = foo[5] / baz", "foo[5] /= baz", None),
+        // Evaluating the target twice could have side effects
+        ("foo.bar.baz = foo.bar.baz + qux", "foo.bar.baz = foo.bar.baz + qux", None),
+        ("foo[bar] = foo[bar] + baz", "foo[bar] = foo[bar] + baz", None),
+        ("x = /* comment */ x + y", "x = /* comment */ x + y", None),
+        ("x += y", "x = x + y", Some(json!(["never"]))),
+        ("x += (y)", "x = x + (y)", Some(json!(["never"]))),
+        ("foo.bar += baz", "foo.bar = foo.bar + baz", Some(json!(["never"]))),
+        ("this.foo += bar", "this.foo = this.foo + bar", Some(json!(["never"]))),
+        ("foo[5] >>>= baz", "foo[5] = foo[5] >>> baz", Some(json!(["never"]))),
+        ("x -= y - z", "x = x - (y - z)", Some(json!(["never"]))),
+        ("x *= y + z", "x = x * (y + z)", Some(json!(["never"]))),
+        ("x **= y ** z", "x = x ** (y ** z)", Some(json!(["never"]))),
+        ("x += y * z", "x = x + y * z", Some(json!(["never"]))),
+        ("x+=+y", "x= x + +y", Some(json!(["never"]))),
+        ("x-=-y", "x= x - -y", Some(json!(["never"]))),
+        ("x+=y", "x= x +y", Some(json!(["never"]))),
+        ("foo.bar.baz += qux", "foo.bar.baz += qux", Some(json!(["never"]))),
+        ("x[fn()] += y", "x[fn()] += y", Some(json!(["never"]))),
+        ("x /* comment */ += y", "x /* comment */ += y", Some(json!(["never"]))),
+    ];
+
+    Tester::new(OperatorAssignment::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
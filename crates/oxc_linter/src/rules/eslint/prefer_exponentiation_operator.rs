@@ -1,10 +1,17 @@
-use oxc_ast::{ast::Expression, match_member_expression, AstKind};
+use oxc_ast::{
+    ast::{CallExpression, Expression},
+    match_member_expression, AstKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::{operator::BinaryOperator, precedence::Precedence};
 
 use crate::{
-    ast_util::is_method_call, context::LintContext, globals::GLOBAL_OBJECT_NAMES, rule::Rule,
+    ast_util::{can_be_adjacent, get_precedence, is_method_call},
+    context::LintContext,
+    globals::GLOBAL_OBJECT_NAMES,
+    rule::Rule,
     AstNode,
 };
 
@@ -30,6 +37,9 @@ declare_oxc_lint!(
     /// ```javascript
     /// Math.pow(a, b)
     /// ```
+    ///
+    /// The fix rewrites the call as `a**b`, wrapping the operands in parentheses
+    /// where needed, e.g. `Math.pow(a + 1, b)` becomes `(a + 1)**b`.
     PreferExponentiationOperator,
     style,
 );
@@ -48,36 +58,101 @@ impl Rule for PreferExponentiationOperator {
             return;
         };
 
-        let member_expor_obj = member_expr.object();
-
-        match member_expor_obj {
+        let is_global_math = match member_expr.object() {
             Expression::Identifier(ident) => {
-                if ident.name.as_str() == "Math"
+                ident.name.as_str() == "Math"
                     && ctx.semantic().is_reference_to_global_variable(ident)
-                {
-                    ctx.diagnostic(prefer_exponentian_operator_diagnostic(call_expr.span));
-                }
             }
-            match_member_expression!(Expression) => {
-                let member_expr = member_expor_obj.to_member_expression();
-                let Some(static_prop_name) = member_expr.static_property_name() else {
+            obj @ match_member_expression!(Expression) => {
+                let member_expr = obj.to_member_expression();
+                let Expression::Identifier(ident) = member_expr.object().without_parenthesized()
+                else {
                     return;
                 };
-                if static_prop_name != "Math" {
-                    return;
-                }
-
-                if let Expression::Identifier(ident) = member_expr.object().without_parenthesized()
-                {
-                    if GLOBAL_OBJECT_NAMES.contains(ident.name.as_str())
-                        && ctx.semantic().is_reference_to_global_variable(ident)
-                    {
-                        ctx.diagnostic(prefer_exponentian_operator_diagnostic(call_expr.span));
-                    }
-                }
+                member_expr.static_property_name() == Some("Math")
+                    && GLOBAL_OBJECT_NAMES.contains(ident.name.as_str())
+                    && ctx.semantic().is_reference_to_global_variable(ident)
             }
-            _ => {}
+            _ => false,
         };
+        if !is_global_math {
+            return;
+        }
+
+        let diagnostic = prefer_exponentian_operator_diagnostic(call_expr.span);
+        match get_fix(call_expr, node, ctx) {
+            Some(fixed) => {
+                ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(call_expr.span, fixed));
+            }
+            None => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+/// Rewrites `Math.pow(a, b)` as `a ** b`, adding parentheses where needed.
+fn get_fix(call_expr: &CallExpression, node: &AstNode, ctx: &LintContext) -> Option<String> {
+    let [base, exponent] = call_expr.arguments.as_slice() else {
+        return None;
+    };
+    let (Some(base), Some(exponent)) = (base.as_expression(), exponent.as_expression()) else {
+        return None;
+    };
+    if ctx.semantic().trivias().has_comments_between(call_expr.span) {
+        return None;
+    }
+
+    // `**` is right-associative, and unary operators can't be used for its base
+    let base_needs_parens = get_precedence(base) <= Precedence::Exponential
+        || matches!(
+            base,
+            Expression::UnaryExpression(_)
+                | Expression::AwaitExpression(_)
+                | Expression::TSTypeAssertion(_)
+        );
+    let exponent_needs_parens = get_precedence(exponent) < Precedence::Exponential;
+    let base = parenthesize(ctx.source_range(base.span()), base_needs_parens);
+    let exponent = parenthesize(ctx.source_range(exponent.span()), exponent_needs_parens);
+    let fixed = format!("{base}**{exponent}");
+
+    if needs_parens(call_expr.span, node, ctx) {
+        return Some(format!("({fixed})"));
+    }
+    // Keep the tokens around the call separate, e.g. `a+Math.pow(+b, c)`
+    let before = ctx.source_text()[..call_expr.span.start as usize].chars().next_back();
+    let after = ctx.source_text()[call_expr.span.end as usize..].chars().next();
+    let prefix =
+        if before.is_some_and(|c| !can_be_adjacent(c, fixed.chars().next())) { " " } else { "" };
+    let suffix = if after.is_some_and(|c| !can_be_adjacent(c, fixed.chars().next_back())) {
+        " "
+    } else {
+        ""
+    };
+    Some(format!("{prefix}{fixed}{suffix}"))
+}
+
+fn parenthesize(text: &str, should_parenthesize: bool) -> String {
+    if should_parenthesize {
+        format!("({text})")
+    } else {
+        text.to_string()
+    }
+}
+
+/// Checks if the exponentiation needs parentheses in place of the call.
+fn needs_parens(span: Span, node: &AstNode, ctx: &LintContext) -> bool {
+    let Some(parent) = ctx.nodes().parent_node(node.id()) else {
+        return false;
+    };
+    match parent.kind() {
+        AstKind::BinaryExpression(binary) => {
+            binary.operator == BinaryOperator::Exponential && binary.left.span() == span
+        }
+        AstKind::MemberExpression(member) => member.object().span() == span,
+        AstKind::CallExpression(call) => call.callee.span() == span,
+        AstKind::NewExpression(new) => new.callee.span() == span,
+        AstKind::TaggedTemplateExpression(tagged) => tagged.tag.span() == span,
+        AstKind::UnaryExpression(_) | AstKind::AwaitExpression(_) | AstKind::Class(_) => true,
+        _ => false,
     }
 }
 
@@ -125,5 +200,33 @@ fn test() {
         "Math.pow(a, b) as any",
     ];
 
-    Tester::new(PreferExponentiationOperator::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("Math.pow(a, b)", "a**b"),
+        ("globalThis.Math.pow(a, b)", "a**b"),
+        ("Math.pow(a + 1, b)", "(a + 1)**b"),
+        ("Math.pow(a, b + 1)", "a**(b + 1)"),
+        ("Math.pow(a ** b, c)", "(a ** b)**c"),
+        ("Math.pow(a, b ** c)", "a**b ** c"),
+        ("Math.pow(-a, b)", "(-a)**b"),
+        ("Math.pow(a, -b)", "a**-b"),
+        ("async () => Math.pow(await a, b)", "async () => (await a)**b"),
+        ("Math.pow(a ? b : c, d)", "(a ? b : c)**d"),
+        ("Math.pow(a, b as any)", "a**(b as any)"),
+        ("Math.pow(a.b, c.d)", "a.b**c.d"),
+        ("Math.pow(a(), b())", "a()**b()"),
+        ("-Math.pow(a, b)", "-(a**b)"),
+        ("Math.pow(a, b).toString()", "(a**b).toString()"),
+        ("Math.pow(a, b)**c", "(a**b)**c"),
+        ("c**Math.pow(a, b)", "c**a**b"),
+        ("Math.pow(a, b) * c", "a**b * c"),
+        ("foo(Math.pow(a, b))", "foo(a**b)"),
+        ("a+Math.pow(++b, c)", "a+ ++b**c"),
+        ("typeof Math.pow(a, b)", "typeof (a**b)"),
+        ("Math.pow(a, b)in c", "a**b in c"),
+        // not fixed
+        ("Math.pow(a, ...b)", "Math.pow(a, ...b)"),
+        ("Math.pow(a, /* comment */ b)", "Math.pow(a, /* comment */ b)"),
+    ];
+
+    Tester::new(PreferExponentiationOperator::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
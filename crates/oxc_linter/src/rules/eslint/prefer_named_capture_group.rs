@@ -0,0 +1,184 @@
+use oxc_ast::{
+    ast::{Argument, Expression, RegExpFlags},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{ast_util::get_regexp_constructor_pattern, context::LintContext, rule::Rule, AstNode};
+
+fn prefer_named_capture_group_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Capture group '{x0}' should be converted to a named or non-capturing group."
+    ))
+    .with_help("Use a named group `(?<name>...)` or a non-capturing group `(?:...)`")
+    .with_label(span1)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferNamedCaptureGroup;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using named capture group in regular expression.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// With ECMAScript 2018, named capture groups can be used in regular expressions,
+    /// which can improve their readability. Numbered groups are harder to follow, and
+    /// their numbers change whenever a group is added or removed before them.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// const foo = /(ba[rz])/;
+    /// const bar = new RegExp('(ba[rz])');
+    /// const baz = RegExp('(ba[rz])');
+    ///
+    /// foo.exec('bar')[1]; // Retrieve the group result.
+    ///
+    /// // Good
+    /// const qux = /(?<id>ba[rz])/;
+    /// const quux = /(?:ba[rz])/;
+    ///
+    /// qux.exec('bar').groups.id; // Retrieve the group result.
+    /// ```
+    PreferNamedCaptureGroup,
+    style
+);
+
+impl Rule for PreferNamedCaptureGroup {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::RegExpLiteral(literal) => {
+                let unicode_sets = literal.regex.flags.contains(RegExpFlags::V);
+                for group in find_unnamed_groups(&literal.regex.pattern, unicode_sets) {
+                    // The pattern starts after the opening `/`
+                    #[allow(clippy::cast_possible_truncation)]
+                    let span = Span::new(
+                        literal.span.start + 1 + *group.start() as u32,
+                        literal.span.start + 1 + *group.end() as u32 + 1,
+                    );
+                    ctx.diagnostic(prefer_named_capture_group_diagnostic(
+                        &literal.regex.pattern[group],
+                        span,
+                    ));
+                }
+            }
+            AstKind::NewExpression(new_expr) => {
+                check_regexp_constructor(&new_expr.callee, &new_expr.arguments, ctx);
+            }
+            AstKind::CallExpression(call) => {
+                check_regexp_constructor(&call.callee, &call.arguments, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Checks `new RegExp("(a)")` and `RegExp("(a)")`.
+fn check_regexp_constructor<'a>(
+    callee: &Expression<'a>,
+    arguments: &'a oxc_allocator::Vec<'a, Argument<'a>>,
+    ctx: &LintContext<'a>,
+) {
+    let Some((pattern, flags)) = get_regexp_constructor_pattern(callee, arguments, ctx) else {
+        return;
+    };
+    let unicode_sets = flags.is_some_and(|flags| flags.contains(RegExpFlags::V));
+    for group in find_unnamed_groups(&pattern.value, unicode_sets) {
+        ctx.diagnostic(prefer_named_capture_group_diagnostic(&pattern.value[group], pattern.span));
+    }
+}
+
+/// Returns the byte ranges of the unnamed capturing groups in a regex pattern,
+/// skipping escaped parentheses and those inside character classes. Character classes
+/// only nest with the `v` flag.
+fn find_unnamed_groups(pattern: &str, unicode_sets: bool) -> Vec<std::ops::RangeInclusive<usize>> {
+    let bytes = pattern.as_bytes();
+    let mut groups = vec![];
+    // Start offsets of the open groups, or `None` for non-capturing groups and lookarounds
+    let mut open: Vec<Option<usize>> = vec![];
+    let mut class_depth = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b'[' if class_depth == 0 || unicode_sets => class_depth += 1,
+            b']' if class_depth > 0 => class_depth -= 1,
+            _ if class_depth > 0 => {}
+            b'(' => {
+                let named_or_non_capturing = bytes.get(i + 1) == Some(&b'?');
+                open.push((!named_or_non_capturing).then_some(i));
+            }
+            b')' => {
+                if let Some(Some(start)) = open.pop() {
+                    groups.push(start..=i);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    groups.sort_by_key(|group| *group.start());
+    groups
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "/normal_regex/",
+        "/(?:[0-9]{4})/",
+        "/(?<year>[0-9]{4})/",
+        r"/\u{1F680}/u",
+        "new RegExp()",
+        "new RegExp(foo)",
+        "new RegExp('')",
+        "new RegExp('(?<year>[0-9]{4})')",
+        "RegExp()",
+        "RegExp(foo)",
+        "RegExp('')",
+        "RegExp('(?<year>[0-9]{4})')",
+        "RegExp('(')",
+        "RegExp('[(]')",
+        "/[(]/",
+        r"/\(foo\)/",
+        r"/[\]()]/",
+        r"/\\\(/",
+        "/(?=foo)(?!bar)(?<=baz)(?<!qux)/",
+        "/(?<a>(?:b)(?<c>d))/",
+        "new Foo('(a)')",
+        "foo.RegExp('(a)')",
+        "new foo.RegExp('(a)')",
+        "function foo(RegExp) { new RegExp('(a)'); }",
+        "var RegExp; RegExp('(a)')",
+        "new RegExp(`a`, '(b)')",
+    ];
+
+    let fail = vec![
+        "/([0-9]{4})/",
+        "new RegExp('([0-9]{4})')",
+        "RegExp('([0-9]{4})')",
+        "/([0-9]{4})-(\\w{5})/",
+        "/([0-9]{4})-(5)/",
+        "/(?<year>[0-9]{4})-(\\w{5})/",
+        "/(?<year>[0-9]{4})-(5)/",
+        "/(?<year>[0-9]{4})-(?<month>[0-9]{2})-(5)/",
+        "/((?<year>[0-9]{4}))/",
+        "/(a(b)c)/",
+        "/[(](a)[)]/",
+        r"/\((a)\)/",
+        r"/[\]](a)/",
+        "/(a)/v",
+        "/[[(]](a)/v",
+        "/[[](a)/",
+        "new RegExp('[[(]](a)', 'v')",
+        "new RegExp('(a)(?:b)(c)', 'g')",
+        "(RegExp)('(a)')",
+    ];
+
+    Tester::new(PreferNamedCaptureGroup::NAME, pass, fail).test_and_snapshot();
+}
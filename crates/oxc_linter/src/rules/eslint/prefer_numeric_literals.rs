@@ -0,0 +1,249 @@
+use oxc_ast::{
+    ast::{Argument, CallExpression, Expression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    ast_util::{can_be_adjacent, is_global_reference},
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+fn prefer_numeric_literals_diagnostic(span0: Span, system: &str, name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Use {system} literals instead of {name}().")).with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferNumericLiterals;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `parseInt()` and `Number.parseInt()` in favor of binary, octal, and
+    /// hexadecimal literals.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The `parseInt()` and `Number.parseInt()` functions can be used to turn binary,
+    /// octal, and hexadecimal strings into integers. As binary, octal, and hexadecimal
+    /// literals are supported since ES6, they are more readable than parsing a string
+    /// at runtime.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// parseInt("111110111", 2) === 503;
+    ///
+    /// parseInt(`767`, 8) === 503;
+    ///
+    /// Number.parseInt("1F7", 16) === 503;
+    /// ```
+    PreferNumericLiterals,
+    style
+);
+
+impl Rule for PreferNumericLiterals {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else {
+            return;
+        };
+        let Some(name) = get_parse_int_name(call_expr, ctx) else {
+            return;
+        };
+        let [str_arg, Argument::NumericLiteral(radix)] = call_expr.arguments.as_slice() else {
+            return;
+        };
+        let digits = match str_arg {
+            Argument::StringLiteral(lit) => lit.value.as_str(),
+            Argument::TemplateLiteral(lit) if lit.expressions.is_empty() => {
+                let Some(cooked) = lit.quasis.first().and_then(|quasi| quasi.value.cooked.as_ref())
+                else {
+                    return;
+                };
+                cooked.as_str()
+            }
+            _ => return,
+        };
+        let (system, prefix, radix) = match radix.value {
+            2.0 => ("binary", "0b", 2),
+            8.0 => ("octal", "0o", 8),
+            16.0 => ("hexadecimal", "0x", 16),
+            _ => return,
+        };
+
+        let diagnostic = prefer_numeric_literals_diagnostic(call_expr.span, system, name);
+        // The literal would differ from the result of `parseInt`, e.g. for `parseInt("1_0", 2)`
+        if digits.is_empty()
+            || !digits.chars().all(|c| c.is_digit(radix))
+            || ctx.semantic().trivias().has_comments_between(call_expr.span)
+        {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+
+        let literal = format!("{prefix}{digits}");
+        // Keep the tokens around the call separate, e.g. `typeof parseInt("1", 2)`
+        let before = ctx.source_text()[..call_expr.span.start as usize].chars().next_back();
+        let after = ctx.source_text()[call_expr.span.end as usize..].chars().next();
+        let prefix = if before.is_some_and(|c| !can_be_adjacent(c, Some('0'))) { " " } else { "" };
+        let suffix = if after.is_some_and(|c| !can_be_adjacent(c, literal.chars().next_back())) {
+            " "
+        } else {
+            ""
+        };
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            fixer.replace(call_expr.span, format!("{prefix}{literal}{suffix}"))
+        });
+    }
+}
+
+/// Returns the name of the callee if it is the global `parseInt` or `Number.parseInt`.
+fn get_parse_int_name(call_expr: &CallExpression, ctx: &LintContext) -> Option<&'static str> {
+    match call_expr.callee.without_parenthesized() {
+        Expression::Identifier(ident)
+            if ident.name == "parseInt" && is_global_reference(ident, ctx) =>
+        {
+            Some("parseInt")
+        }
+        callee => {
+            let member_expr = match callee {
+                Expression::ChainExpression(chain) => chain.expression.as_member_expression()?,
+                _ => callee.as_member_expression()?,
+            };
+            let Expression::Identifier(ident) = member_expr.object().without_parenthesized() else {
+                return None;
+            };
+            (ident.name == "Number"
+                && member_expr.static_property_name() == Some("parseInt")
+                && is_global_reference(ident, ctx))
+            .then_some("Number.parseInt")
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "parseInt(1);",
+        "parseInt(1, 3);",
+        "Number.parseInt(1);",
+        "Number.parseInt(1, 3);",
+        "0b111110111 === 503;",
+        "0o767 === 503;",
+        "0x1F7 === 503;",
+        "a[parseInt](1,2);",
+        "parseInt(foo);",
+        "parseInt(foo, 2);",
+        "Number.parseInt(foo);",
+        "Number.parseInt(foo, 2);",
+        "parseInt(11, 2);",
+        "Number.parseInt(1, 8);",
+        "parseInt(1e5, 16);",
+        "parseInt('11', '2');",
+        "Number.parseInt('11', '8');",
+        "parseInt(/foo/, 2);",
+        "parseInt(`11${foo}`, 2);",
+        "parseInt('11', 10);",
+        "parseInt('11', 2, 3);",
+        "parseInt('11');",
+        "Number.parseInt('11', 4);",
+        "Number['parseInt']('11', 3);",
+        "Number[parseInt]('11', 2);",
+        "Math.parseInt('11', 2);",
+        "foo.parseInt('11', 2);",
+        "
+            function parseInt() {}
+            parseInt('11', 2);
+        ",
+        "
+            const Number = foo;
+            Number.parseInt('11', 2);
+        ",
+        "(function (parseInt) { parseInt('11', 2); })",
+    ];
+
+    let fail = vec![
+        "parseInt(\"111110111\", 2) === 503;",
+        "parseInt(\"767\", 8) === 503;",
+        "parseInt(\"1F7\", 16) === 255;",
+        "Number.parseInt(\"111110111\", 2) === 503;",
+        "Number.parseInt(\"767\", 8) === 503;",
+        "Number.parseInt(\"1F7\", 16) === 255;",
+        "parseInt('7999', 8);",
+        "parseInt('1234', 2);",
+        "parseInt('1234.5', 8);",
+        "parseInt('1️⃣3️⃣3️⃣7️⃣', 16);",
+        "Number.parseInt('7999', 8);",
+        "Number.parseInt('1234', 2);",
+        "Number.parseInt('1234.5', 8);",
+        "Number.parseInt('1️⃣3️⃣3️⃣7️⃣', 16);",
+        "parseInt(`111110111`, 2) === 503;",
+        "parseInt(`767`, 8) === 503;",
+        "parseInt(`1F7`, 16) === 255;",
+        "parseInt('', 8);",
+        "parseInt(``, 8);",
+        "parseInt(`7999`, 8);",
+        "parseInt(`1234`, 2);",
+        "parseInt(`1234.5`, 8);",
+        "parseInt('11', 2)",
+        "Number.parseInt('67', 8)",
+        "5+parseInt('A', 16)",
+        "function *f(){ yield(Number).parseInt('11', 2) }",
+        "function *f(){ yield(Number.parseInt)('67', 8) }",
+        "function *f(){ yield(parseInt)('A', 16) }",
+        "function *f(){ yield Number.parseInt('11', 2) }",
+        "function *f(){ yield/**/Number.parseInt('67', 8) }",
+        "function *f(){ yield(parseInt('A', 16)) }",
+        "parseInt('11', 2)+5",
+        "Number.parseInt('17', 8)+5",
+        "parseInt('A', 16)+5",
+        "parseInt('11', 2)in foo",
+        "Number.parseInt('17', 8)in foo",
+        "parseInt('A', 16)in foo",
+        "parseInt('11', 2) in foo",
+        "Number.parseInt('17', 8)/* comment */in foo",
+        "parseInt('A', 16)in foo",
+        "parseInt('11', 2).foo",
+        "Number.parseInt('17', 8).foo",
+        "parseInt('A', 16).foo",
+        "parseInt('11', 2)?.foo",
+        "Number.parseInt('17', 8)?.foo",
+        "parseInt('A', 16)?.foo",
+        "parseInt('11', 2)",
+        "Number?.parseInt('11', 2)",
+        "(Number?.parseInt)('11', 2)",
+        "parseInt?.('11', 2)",
+        "parseInt(/* comment */'11', 2)",
+        "typeof parseInt('11', 2)",
+    ];
+
+    let fix = vec![
+        ("parseInt(\"111110111\", 2) === 503;", "0b111110111 === 503;"),
+        ("parseInt(\"767\", 8) === 503;", "0o767 === 503;"),
+        ("parseInt(\"1F7\", 16) === 255;", "0x1F7 === 255;"),
+        ("Number.parseInt(\"111110111\", 2) === 503;", "0b111110111 === 503;"),
+        ("parseInt(`767`, 8) === 503;", "0o767 === 503;"),
+        ("5+parseInt('A', 16)", "5+0xA"),
+        ("function *f(){ yield(Number).parseInt('11', 2) }", "function *f(){ yield 0b11 }"),
+        ("function *f(){ yield Number.parseInt('11', 2) }", "function *f(){ yield 0b11 }"),
+        ("function *f(){ yield(parseInt('A', 16)) }", "function *f(){ yield(0xA) }"),
+        ("parseInt('11', 2)+5", "0b11+5"),
+        ("parseInt('A', 16)in foo", "0xA in foo"),
+        ("parseInt('11', 2).foo", "0b11.foo"),
+        ("Number?.parseInt('11', 2)", "0b11"),
+        ("parseInt?.('11', 2)", "0b11"),
+        ("typeof parseInt('11', 2)", "typeof 0b11"),
+        // not fixed
+        ("parseInt('7999', 8);", "parseInt('7999', 8);"),
+        ("parseInt('1234.5', 8);", "parseInt('1234.5', 8);"),
+        ("parseInt('', 8);", "parseInt('', 8);"),
+        ("parseInt(/* comment */'11', 2)", "parseInt(/* comment */'11', 2)"),
+    ];
+
+    Tester::new(PreferNumericLiterals::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
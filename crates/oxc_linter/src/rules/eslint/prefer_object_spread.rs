@@ -0,0 +1,310 @@
+use oxc_ast::{
+    ast::{
+        Argument, CallExpression, Expression, IdentifierReference, ObjectExpression,
+        ObjectPropertyKind, PropertyKind,
+    },
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::precedence::Precedence;
+
+use crate::{
+    ast_util::{get_precedence, get_starting_expression_statement, is_global_reference},
+    context::LintContext,
+    globals::GLOBAL_OBJECT_NAMES,
+    rule::Rule,
+    AstNode,
+};
+
+fn use_spread_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Use an object spread instead of `Object.assign`, e.g. `{ ...foo }`.")
+        .with_label(span0)
+}
+
+fn use_literal_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Use an object literal instead of `Object.assign`, e.g. `{ foo: bar }`.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferObjectSpread;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow using `Object.assign` with an object literal as the first argument
+    /// and prefer the use of object spread instead.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When `Object.assign` is called with an object literal as the first argument,
+    /// it creates a new object, which is more concisely written with object spread.
+    ///
+    /// Calls with accessors in an object literal are allowed, as `Object.assign`
+    /// invokes setters of the target, while object spread defines properties.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// Object.assign({}, foo);
+    ///
+    /// Object.assign({}, { foo: "bar" });
+    ///
+    /// Object.assign({ foo: "bar" }, baz);
+    ///
+    /// Object.assign({});
+    /// ```
+    PreferObjectSpread,
+    style
+);
+
+impl Rule for PreferObjectSpread {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else {
+            return;
+        };
+        if !is_object_assign(call_expr, ctx) {
+            return;
+        }
+        let Some(Argument::ObjectExpression(_)) = call_expr.arguments.first() else {
+            return;
+        };
+        if call_expr.arguments.iter().any(|arg| matches!(arg, Argument::SpreadElement(_))) {
+            return;
+        }
+
+        let diagnostic = if call_expr.arguments.len() == 1 {
+            use_literal_diagnostic(call_expr.span)
+        } else {
+            // Spreading doesn't invoke the setters of the first object
+            if call_expr
+                .arguments
+                .iter()
+                .any(|arg| matches!(arg, Argument::ObjectExpression(obj) if has_accessors(obj)))
+            {
+                return;
+            }
+            use_spread_diagnostic(call_expr.span)
+        };
+
+        match get_fix(call_expr, node, ctx) {
+            Some(fixed) => {
+                ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(call_expr.span, fixed));
+            }
+            None => ctx.diagnostic(diagnostic),
+        }
+    }
+}
+
+/// Checks if the callee is `Object.assign` or `globalThis.Object.assign` of the global `Object`.
+fn is_object_assign(call_expr: &CallExpression, ctx: &LintContext) -> bool {
+    let Some(member_expr) = call_expr.callee.without_parenthesized().get_member_expr() else {
+        return false;
+    };
+    if member_expr.static_property_name() != Some("assign") {
+        return false;
+    }
+    match member_expr.object().without_parenthesized() {
+        Expression::Identifier(ident) => ident.name == "Object" && is_unmodified_global(ident, ctx),
+        obj => {
+            let Some(obj) = obj.as_member_expression() else {
+                return false;
+            };
+            let Expression::Identifier(ident) = obj.object().without_parenthesized() else {
+                return false;
+            };
+            obj.static_property_name() == Some("Object")
+                && GLOBAL_OBJECT_NAMES.contains(ident.name.as_str())
+                && is_unmodified_global(ident, ctx)
+        }
+    }
+}
+
+/// Checks if the identifier refers to a global which is never reassigned.
+fn is_unmodified_global(ident: &IdentifierReference, ctx: &LintContext) -> bool {
+    if !is_global_reference(ident, ctx) {
+        return false;
+    }
+    ctx.scopes().root_unresolved_references().get(ident.name.as_str()).map_or(true, |references| {
+        references
+            .iter()
+            .all(|(reference_id, _)| !ctx.symbols().get_reference(*reference_id).is_write())
+    })
+}
+
+fn has_accessors(obj: &ObjectExpression) -> bool {
+    obj.properties.iter().any(|prop| {
+        matches!(prop, ObjectPropertyKind::ObjectProperty(prop)
+            if matches!(prop.kind, PropertyKind::Get | PropertyKind::Set))
+    })
+}
+
+/// Rewrites the call as an object literal, spreading the arguments which are not
+/// object literals themselves.
+fn get_fix<'a>(
+    call_expr: &CallExpression<'a>,
+    node: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<String> {
+    if ctx.semantic().trivias().has_comments_between(call_expr.span) {
+        return None;
+    }
+
+    let mut parts = vec![];
+    for arg in &call_expr.arguments {
+        let arg = arg.as_expression()?;
+        if let Expression::ObjectExpression(obj) = arg {
+            if let (Some(first), Some(last)) = (obj.properties.first(), obj.properties.last()) {
+                let span = Span::new(first.span().start, last.span().end);
+                parts.push(ctx.source_range(span).to_string());
+            }
+        } else if get_precedence(arg) <= Precedence::Comma {
+            parts.push(format!("...({})", ctx.source_range(arg.span())));
+        } else {
+            parts.push(format!("...{}", ctx.source_range(arg.span())));
+        }
+    }
+
+    let object =
+        if parts.is_empty() { "{}".to_string() } else { format!("{{ {} }}", parts.join(", ")) };
+    // An object literal at the start of a statement would be parsed as a block
+    if get_starting_expression_statement(node, ctx).is_some() {
+        Some(format!("({object})"))
+    } else {
+        Some(object)
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "Object.assign()",
+        "let a = Object.assign(a, b)",
+        "Object.assign(a, b)",
+        "let a = Object.assign(b, { c: 1 })",
+        "const bar = { ...foo }",
+        "Object.assign(...foo)",
+        "Object.assign(foo, { bar: baz })",
+        "Object.assign({}, ...objects)",
+        "foo({ foo: 'bar' })",
+        "
+            const Object = {};
+            Object.assign({}, foo);
+        ",
+        "
+            Object = {};
+            Object.assign({}, foo);
+        ",
+        "
+            const Foo = require('foo');
+            Foo.assign({}, foo);
+        ",
+        "
+            const { Object } = require('foo');
+            Object.assign({}, foo);
+        ",
+        "
+            function foo (Object) { Object.assign({}, bar); }
+        ",
+        "Object.assign({ get a() {} }, {})",
+        "Object.assign({ set a(val) {} }, {})",
+        "Object.assign({ get a() {} }, foo)",
+        "Object.assign({ set a(val) {} }, foo)",
+        "Object.assign({ foo: 'bar', get a() {}, baz: 'quux' }, quuux)",
+        "Object.assign({}, { get a() {} })",
+        "Object.assign({}, { set a(val) {} })",
+        "globalThis.Object.assign({ get a() {} }, foo)",
+        "
+            var globalThis = foo;
+            globalThis.Object.assign({}, foo)
+        ",
+        "
+            globalThis = foo;
+            globalThis.Object.assign({}, foo)
+        ",
+        "class C { #assign; foo() { Object.#assign({}, foo); } }",
+        "Object.assign?.(foo, { bar: baz })",
+        "Object?.assign(foo, { bar: baz })",
+    ];
+
+    let fail = vec![
+        "Object.assign({}, foo)",
+        "Object.assign  ({}, foo)",
+        "Object.assign({}, { foo: 'bar' })",
+        "Object.assign({}, baz, { foo: 'bar' })",
+        "Object.assign({}, { foo: 'bar', baz: 'foo' })",
+        "Object.assign({ foo: 'bar' }, baz)",
+        "Object.assign({ foo: 'bar' }, cats, dogs, trees, birds)",
+        "Object.assign({ foo: 'bar' }, Object.assign({ bar: 'foo' }, baz))",
+        "Object.assign({ foo: 'bar' }, Object.assign({ bar: 'foo' }, Object.assign({}, { superNested: 'butwhy' })))",
+        "Object.assign({foo: 'bar', ...bar}, baz)",
+        "Object.assign({}, { foo, bar, baz })",
+        "Object.assign({}, { [bar]: 'foo' })",
+        "Object.assign({ ...bar }, { ...baz })",
+        "Object.assign({})",
+        "Object.assign({ foo: bar })",
+        "
+            const foo = 'bar';
+            Object.assign({ foo: bar })
+        ",
+        "
+            foo = 'bar';
+            Object.assign({ foo: bar })
+        ",
+        "let a = Object.assign({})",
+        "let a = Object.assign({}, a)",
+        "let a = Object.assign   ({}, a)",
+        "let a = Object.assign({ a: 1 }, b)",
+        "Object.assign(  {},  a,      b,   )",
+        "Object.assign({}, a ? b : {}, b => c, a = 2)",
+        "Object.assign({}, (a, b))",
+        "
+            const someVar = 'foo';
+            Object.assign({}, a, b)
+        ",
+        "() => Object.assign({}, a)",
+        "() => { return Object.assign({}, a) }",
+        "globalThis.Object.assign({}, foo)",
+        "globalThis.Object.assign({}, { foo: 'bar' })",
+        "function foo () { Object.assign({}, bar); }",
+        "Object.assign({ get a() {} })",
+        "Object.assign({ set a(val) {} })",
+        "Object.assign({}, foo).bar",
+    ];
+
+    let fix = vec![
+        ("Object.assign({}, foo)", "({ ...foo })"),
+        ("Object.assign  ({}, foo)", "({ ...foo })"),
+        ("Object.assign({}, { foo: 'bar' })", "({ foo: 'bar' })"),
+        ("Object.assign({}, baz, { foo: 'bar' })", "({ ...baz, foo: 'bar' })"),
+        ("Object.assign({ foo: 'bar' }, baz)", "({ foo: 'bar', ...baz })"),
+        ("Object.assign({foo: 'bar', ...bar}, baz)", "({ foo: 'bar', ...bar, ...baz })"),
+        ("Object.assign({})", "({})"),
+        ("let a = Object.assign({})", "let a = {}"),
+        ("let a = Object.assign({}, a)", "let a = { ...a }"),
+        ("Object.assign(  {},  a,      b,   )", "({ ...a, ...b })"),
+        (
+            "Object.assign({}, a ? b : {}, b => c, a = 2)",
+            "({ ...a ? b : {}, ...b => c, ...a = 2 })",
+        ),
+        ("Object.assign({}, (a, b))", "({ ...(a, b) })"),
+        ("let a = Object.assign({}, a, b)", "let a = { ...a, ...b }"),
+        ("() => Object.assign({}, a)", "() => ({ ...a })"),
+        ("() => { return Object.assign({}, a) }", "() => { return { ...a } }"),
+        ("Object.assign({}, foo).bar", "({ ...foo }).bar"),
+        ("foo(Object.assign({}, foo).bar)", "foo({ ...foo }.bar)"),
+        ("globalThis.Object.assign({}, foo)", "({ ...foo })"),
+        ("Object.assign({ get a() {} })", "({ get a() {} })"),
+        ("let a = Object.assign({ a: 1, }, b)", "let a = { a: 1, ...b }"),
+        // not fixed
+        (
+            "let a = Object.assign({}, /* comment */ a)",
+            "let a = Object.assign({}, /* comment */ a)",
+        ),
+    ];
+
+    Tester::new(PreferObjectSpread::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,405 @@
+use std::borrow::Cow;
+
+use oxc_ast::{
+    ast::{Argument, BinaryExpression, Expression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::{identifier::is_line_terminator, operator::BinaryOperator};
+
+use crate::{
+    ast_util::{is_global_reference, is_global_regexp_callee},
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+fn unexpected_regexp_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Use a regular expression literal instead of the 'RegExp' constructor.")
+        .with_label(span0)
+}
+
+fn unexpected_redundant_regexp_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Regular expression literal is unnecessarily wrapped within a 'RegExp' constructor.",
+    )
+    .with_label(span0)
+}
+
+fn unexpected_redundant_regexp_with_flags_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Use regular expression literal with flags instead of the 'RegExp' constructor.",
+    )
+    .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferRegexLiterals {
+    disallow_redundant_wrapping: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow use of the `RegExp` constructor in favor of regular expression literals.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Regular expression literals are shorter, don't need their backslashes escaped
+    /// twice, and can be checked statically. The `RegExp` constructor is only needed
+    /// when the pattern or the flags are built dynamically.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// new RegExp("abc");
+    /// new RegExp("abc", "u");
+    /// RegExp("abc");
+    /// new RegExp("\\d\\d\\.\\d\\d\\.\\d\\d\\d\\d");
+    /// new RegExp(String.raw`^\d\d\.\d\d\.\d\d\d\d$`);
+    /// new RegExp("a" + "b");
+    ///
+    /// // Good
+    /// /abc/;
+    /// /abc/u;
+    /// /\d\d\.\d\d\.\d\d\d\d/;
+    /// new RegExp(pattern);
+    /// new RegExp("abc", flags);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `disallowRedundantWrapping` (default `false`): also report regex literals that
+    /// are wrapped in a `RegExp` constructor, such as `new RegExp(/abc/, "u")`.
+    PreferRegexLiterals,
+    style
+);
+
+impl Rule for PreferRegexLiterals {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let disallow_redundant_wrapping = value
+            .get(0)
+            .and_then(|config| config.get("disallowRedundantWrapping"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        Self { disallow_redundant_wrapping }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (callee, arguments, span) = match node.kind() {
+            AstKind::NewExpression(new_expr) => {
+                (&new_expr.callee, &new_expr.arguments, new_expr.span)
+            }
+            AstKind::CallExpression(call) => (&call.callee, &call.arguments, call.span),
+            _ => return,
+        };
+        if !is_global_regexp_callee(callee, ctx) {
+            return;
+        }
+        if arguments.is_empty() || arguments.len() > 2 {
+            return;
+        }
+        let flags = match arguments.get(1) {
+            Some(flags) => {
+                match flags.as_expression().and_then(|expr| get_static_string(expr, ctx)) {
+                    Some(flags) => Some(flags),
+                    None => return,
+                }
+            }
+            None => None,
+        };
+
+        if let Argument::RegExpLiteral(literal) = &arguments[0] {
+            if !self.disallow_redundant_wrapping {
+                return;
+            }
+            let source = ctx.source_range(literal.span);
+            let Some(flags_start) = source.rfind('/') else {
+                return;
+            };
+            let diagnostic = if flags.is_some() {
+                unexpected_redundant_regexp_with_flags_diagnostic(span)
+            } else {
+                unexpected_redundant_regexp_diagnostic(span)
+            };
+            // The flags argument replaces the flags of the literal
+            let flags = flags.as_deref().unwrap_or(&source[flags_start + 1..]);
+            if !is_valid_flags(flags) {
+                ctx.diagnostic(diagnostic);
+                return;
+            }
+            let replacement = format!("{}{flags}", &source[..=flags_start]);
+            report_with_replacement(diagnostic, replacement, span, ctx);
+            return;
+        }
+
+        let Some(pattern) =
+            arguments[0].as_expression().and_then(|expr| get_static_string(expr, ctx))
+        else {
+            return;
+        };
+        let diagnostic = unexpected_regexp_diagnostic(span);
+        let flags = flags.as_deref().unwrap_or("");
+        if !is_valid_flags(flags) || !is_valid_pattern(&pattern) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        let replacement = format!("/{}/{flags}", escape_pattern(&pattern));
+        report_with_replacement(diagnostic, replacement, span, ctx);
+    }
+}
+
+fn report_with_replacement(
+    diagnostic: OxcDiagnostic,
+    replacement: String,
+    span: Span,
+    ctx: &LintContext,
+) {
+    // Keep `a/new RegExp("b")` from becoming the comment `a//b/`
+    let needs_space = ctx.source_text()[..span.start as usize].ends_with('/');
+    let replacement = if needs_space { format!(" {replacement}") } else { replacement };
+    ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+        fixer
+            .replace(span, replacement)
+            .with_message("Replace with an equivalent regular expression literal.")
+    });
+}
+
+/// Evaluates string literals, templates without substitutions, `String.raw` templates
+/// and concatenations of them.
+fn get_static_string<'a>(expr: &Expression<'a>, ctx: &LintContext<'a>) -> Option<Cow<'a, str>> {
+    match expr.without_parenthesized() {
+        Expression::StringLiteral(literal) => Some(Cow::Borrowed(literal.value.as_str())),
+        Expression::TemplateLiteral(template) if template.is_no_substitution_template() => {
+            template.quasis[0].value.cooked.as_ref().map(|cooked| Cow::Borrowed(cooked.as_str()))
+        }
+        Expression::TaggedTemplateExpression(tagged)
+            if tagged.quasi.is_no_substitution_template() && is_string_raw(&tagged.tag, ctx) =>
+        {
+            Some(Cow::Borrowed(tagged.quasi.quasis[0].value.raw.as_str()))
+        }
+        Expression::BinaryExpression(binary) => get_static_concatenation(binary, ctx),
+        _ => None,
+    }
+}
+
+fn get_static_concatenation<'a>(
+    binary: &BinaryExpression<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<Cow<'a, str>> {
+    if binary.operator != BinaryOperator::Addition {
+        return None;
+    }
+    let left = get_static_string(&binary.left, ctx)?;
+    let right = get_static_string(&binary.right, ctx)?;
+    Some(Cow::Owned(format!("{left}{right}")))
+}
+
+fn is_string_raw(tag: &Expression, ctx: &LintContext) -> bool {
+    let Expression::StaticMemberExpression(member) = tag.without_parenthesized() else {
+        return false;
+    };
+    let Expression::Identifier(object) = &member.object else {
+        return false;
+    };
+    object.name == "String" && member.property.name == "raw" && is_global_reference(object, ctx)
+}
+
+fn is_valid_flags(flags: &str) -> bool {
+    flags.chars().enumerate().all(|(i, c)| "dgimsuvy".contains(c) && !flags[..i].contains(c))
+        && !(flags.contains('u') && flags.contains('v'))
+}
+
+/// A rough syntax check, so that the suggestion doesn't turn a runtime error into a
+/// syntax error: brackets must be balanced and quantifiers must follow something.
+fn is_valid_pattern(pattern: &str) -> bool {
+    let mut depth = 0;
+    let mut in_class = false;
+    let mut quantifiable = false;
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if chars.next().is_none() {
+                    return false;
+                }
+                quantifiable = !in_class;
+                continue;
+            }
+            ']' if in_class => in_class = false,
+            _ if in_class => continue,
+            '[' => in_class = true,
+            '(' => {
+                depth += 1;
+                // `(?:`, `(?=`, `(?<name>`
+                chars.next_if_eq(&'?');
+            }
+            ')' => {
+                if depth == 0 {
+                    return false;
+                }
+                depth -= 1;
+            }
+            '*' | '+' | '?' => {
+                if !quantifiable {
+                    return false;
+                }
+                // Lazy quantifier
+                chars.next_if_eq(&'?');
+            }
+            _ => {}
+        }
+        quantifiable = !matches!(c, '(' | '|' | '^' | '*' | '+' | '?');
+    }
+    depth == 0 && !in_class
+}
+
+/// Escapes slashes and line terminators, which would end the regex literal.
+fn escape_pattern(pattern: &str) -> Cow<'_, str> {
+    if pattern.is_empty() {
+        return Cow::Borrowed("(?:)");
+    }
+    if !pattern.contains(|c| c == '/' || is_line_terminator(c)) {
+        return Cow::Borrowed(pattern);
+    }
+    let mut escaped = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                escaped.push('\\');
+                if let Some(next) = chars.next() {
+                    escaped.push_str(&escape_char(next).unwrap_or_else(|| next.to_string()));
+                }
+            }
+            '/' => escaped.push_str("\\/"),
+            _ => match escape_char(c) {
+                Some(escape) => escaped.push_str(&format!("\\{escape}")),
+                None => escaped.push(c),
+            },
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+/// Returns the escape letter of a line terminator.
+fn escape_char(c: char) -> Option<String> {
+    match c {
+        '\n' => Some("n".to_string()),
+        '\r' => Some("r".to_string()),
+        '\u{2028}' => Some("u2028".to_string()),
+        '\u{2029}' => Some("u2029".to_string()),
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("/abc/", None),
+        ("/abc/g", None),
+        ("new RegExp(pattern)", None),
+        ("RegExp(pattern, 'g')", None),
+        ("new RegExp(f('a'))", None),
+        ("RegExp(prefix + 'a')", None),
+        ("new RegExp('a' + suffix)", None),
+        ("RegExp(`a` + suffix);", None),
+        ("new RegExp(String.raw`a` + suffix);", None),
+        ("RegExp('a', flags)", None),
+        ("const flags = 'gu';RegExp('a', flags)", None),
+        ("RegExp('a', 'g' + flags)", None),
+        ("new RegExp(String.raw`a`, flags);", None),
+        ("RegExp(`${prefix}abc`)", None),
+        ("new RegExp(`a${b}c`);", None),
+        ("new RegExp(`a${''}c`);", None),
+        ("new RegExp(String.raw`a${b}c`);", None),
+        ("new RegExp(String.raw`a${''}c`);", None),
+        ("new RegExp('a' + 'b' + c)", None),
+        ("new RegExp('a', 'g', 'i')", None),
+        ("new RegExp(...args)", None),
+        ("new RegExp()", None),
+        ("RegExp()", None),
+        ("new RegExp(String`a`);", None),
+        ("new RegExp(String.foo`a`);", None),
+        ("new RegExp(raw`a`);", None),
+        ("new RegExp(foo.String.raw`a`);", None),
+        ("new Regexp('abc');", None),
+        ("Regexp(`a`);", None),
+        ("new Regexp(String.raw`a`);", None),
+        ("new RegExp(/a/);", None),
+        ("new RegExp(/a/, 'u');", None),
+        ("new RegExp(/a/);", Some(serde_json::json!([{ "disallowRedundantWrapping": false }]))),
+        (
+            "new RegExp(/a/, flags);",
+            Some(serde_json::json!([{ "disallowRedundantWrapping": true }])),
+        ),
+        (
+            "new RegExp(/a/, `u${flags}`);",
+            Some(serde_json::json!([{ "disallowRedundantWrapping": true }])),
+        ),
+        (
+            "new RegExp(/a/, 'g', 'i');",
+            Some(serde_json::json!([{ "disallowRedundantWrapping": true }])),
+        ),
+        ("new RegExp(foo, 'g');", Some(serde_json::json!([{ "disallowRedundantWrapping": true }]))),
+        ("RegExp.raw`a`", None),
+        ("new foo.RegExp('a')", None),
+        ("class C { #RegExp; foo() { globalThis.#RegExp('a'); } }", None),
+        ("function foo(RegExp) { return new RegExp('a'); }", None),
+        ("let RegExp; RegExp('a');", None),
+        ("function foo() { const String = bar; new RegExp(String.raw`a`); }", None),
+    ];
+
+    let fail = vec![
+        ("new RegExp('abc');", None),
+        ("RegExp('abc');", None),
+        ("new RegExp('abc', 'g');", None),
+        ("RegExp('abc', 'g');", None),
+        ("new RegExp(`abc`);", None),
+        ("RegExp(`abc`);", None),
+        ("new RegExp(`abc`, `g`);", None),
+        ("RegExp(`abc`, `g`);", None),
+        ("new RegExp(String.raw`abc`);", None),
+        ("new RegExp(String.raw`abc\nabc`);", None),
+        ("new RegExp(String.raw`\tabc\nabc`);", None),
+        ("RegExp(String.raw`abc`);", None),
+        ("new RegExp(String.raw`abc`, String.raw`g`);", None),
+        ("new RegExp(String.raw`\\d`);", None),
+        ("new RegExp(String.raw`\\\\d`);", None),
+        ("new RegExp('a' + 'b');", None),
+        ("new RegExp(`a` + 'b' + String.raw`c`, 'g' + 'u');", None),
+        ("new RegExp(('a'));", None),
+        ("new (RegExp)('a');", None),
+        ("new RegExp('', '');", None),
+        ("new RegExp('a/b');", None),
+        ("new RegExp('a\\\\/b');", None),
+        ("new RegExp('\\n');", None),
+        ("new RegExp('\\\\\\n');", None),
+        ("a/new RegExp('b')", None),
+        ("typeof RegExp('a')", None),
+        ("new RegExp('a').test(b)", None),
+        ("new RegExp('(');", None),
+        ("new RegExp('*');", None),
+        ("new RegExp('[');", None),
+        ("new RegExp('a', 'gg');", None),
+        ("new RegExp('a', 'z');", None),
+        ("new RegExp('a', 'uv');", None),
+        ("new RegExp(/a/);", Some(serde_json::json!([{ "disallowRedundantWrapping": true }]))),
+        ("new RegExp(/a/, 'u');", Some(serde_json::json!([{ "disallowRedundantWrapping": true }]))),
+        ("new RegExp(/a/, `u`);", Some(serde_json::json!([{ "disallowRedundantWrapping": true }]))),
+        (
+            "new RegExp(/a/g, 'i');",
+            Some(serde_json::json!([{ "disallowRedundantWrapping": true }])),
+        ),
+        ("new RegExp('a');", Some(serde_json::json!([{ "disallowRedundantWrapping": true }]))),
+        (
+            "new RegExp(/a/, 'gg');",
+            Some(serde_json::json!([{ "disallowRedundantWrapping": true }])),
+        ),
+    ];
+
+    Tester::new(PreferRegexLiterals::NAME, pass, fail).test_and_snapshot();
+}
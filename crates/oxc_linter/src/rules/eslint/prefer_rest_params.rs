@@ -0,0 +1,118 @@
+use oxc_ast::{ast::MemberExpression, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    ast_util::{is_global_reference, outermost_paren_parent},
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+fn prefer_rest_params_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Use the rest parameters instead of `arguments`.").with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferRestParams;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require rest parameters instead of `arguments`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// There are rest parameters in ES2015. We can use that feature for variadic
+    /// functions instead of the `arguments` variable. `arguments` does not have methods
+    /// of `Array.prototype`, so it's a bit of an inconvenience.
+    ///
+    /// Accessing a property of `arguments` by name, such as `arguments.length`, is
+    /// allowed.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function foo() {
+    ///     console.log(arguments);
+    /// }
+    ///
+    /// function foo(action) {
+    ///     var args = Array.prototype.slice.call(arguments, 1);
+    ///     action.apply(null, args);
+    /// }
+    ///
+    /// function foo(action) {
+    ///     var args = [].slice.call(arguments, 1);
+    ///     action.apply(null, args);
+    /// }
+    /// ```
+    PreferRestParams,
+    style
+);
+
+impl Rule for PreferRestParams {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::IdentifierReference(ident) = node.kind() else {
+            return;
+        };
+        // A declared `arguments` variable shadows the implicit binding
+        if ident.name != "arguments" || !is_global_reference(ident, ctx) {
+            return;
+        }
+        if let Some(AstKind::MemberExpression(MemberExpression::StaticMemberExpression(member))) =
+            outermost_paren_parent(node, ctx).map(AstNode::kind)
+        {
+            if member.object.without_parenthesized().span() == ident.span {
+                return;
+            }
+        }
+        // Arrow functions use the `arguments` of the enclosing function
+        let in_function = ctx
+            .nodes()
+            .iter_parents(node.id())
+            .any(|parent| matches!(parent.kind(), AstKind::Function(_)));
+        if in_function {
+            ctx.diagnostic(prefer_rest_params_diagnostic(ident.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "arguments;",
+        "function foo(arguments) { arguments; }",
+        "function foo() { var arguments; arguments; }",
+        "var foo = () => arguments;",
+        "function foo(...args) { args; }",
+        "function foo() { arguments.length; }",
+        "function foo() { arguments.callee; }",
+        "function foo() { arguments?.length; }",
+        "function foo() { let arguments = []; return arguments[0]; }",
+        "function foo() { const { arguments } = bar; arguments; }",
+        "class A { foo() { this.arguments; } }",
+        "function foo() { foo.arguments; }",
+        "function foo() { (arguments).length; }",
+    ];
+
+    let fail = vec![
+        "function foo() { arguments; }",
+        "function foo() { arguments[0]; }",
+        "function foo() { arguments[1]; }",
+        "function foo() { arguments[Symbol.iterator]; }",
+        "function foo() { return Array.prototype.slice.call(arguments, 1); }",
+        "function foo() { return [].slice.call(arguments); }",
+        "function foo() { bar.apply(this, arguments); }",
+        "function foo() { const args = [...arguments]; }",
+        "function foo() { return () => arguments; }",
+        "function foo() { arguments; arguments.length; arguments[0]; }",
+        "var foo = function() { return arguments; };",
+        "class A { foo() { return arguments; } }",
+        "({ foo() { return arguments; } })",
+    ];
+
+    Tester::new(PreferRestParams::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,155 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn prefer_spread_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Use the spread operator instead of `.apply()`.").with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferSpread;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require spread operators instead of `.apply()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Before ES2015, one must use `Function.prototype.apply()` to call variadic
+    /// functions. With the spread operator, `foo(...args)` is equivalent to
+    /// `foo.apply(undefined, args)` and is easier to read.
+    ///
+    /// Only calls with a `null` or `undefined` `this` argument on a function which
+    /// isn't accessed as a method are reported, as the call is otherwise not
+    /// equivalent to a spread.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// foo.apply(undefined, args);
+    ///
+    /// foo.apply(null, args);
+    ///
+    /// foo.apply(void 0, args);
+    /// ```
+    PreferSpread,
+    style
+);
+
+impl Rule for PreferSpread {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call_expr) = node.kind() else {
+            return;
+        };
+        let member_expr = match call_expr.callee.without_parenthesized() {
+            Expression::ChainExpression(chain) => chain.expression.as_member_expression(),
+            callee => callee.as_member_expression(),
+        };
+        let Some(member_expr) = member_expr else {
+            return;
+        };
+        if member_expr.static_property_name() != Some("apply") {
+            return;
+        }
+        let [this_arg, args] = call_expr.arguments.as_slice() else {
+            return;
+        };
+        // `.apply()` with an array literal is reported by `no-useless-call` instead
+        if matches!(args, Argument::ArrayExpression(_)) {
+            return;
+        }
+        let (Some(this_arg), Some(args)) = (this_arg.as_expression(), args.as_expression()) else {
+            return;
+        };
+        if !this_arg.is_null_or_undefined() {
+            return;
+        }
+        // A method would be called with a different `this`, e.g. `Math.max.apply(Math, arr)`
+        let applied = member_expr.object();
+        if applied.without_parenthesized().as_member_expression().is_some()
+            || matches!(applied.without_parenthesized(), Expression::ChainExpression(_))
+        {
+            return;
+        }
+
+        let diagnostic = prefer_spread_diagnostic(call_expr.span);
+        if call_expr.optional
+            || member_expr.optional()
+            || ctx.semantic().trivias().has_comments_between(call_expr.span)
+        {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        let fixed =
+            format!("{}(...{})", ctx.source_range(applied.span()), ctx.source_range(args.span()));
+        ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(call_expr.span, fixed));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "foo.apply(obj, args);",
+        "obj.foo.apply(null, args);",
+        "obj.foo.apply(otherObj, args);",
+        "a.b(x, y).c.foo.apply(a.b(x, z).c, args);",
+        "a.b.foo.apply(a.b.c, args);",
+        "foo.apply(undefined, [1, 2]);",
+        "foo.apply(null, [1, 2]);",
+        "obj.foo.apply(obj, [1, 2]);",
+        "var apply; foo[apply](null, args);",
+        "foo.apply();",
+        "obj.foo.apply();",
+        "obj.foo.apply(obj, ...args)",
+        "foo.apply(null, ...args)",
+        "foo.apply(null, args, extra)",
+        "foo.call(null, args)",
+        "Math.max.apply(Math, arr)",
+        "Math.max.apply(null, arr)",
+        "obj.foo.apply(obj, args);",
+        "(a?.b).c.apply(null, args);",
+        "class C { #apply; foo() { foo.#apply(undefined, args); } }",
+    ];
+
+    let fail = vec![
+        "foo.apply(undefined, args);",
+        "foo.apply(void 0, args);",
+        "foo.apply(null, args);",
+        "foo['apply'](null, args);",
+        "(foo).apply(null, args);",
+        "foo.apply(null, getArgs());",
+        "foo?.apply(undefined, args);",
+        "foo.apply?.(undefined, args);",
+        "(foo?.apply)(undefined, args);",
+        "foo.apply(/* comment */ null, args);",
+        "(a || b).apply(null, args);",
+        "foo()(bar).apply(null, args);",
+    ];
+
+    let fix = vec![
+        ("foo.apply(undefined, args);", "foo(...args);"),
+        ("foo.apply(void 0, args);", "foo(...args);"),
+        ("foo.apply(null, args);", "foo(...args);"),
+        ("foo['apply'](null, args);", "foo(...args);"),
+        ("(foo).apply(null, args);", "(foo)(...args);"),
+        ("foo.apply(null, getArgs());", "foo(...getArgs());"),
+        ("(a || b).apply(null, args);", "(a || b)(...args);"),
+        ("foo()(bar).apply(null, args);", "foo()(bar)(...args);"),
+        // not fixed
+        ("foo?.apply(undefined, args);", "foo?.apply(undefined, args);"),
+        ("foo.apply(/* comment */ null, args);", "foo.apply(/* comment */ null, args);"),
+    ];
+
+    Tester::new(PreferSpread::NAME, pass, fail)
+        .change_rule_plugin("eslint")
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
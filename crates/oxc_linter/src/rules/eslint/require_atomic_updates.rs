@@ -0,0 +1,375 @@
+use oxc_ast::{
+    ast::{
+        ArrowFunctionExpression, AssignmentExpression, AssignmentTarget, AwaitExpression,
+        CallExpression, ConditionalExpression, Expression, Function, IdentifierReference,
+        IfStatement, LogicalExpression, ReturnStatement, ThrowStatement, YieldExpression,
+    },
+    visit::walk::{
+        walk_assignment_target, walk_await_expression, walk_return_statement, walk_throw_statement,
+        walk_yield_expression,
+    },
+    AstKind, Visit,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::AssignmentOperator;
+use rustc_hash::FxHashSet;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn non_atomic_update_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Possible race condition: `{x0}` might be reassigned based on an outdated value of `{x0}`."
+    ))
+    .with_help("Read the variable again after the `await` or `yield`.")
+    .with_label(span1)
+}
+
+fn non_atomic_object_update_diagnostic(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Possible race condition: `{x0}` might be assigned based on an outdated state of `{x1}`."
+    ))
+    .with_help("Read the object again after the `await` or `yield`.")
+    .with_label(span2)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RequireAtomicUpdates {
+    allow_properties: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow assignments that can lead to race conditions due to usage of `await` or `yield`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When a variable is read, then the function is paused by `await` or `yield`,
+    /// and the variable is assigned afterwards, the assignment may be based on an
+    /// outdated value: other code could have modified the variable in the meantime.
+    ///
+    /// Only variables which are shared with other functions, and properties of
+    /// parameters, are checked.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// let result;
+    ///
+    /// async function foo() {
+    ///     result += await something;
+    /// }
+    ///
+    /// async function bar(obj) {
+    ///     if (!obj.done) {
+    ///         obj.done = await getDone();
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `allowProperties` (default `false`): do not report assignments to properties.
+    RequireAtomicUpdates,
+    nursery
+);
+
+impl Rule for RequireAtomicUpdates {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_properties: value
+                .get(0)
+                .and_then(|config| config.get("allowProperties"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::FunctionBody(body) = node.kind() else {
+            return;
+        };
+        let can_suspend = match ctx.nodes().parent_kind(node.id()) {
+            Some(AstKind::Function(func)) => func.r#async || func.generator,
+            Some(AstKind::ArrowFunctionExpression(arrow)) => arrow.r#async,
+            _ => false,
+        };
+        if !can_suspend {
+            return;
+        }
+
+        let mut checker = AtomicUpdateChecker {
+            ctx,
+            allow_properties: self.allow_properties,
+            state: ReadState::default(),
+            ignored_read: None,
+        };
+        checker.visit_function_body(body);
+    }
+}
+
+/// The variables read since the last suspension point, and the variables read before it.
+#[derive(Debug, Default, Clone)]
+struct ReadState {
+    fresh: FxHashSet<SymbolId>,
+    outdated: FxHashSet<SymbolId>,
+    /// Set after a `return` or `throw`, as the state can't flow to the following code.
+    terminated: bool,
+}
+
+impl ReadState {
+    fn mark_as_read(&mut self, symbol_id: SymbolId) {
+        self.fresh.insert(symbol_id);
+        self.outdated.remove(&symbol_id);
+    }
+
+    fn make_outdated(&mut self) {
+        self.outdated.extend(self.fresh.drain());
+    }
+
+    /// Joins the states at the end of two branches.
+    fn merge(self, other: Self) -> Self {
+        match (self.terminated, other.terminated) {
+            (true, _) => other,
+            (_, true) => self,
+            _ => Self {
+                fresh: self.fresh.union(&other.fresh).copied().collect(),
+                outdated: self.outdated.union(&other.outdated).copied().collect(),
+                terminated: false,
+            },
+        }
+    }
+}
+
+/// Walks the body of an async function or generator in evaluation order.
+struct AtomicUpdateChecker<'a, 'b> {
+    ctx: &'b LintContext<'a>,
+    allow_properties: bool,
+    state: ReadState,
+    /// The object of a member expression assigned with `=`, which isn't read.
+    ignored_read: Option<Span>,
+}
+
+impl<'a, 'b> AtomicUpdateChecker<'a, 'b> {
+    /// Variables only used by the current function can't be modified while it is paused,
+    /// unless they are parameters whose properties are assigned.
+    fn is_local_variable_without_escape(
+        &self,
+        symbol_id: SymbolId,
+        is_member_access: bool,
+    ) -> bool {
+        let symbols = self.ctx.symbols();
+        if is_member_access
+            && matches!(
+                self.ctx.nodes().kind(symbols.get_declaration(symbol_id)),
+                AstKind::FormalParameter(_)
+            )
+        {
+            return false;
+        }
+
+        let function_scope = self.get_function_scope(symbols.get_scope_id(symbol_id));
+        symbols.get_resolved_references(symbol_id).all(|reference| {
+            let scope_id = self.ctx.nodes().get_node(reference.node_id()).scope_id();
+            self.get_function_scope(scope_id) == function_scope
+        })
+    }
+
+    fn get_function_scope(&self, scope_id: ScopeId) -> Option<ScopeId> {
+        let scopes = self.ctx.scopes();
+        scopes.ancestors(scope_id).find(|scope_id| scopes.get_flags(*scope_id).is_var())
+    }
+}
+
+/// Returns the variable and whether it is the object of a member expression.
+fn get_assignment_target<'a, 'c>(
+    target: &'c AssignmentTarget<'a>,
+) -> Option<(&'c IdentifierReference<'a>, bool)> {
+    if let AssignmentTarget::AssignmentTargetIdentifier(ident) = target {
+        return Some((ident, false));
+    }
+    let mut object = target.as_member_expression()?.object();
+    loop {
+        match object.without_parenthesized() {
+            Expression::Identifier(ident) => return Some((ident, true)),
+            expr => object = expr.as_member_expression()?.object(),
+        }
+    }
+}
+
+impl<'a, 'b> Visit<'a> for AtomicUpdateChecker<'a, 'b> {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if self.ignored_read == Some(it.span) {
+            return;
+        }
+        let Some(reference_id) = it.reference_id.get() else {
+            return;
+        };
+        let reference = self.ctx.symbols().get_reference(reference_id);
+        if let Some(symbol_id) = reference.symbol_id() {
+            if reference.is_read() {
+                self.state.mark_as_read(symbol_id);
+            }
+        }
+    }
+
+    fn visit_assignment_expression(&mut self, it: &AssignmentExpression<'a>) {
+        let target = get_assignment_target(&it.left);
+        // `foo.bar = baz` doesn't read `foo.bar`
+        if let Some((ident, true)) = target {
+            if it.operator == AssignmentOperator::Assign {
+                self.ignored_read = Some(ident.span);
+            }
+        }
+        walk_assignment_target(self, &it.left);
+        self.ignored_read = None;
+        self.visit_expression(&it.right);
+
+        let Some((ident, is_member_access)) = target else {
+            return;
+        };
+        let Some(symbol_id) = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| self.ctx.symbols().get_reference(reference_id).symbol_id())
+        else {
+            return;
+        };
+        if !self.state.outdated.contains(&symbol_id)
+            || self.is_local_variable_without_escape(symbol_id, is_member_access)
+        {
+            return;
+        }
+
+        if !is_member_access {
+            self.ctx.diagnostic(non_atomic_update_diagnostic(&ident.name, it.span));
+        } else if !self.allow_properties {
+            self.ctx.diagnostic(non_atomic_object_update_diagnostic(
+                self.ctx.source_range(it.left.span()),
+                &ident.name,
+                it.span,
+            ));
+        }
+    }
+
+    fn visit_await_expression(&mut self, it: &AwaitExpression<'a>) {
+        walk_await_expression(self, it);
+        self.state.make_outdated();
+    }
+
+    fn visit_yield_expression(&mut self, it: &YieldExpression<'a>) {
+        walk_yield_expression(self, it);
+        self.state.make_outdated();
+    }
+
+    fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+        // The callee is evaluated before the arguments
+        self.visit_expression(&it.callee);
+        self.visit_arguments(&it.arguments);
+    }
+
+    fn visit_conditional_expression(&mut self, it: &ConditionalExpression<'a>) {
+        self.visit_expression(&it.test);
+        let before = self.state.clone();
+        self.visit_expression(&it.consequent);
+        let consequent = std::mem::replace(&mut self.state, before);
+        self.visit_expression(&it.alternate);
+        self.state = std::mem::take(&mut self.state).merge(consequent);
+    }
+
+    fn visit_logical_expression(&mut self, it: &LogicalExpression<'a>) {
+        self.visit_expression(&it.left);
+        let before = self.state.clone();
+        self.visit_expression(&it.right);
+        self.state = std::mem::take(&mut self.state).merge(before);
+    }
+
+    fn visit_if_statement(&mut self, it: &IfStatement<'a>) {
+        self.visit_expression(&it.test);
+        let before = self.state.clone();
+        self.visit_statement(&it.consequent);
+        let consequent = std::mem::replace(&mut self.state, before);
+        if let Some(alternate) = &it.alternate {
+            self.visit_statement(alternate);
+        }
+        self.state = std::mem::take(&mut self.state).merge(consequent);
+    }
+
+    fn visit_return_statement(&mut self, it: &ReturnStatement<'a>) {
+        walk_return_statement(self, it);
+        self.state.terminated = true;
+    }
+
+    fn visit_throw_statement(&mut self, it: &ThrowStatement<'a>) {
+        walk_throw_statement(self, it);
+        self.state.terminated = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+    use serde_json::json;
+
+    let pass = vec![
+        ("let foo; async function x() { foo += bar; }", None),
+        ("let foo; async function x() { foo = foo + bar; }", None),
+        ("let foo; async function x() { foo = await bar + foo; }", None),
+        ("async function x() { let foo; foo += await bar; }", None),
+        ("let foo; async function x() { foo = (await result)(foo); }", None),
+        ("let foo; async function x() { foo = bar(await something, foo) }", None),
+        ("function* x() { let foo; foo += yield bar; }", None),
+        ("const foo = 1; async function x() { foo = await bar; }", None),
+        ("let foo; function* x() { foo = bar + foo; }", None),
+        ("async function x() { let foo; bar(() => baz += 1); foo += await amount; }", None),
+        ("let foo; async function x() { foo = condition ? foo : await bar; }", None),
+        (
+            "async function x() { let foo; bar(() => { let foo; blah(foo); }); foo += await result; }",
+            None,
+        ),
+        ("let foo; async function x() { foo = foo + 1; await bar; }", None),
+        ("async function x() { foo += await bar; }", None),
+        (
+            "let count = 0; async function A() { count += 1; await new Promise(resolve => resolve()); count -= 1; return }",
+            None,
+        ),
+        ("let foo; async function x() { foo; if (a) { await bar; return; } foo = 1; }", None),
+        ("let foo; async function x() { if (a) { foo; } else { await bar; } }", None),
+        ("let foo; function x() { foo += bar(); }", None),
+        ("let foo; async function x() { const f = async () => foo; foo = await f(); }", None),
+        ("async function x(foo) { foo.prop = await bar; }", None),
+        ("async function x(foo) { foo.prop += await bar; }", Some(json!([{ "allowProperties": true }]))),
+        ("async function a(foo) { if (foo.bar) { foo.bar = await something; } }", Some(json!([{ "allowProperties": true }]))),
+    ];
+
+    let fail = vec![
+        ("let foo; async function x() { foo += await amount; }", None),
+        ("if (1); let foo; async function x() { foo += await amount; }", None),
+        ("let foo; async function x() { while (condition) { foo += await amount; } }", None),
+        ("let foo; async function x() { foo = foo + await amount; }", None),
+        ("let foo; async function x() { foo = foo + (bar ? baz : await amount); }", None),
+        ("let foo; async function x() { foo = foo + (bar ? await amount : baz); }", None),
+        (
+            "let foo; async function x() { foo = condition ? foo + await amount : somethingElse; }",
+            None,
+        ),
+        ("let foo; async function x() { foo = (condition ? foo : await bar) + await bar; }", None),
+        ("let foo; async function x() { foo += bar + await amount; }", None),
+        ("async function x() { let foo; bar(() => foo); foo += await amount; }", None),
+        ("let foo; function* x() { foo += yield baz }", None),
+        ("let foo; const x = async () => { foo = foo + await bar; };", None),
+        ("let foo; async function x() { foo = foo || await bar; }", None),
+        ("async function x(foo) { foo.prop += await amount; }", None),
+        ("async function a(foo) { if (foo.bar) { foo.bar = await something; } }", None),
+        ("function* g(foo) { baz = foo.bar; yield; foo.bar = 1; }", None),
+        ("async function x(foo) { foo.a.b = foo.a.b + await bar; }", None),
+    ];
+
+    Tester::new(RequireAtomicUpdates::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,499 @@
+use oxc_ast::{
+    ast::{Argument, Expression, RegExpFlags, RegExpLiteral},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{ast_util::is_global_regexp_callee, context::LintContext, rule::Rule, AstNode};
+
+fn require_unicode_regexp_diagnostic(flag: char, span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Use the '{flag}' flag.")).with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RequireUnicodeRegexp {
+    require_v_flag: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce the use of `u` or `v` flag on RegExp.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Without the `u` flag, regular expressions work on UTF-16 code units instead of
+    /// code points: surrogate pairs are matched as two characters, and case-insensitive
+    /// matching and character classes like `\w` ignore most of Unicode. The `u` flag
+    /// also makes invalid escapes like `\a` a syntax error, which catches typos early.
+    /// The `v` flag is an upgraded `u` flag with set notation in character classes.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// const a = /aaa/;
+    /// const b = /bbb/gi;
+    /// const c = new RegExp("ccc");
+    /// const d = new RegExp("ddd", "gi");
+    ///
+    /// // Good
+    /// const e = /eee/u;
+    /// const f = /fff/giu;
+    /// const g = new RegExp("ggg", "u");
+    /// const h = /hhh/v;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `requireFlag`: `"u"` or `"v"`. By default either flag is accepted; set it to
+    /// require one of them. `{ "requireFlag": "v" }` also reports regexes with only
+    /// the `u` flag.
+    ///
+    /// The suggested fix is only offered when the pattern stays valid with the new
+    /// flag, since the `u` flag changes how some patterns are parsed.
+    RequireUnicodeRegexp,
+    restriction
+);
+
+impl Rule for RequireUnicodeRegexp {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let require_v_flag = value
+            .get(0)
+            .and_then(|config| config.get("requireFlag"))
+            .and_then(serde_json::Value::as_str)
+            .is_some_and(|flag| flag == "v");
+        Self { require_v_flag }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::RegExpLiteral(literal) => self.check_literal(literal, ctx),
+            AstKind::NewExpression(new_expr) => {
+                self.check_regexp_constructor(
+                    &new_expr.callee,
+                    &new_expr.arguments,
+                    new_expr.span,
+                    ctx,
+                );
+            }
+            AstKind::CallExpression(call) => {
+                self.check_regexp_constructor(&call.callee, &call.arguments, call.span, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl RequireUnicodeRegexp {
+    fn required_flag(&self) -> char {
+        if self.require_v_flag {
+            'v'
+        } else {
+            'u'
+        }
+    }
+
+    fn is_missing_flag(&self, flags: &str) -> bool {
+        if self.require_v_flag {
+            !flags.contains('v')
+        } else {
+            !flags.contains('u') && !flags.contains('v')
+        }
+    }
+
+    /// Returns the flags with the required flag added, replacing `u` with `v`.
+    fn add_flag(&self, flags: &str) -> String {
+        let flag = self.required_flag();
+        if flag == 'v' && flags.contains('u') {
+            flags.replace('u', "v")
+        } else {
+            format!("{flags}{flag}")
+        }
+    }
+
+    fn can_fix(&self, pattern: &str) -> bool {
+        // Only patterns without character classes are known to mean the same with `v`
+        is_valid_with_unicode_flag(pattern) && !(self.require_v_flag && pattern.contains('['))
+    }
+
+    fn check_literal(&self, literal: &RegExpLiteral, ctx: &LintContext) {
+        let flags = literal.regex.flags;
+        let has_flag = if self.require_v_flag {
+            flags.contains(RegExpFlags::V)
+        } else {
+            flags.intersects(RegExpFlags::U | RegExpFlags::V)
+        };
+        if has_flag {
+            return;
+        }
+        let diagnostic = require_unicode_regexp_diagnostic(self.required_flag(), literal.span);
+        if !self.can_fix(&literal.regex.pattern) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        let source = ctx.source_range(literal.span);
+        let Some(flags_start) = source.rfind('/').map(|i| i + 1) else {
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+        let fixed = format!("{}{}", &source[..flags_start], self.add_flag(&source[flags_start..]));
+        let message = format!("Add the '{}' flag.", self.required_flag());
+        ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+            fixer.replace(literal.span, fixed).with_message(message)
+        });
+    }
+
+    /// Checks `new RegExp("a")` and `RegExp("a", "g")`.
+    fn check_regexp_constructor<'a>(
+        &self,
+        callee: &Expression<'a>,
+        arguments: &oxc_allocator::Vec<'a, Argument<'a>>,
+        span: Span,
+        ctx: &LintContext<'a>,
+    ) {
+        if !is_global_regexp_callee(callee, ctx) {
+            return;
+        }
+        let Some(pattern_arg) = arguments.first() else {
+            return;
+        };
+        if pattern_arg.is_spread() {
+            return;
+        }
+        let pattern = match pattern_arg {
+            Argument::StringLiteral(pattern) => Some(pattern.value.as_str()),
+            _ => None,
+        };
+        let diagnostic = require_unicode_regexp_diagnostic(self.required_flag(), span);
+        let message = format!("Add the '{}' flag.", self.required_flag());
+        match arguments.get(1) {
+            None => {
+                if !pattern.is_some_and(|pattern| self.can_fix(pattern)) {
+                    ctx.diagnostic(diagnostic);
+                    return;
+                }
+                let flag = self.required_flag();
+                ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                    fixer
+                        .insert_text_after_range(pattern_arg.span(), format!(", \"{flag}\""))
+                        .with_message(message)
+                });
+            }
+            Some(Argument::StringLiteral(flags)) => {
+                if !self.is_missing_flag(&flags.value) {
+                    return;
+                }
+                if !pattern.is_some_and(|pattern| self.can_fix(pattern)) {
+                    ctx.diagnostic(diagnostic);
+                    return;
+                }
+                let quote = &ctx.source_range(flags.span)[..1];
+                let fixed = format!("{quote}{}{quote}", self.add_flag(&flags.value));
+                ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                    fixer.replace(flags.span, fixed).with_message(message)
+                });
+            }
+            // Flags that aren't known statically
+            Some(_) => {}
+        }
+    }
+}
+
+/// Checks that adding the `u` flag doesn't turn `pattern` into a syntax error.
+/// Unicode mode forbids the lenient syntax of Annex B, such as unknown escapes,
+/// lone braces and quantified lookaheads.
+fn is_valid_with_unicode_flag(pattern: &str) -> bool {
+    let chars: Vec<char> = pattern.chars().collect();
+    let group_names = find_group_names(&chars);
+    let group_count = count_capturing_groups(&chars);
+    // Whether each open group is a lookaround, which can't be quantified
+    let mut groups: Vec<bool> = vec![];
+    let mut in_class = false;
+    // Whether the previous class atom is a set like `\w`, which can't bound a range
+    let mut prev_class_set = false;
+    let mut quantifiable = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\\' {
+            let Some(escape) = parse_escape(&chars[i + 1..], in_class, group_count, &group_names)
+            else {
+                return false;
+            };
+            if in_class {
+                let is_range = i > 0 && chars[i - 1] == '-' && !prev_class_set;
+                if escape.is_class_set && is_range && !is_class_start(&chars, i - 1) {
+                    return false;
+                }
+                prev_class_set = escape.is_class_set;
+            } else {
+                quantifiable = !escape.is_assertion;
+            }
+            i += escape.len + 1;
+            continue;
+        }
+        if in_class {
+            match c {
+                ']' => {
+                    in_class = false;
+                    quantifiable = true;
+                }
+                '-' if prev_class_set && chars.get(i + 1) != Some(&']') => return false,
+                _ => prev_class_set = false,
+            }
+            i += 1;
+            continue;
+        }
+        match c {
+            '[' => {
+                in_class = true;
+                prev_class_set = false;
+                if chars.get(i + 1) == Some(&'^') {
+                    i += 1;
+                }
+            }
+            ']' | '}' => return false,
+            '{' => {
+                let Some(len) = parse_braced_quantifier(&chars[i..]) else {
+                    return false;
+                };
+                if !quantifiable {
+                    return false;
+                }
+                quantifiable = false;
+                i += len - 1;
+                if chars.get(i + 1) == Some(&'?') {
+                    i += 1;
+                }
+            }
+            '*' | '+' | '?' => {
+                if !quantifiable {
+                    return false;
+                }
+                quantifiable = false;
+                if chars.get(i + 1) == Some(&'?') {
+                    i += 1;
+                }
+            }
+            '(' => {
+                let is_lookaround = chars.get(i + 1) == Some(&'?')
+                    && (matches!(chars.get(i + 2), Some('=' | '!'))
+                        || (chars.get(i + 2) == Some(&'<')
+                            && matches!(chars.get(i + 3), Some('=' | '!'))));
+                groups.push(is_lookaround);
+                quantifiable = false;
+            }
+            ')' => {
+                let Some(is_lookaround) = groups.pop() else {
+                    return false;
+                };
+                quantifiable = !is_lookaround;
+            }
+            '|' | '^' | '$' => quantifiable = false,
+            _ => quantifiable = true,
+        }
+        i += 1;
+    }
+    !in_class && groups.is_empty()
+}
+
+/// Whether the `-` at `dash` is the first character of a character class.
+fn is_class_start(chars: &[char], dash: usize) -> bool {
+    match dash.checked_sub(1).map(|i| chars[i]) {
+        Some('[') => true,
+        Some('^') => dash >= 2 && chars[dash - 2] == '[',
+        _ => false,
+    }
+}
+
+struct Escape {
+    /// Length of the escape, without the backslash
+    len: usize,
+    /// `\b` and `\B` outside character classes
+    is_assertion: bool,
+    /// `\d`, `\w`, `\s`, `\p{...}` and their negations
+    is_class_set: bool,
+}
+
+/// Parses the escape after a backslash in unicode mode, returning `None` if it's invalid.
+fn parse_escape(
+    chars: &[char],
+    in_class: bool,
+    group_count: usize,
+    group_names: &[String],
+) -> Option<Escape> {
+    let escape = |len| Some(Escape { len, is_assertion: false, is_class_set: false });
+    match *chars.first()? {
+        '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|'
+        | '/' | 'f' | 'n' | 'r' | 't' | 'v' => escape(1),
+        '-' | 'b' if in_class => escape(1),
+        'd' | 'D' | 'w' | 'W' | 's' | 'S' => {
+            Some(Escape { len: 1, is_assertion: false, is_class_set: true })
+        }
+        'b' | 'B' if !in_class => Some(Escape { len: 1, is_assertion: true, is_class_set: false }),
+        'c' if chars.get(1).is_some_and(char::is_ascii_alphabetic) => escape(2),
+        '0' if !chars.get(1).is_some_and(char::is_ascii_digit) => escape(1),
+        '1'..='9' if !in_class => {
+            let len = chars.iter().take_while(|c| c.is_ascii_digit()).count();
+            let index: String = chars[..len].iter().collect();
+            if index.parse::<usize>().ok()? > group_count {
+                return None;
+            }
+            escape(len)
+        }
+        'x' if chars.len() >= 3 && chars[1..3].iter().all(char::is_ascii_hexdigit) => escape(3),
+        'u' if chars.get(1) == Some(&'{') => {
+            let len = chars[2..].iter().position(|&c| c == '}')?;
+            let hex: String = chars[2..2 + len].iter().collect();
+            if u32::from_str_radix(&hex, 16).ok()? > 0x0010_FFFF {
+                return None;
+            }
+            escape(len + 3)
+        }
+        'u' if chars.len() >= 5 && chars[1..5].iter().all(char::is_ascii_hexdigit) => escape(5),
+        'p' | 'P' if chars.get(1) == Some(&'{') => {
+            let len = chars[2..].iter().position(|&c| c == '}')?;
+            let valid = len > 0
+                && chars[2..2 + len].iter().all(|c| c.is_ascii_alphanumeric() || "_=".contains(*c));
+            valid.then_some(Escape { len: len + 3, is_assertion: false, is_class_set: true })
+        }
+        'k' if !in_class && chars.get(1) == Some(&'<') => {
+            let len = chars[2..].iter().position(|&c| c == '>')?;
+            let name: String = chars[2..2 + len].iter().collect();
+            if !group_names.contains(&name) {
+                return None;
+            }
+            escape(len + 3)
+        }
+        _ => None,
+    }
+}
+
+/// Parses `{n}`, `{n,}` or `{n,m}`, returning its length.
+fn parse_braced_quantifier(chars: &[char]) -> Option<usize> {
+    let end = chars.iter().position(|&c| c == '}')?;
+    let inner: String = chars[1..end].iter().collect();
+    let (min, max) = inner.split_once(',').unwrap_or((&inner, "0"));
+    let is_number = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    (is_number(min) && (max.is_empty() || is_number(max))).then_some(end + 1)
+}
+
+/// Iterates over the characters outside of escapes and character classes.
+fn syntax_chars(chars: &[char]) -> impl Iterator<Item = usize> + '_ {
+    let mut in_class = false;
+    let mut escaped = false;
+    (0..chars.len()).filter(move |&i| {
+        if escaped {
+            escaped = false;
+            return false;
+        }
+        match chars[i] {
+            '\\' => escaped = true,
+            '[' if !in_class => in_class = true,
+            ']' if in_class => in_class = false,
+            _ => return !in_class,
+        }
+        false
+    })
+}
+
+fn count_capturing_groups(chars: &[char]) -> usize {
+    syntax_chars(chars)
+        .filter(|&i| {
+            chars[i] == '('
+                && (chars.get(i + 1) != Some(&'?')
+                    || (chars.get(i + 2) == Some(&'<')
+                        && !matches!(chars.get(i + 3), Some('=' | '!'))))
+        })
+        .count()
+}
+
+fn find_group_names(chars: &[char]) -> Vec<String> {
+    syntax_chars(chars)
+        .filter(|&i| chars[i] == '(' && chars.get(i + 1) == Some(&'?'))
+        .filter(|&i| chars.get(i + 2) == Some(&'<'))
+        .filter_map(|i| {
+            let rest = &chars[i + 3..];
+            let len = rest.iter().position(|&c| c == '>')?;
+            Some(rest[..len].iter().collect())
+        })
+        .collect()
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("/foo/u", None),
+        ("/foo/gimuy", None),
+        ("RegExp('', 'u')", None),
+        ("new RegExp('', 'u')", None),
+        ("RegExp('', 'gimuy')", None),
+        ("new RegExp('', 'gimuy')", None),
+        ("const flags = 'u'; new RegExp('', flags)", None),
+        ("const flags = 'g'; new RegExp('', flags + 'u')", None),
+        ("const flags = 'gimu'; new RegExp('foo', flags[3])", None),
+        ("new RegExp('', flags)", None),
+        ("function f(flags) { return new RegExp('', flags) }", None),
+        ("function f(RegExp) { return new RegExp('foo') }", None),
+        ("function f(patternAndFlags) { return new RegExp(...patternAndFlags) }", None),
+        ("new foo.RegExp('foo')", None),
+        ("RegExp.foo('foo')", None),
+        ("new RegExp()", None),
+        ("RegExp()", None),
+        ("/foo/v", None),
+        ("new RegExp('', 'v')", None),
+        ("/foo/v", Some(serde_json::json!([{ "requireFlag": "v" }]))),
+        ("new RegExp('', 'gv')", Some(serde_json::json!([{ "requireFlag": "v" }]))),
+        ("/foo/u", Some(serde_json::json!([{ "requireFlag": "u" }]))),
+    ];
+
+    let fail = vec![
+        ("/foo/", None),
+        ("/foo/gimy", None),
+        ("RegExp('foo')", None),
+        ("RegExp('foo', '')", None),
+        ("RegExp('foo', 'gimy')", None),
+        ("new RegExp('foo')", None),
+        ("new RegExp('foo', '')", None),
+        ("new RegExp('foo', 'gimy')", None),
+        ("new RegExp('foo', \"gimy\")", None),
+        ("new RegExp(('foo'))", None),
+        ("new RegExp(pattern)", None),
+        ("RegExp(pattern, 'g')", None),
+        ("new RegExp('foo',)", None),
+        (r"/\a/", None),
+        (r"/\-/", None),
+        (r"/[\w-a]/", None),
+        (r"/[a-\d]/", None),
+        ("/{/", None),
+        ("/a{/", None),
+        ("/a{1,b}/", None),
+        ("/}/", None),
+        ("/]/", None),
+        ("/(?=a)*/", None),
+        (r"/\1/", None),
+        (r"/(a)\2/", None),
+        (r"/\k<a>/", None),
+        (r"/(?<a>.)\k<b>/", None),
+        (r"/\c1/", None),
+        (r"/\00/", None),
+        (r"/\xz/", None),
+        (r"/\u{110000}/", None),
+        (r"/[\B]/", None),
+        (r"RegExp('\\a')", None),
+        (r"/a{1,2}?(?:b|c)*\d+a\u{61}\x61\cA\p{L}(?<n>x)\k<n>\1[\b\-^a-z\w-]\//", None),
+        ("/[a-]b{1}/", None),
+        (r"/[-\w]/", None),
+        (r"/[^-\w]/", None),
+        ("/foo/", Some(serde_json::json!([{ "requireFlag": "v" }]))),
+        ("/foo/u", Some(serde_json::json!([{ "requireFlag": "v" }]))),
+        ("/[a]/u", Some(serde_json::json!([{ "requireFlag": "v" }]))),
+        ("new RegExp('foo', 'gu')", Some(serde_json::json!([{ "requireFlag": "v" }]))),
+        ("new RegExp('foo')", Some(serde_json::json!([{ "requireFlag": "v" }]))),
+    ];
+
+    Tester::new(RequireUnicodeRegexp::NAME, pass, fail).test_and_snapshot();
+}
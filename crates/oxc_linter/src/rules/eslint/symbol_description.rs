@@ -19,11 +19,15 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
-    /// The Symbol function may have an optional description.
+    /// The Symbol function may have an optional description. Using a description
+    /// promotes easier debugging: when a symbol is logged, the description is used.
     ///
     /// ### Example
     /// ```javascript
     /// var foo = Symbol();
+    ///
+    /// // Good
+    /// var bar = Symbol("some description");
     /// ```
     SymbolDescription,
     pedantic,
@@ -59,9 +63,19 @@ fn test() {
         "Symbol(); var Symbol = function () {};",
         "function bar() { var Symbol = function () {}; Symbol(); }",
         "function bar(Symbol) { Symbol(); }",
+        "Symbol.for('foo');",
+        "Symbol.iterator;",
+        "import { Symbol } from 'foo'; Symbol();",
     ];
 
-    let fail = vec!["Symbol();", "Symbol(); Symbol = function () {};"];
+    let fail = vec![
+        "Symbol();",
+        "Symbol(); Symbol = function () {};",
+        "(Symbol)();",
+        "Symbol?.();",
+        "function bar() { return Symbol(); }",
+        "const foo = { [Symbol()]: 1 };",
+    ];
 
     Tester::new(SymbolDescription::NAME, pass, fail).test_and_snapshot();
 }
@@ -11,7 +11,7 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 
 fn comparison_with_na_n(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Requires calls to isNaN() when checking for NaN")
-        .with_help("Use the isNaN function to compare with NaN.")
+        .with_help("Use Number.isNaN() to compare with NaN.")
         .with_label(span0)
 }
 
@@ -190,7 +190,7 @@ fn make_equality_fix<'a>(
         _ => unreachable!(),
     };
 
-    format!("{maybe_bang}isNaN({non_nan})")
+    format!("{maybe_bang}Number.isNaN({non_nan})")
 }
 
 #[test]
@@ -525,18 +525,20 @@ fn test() {
     ];
 
     let fix = vec![
-        ("1 == NaN", "isNaN(1)", None),
-        ("1 === NaN", "isNaN(1)", None),
-        ("1 != NaN", "!isNaN(1)", None),
-        ("1 !== NaN", "!isNaN(1)", None),
-        ("NaN == 'foo'", "isNaN('foo')", None),
-        ("NaN === 'foo'", "isNaN('foo')", None),
-        ("NaN != 'foo'", "!isNaN('foo')", None),
-        ("NaN !== 'foo'", "!isNaN('foo')", None),
-        ("1 == Number.NaN", "isNaN(1)", None),
-        ("1 === Number.NaN", "isNaN(1)", None),
-        ("1 != Number.NaN", "!isNaN(1)", None),
-        ("1 !== Number.NaN", "!isNaN(1)", None),
+        ("1 == NaN", "Number.isNaN(1)", None),
+        ("1 === NaN", "Number.isNaN(1)", None),
+        ("1 != NaN", "!Number.isNaN(1)", None),
+        ("1 !== NaN", "!Number.isNaN(1)", None),
+        ("NaN == 'foo'", "Number.isNaN('foo')", None),
+        ("NaN === 'foo'", "Number.isNaN('foo')", None),
+        ("NaN != 'foo'", "!Number.isNaN('foo')", None),
+        ("NaN !== 'foo'", "!Number.isNaN('foo')", None),
+        ("1 == Number.NaN", "Number.isNaN(1)", None),
+        ("1 === Number.NaN", "Number.isNaN(1)", None),
+        ("1 != Number.NaN", "!Number.isNaN(1)", None),
+        ("1 !== Number.NaN", "!Number.isNaN(1)", None),
+        ("foo.bar === NaN", "Number.isNaN(foo.bar)", None),
+        ("NaN !== a + b", "!Number.isNaN(a + b)", None),
     ];
 
     Tester::new(UseIsnan::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
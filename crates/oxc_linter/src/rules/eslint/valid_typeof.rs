@@ -55,7 +55,7 @@ declare_oxc_lint!(
 impl Rule for ValidTypeof {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         // match on `typeof` unary expression for better performance
-        let _unary_expr = match node.kind() {
+        let unary_expr = match node.kind() {
             AstKind::UnaryExpression(unary_expr)
                 if unary_expr.operator == UnaryOperator::Typeof =>
             {
@@ -71,15 +71,16 @@ impl Rule for ValidTypeof {
             _ => return,
         };
 
-        let ((Expression::UnaryExpression(_), sibling) | (sibling, Expression::UnaryExpression(_))) =
-            (&binary_expr.left, &binary_expr.right)
-        else {
-            return;
+        // The operand `typeof` is compared with
+        let sibling = if binary_expr.left.span() == unary_expr.span {
+            &binary_expr.right
+        } else {
+            &binary_expr.left
         };
 
         if let Expression::StringLiteral(lit) = sibling {
             if !VALID_TYPES.contains(lit.value.as_str()) {
-                ctx.diagnostic(invalid_value(None, sibling.span()));
+                ctx.diagnostic(invalid_value(Some(VALID_TYPES_HELP), sibling.span()));
             }
             return;
         }
@@ -87,7 +88,7 @@ impl Rule for ValidTypeof {
         if let Expression::TemplateLiteral(template) = sibling {
             if template.expressions.is_empty() {
                 if template.quasi().is_some_and(|value| !VALID_TYPES.contains(value.as_str())) {
-                    ctx.diagnostic(invalid_value(None, sibling.span()));
+                    ctx.diagnostic(invalid_value(Some(VALID_TYPES_HELP), sibling.span()));
                 }
                 return;
             }
@@ -95,7 +96,7 @@ impl Rule for ValidTypeof {
 
         if let Expression::Identifier(ident) = sibling {
             if ident.name == "undefined" && ctx.semantic().is_reference_to_global_variable(ident) {
-                ctx.diagnostic_with_fix(
+                ctx.diagnostic_with_suggestion(
                     if self.require_string_literals {
                         not_string(
                             Some("Use `\"undefined\"` instead of `undefined`."),
@@ -132,6 +133,8 @@ impl Rule for ValidTypeof {
     }
 }
 
+const VALID_TYPES_HELP: &str = "The result of `typeof` is one of \"bigint\", \"boolean\", \"function\", \"number\", \"object\", \"string\", \"symbol\" or \"undefined\".";
+
 const VALID_TYPES: Set<&'static str> = phf_set! {
     "symbol",
     "undefined",
@@ -175,6 +178,8 @@ fn test() {
         ("var oddUse = typeof foo + 'thing'", None),
         ("function f(undefined) { typeof x === undefined }", None),
         ("typeof foo === `str${somethingElse}`", None),
+        ("!foo === typeof bar", None),
+        ("-1 == typeof bar", None),
         ("typeof foo === 'number'", Some(serde_json::json!([{ "requireStringLiterals": true }]))),
         ("typeof foo === \"number\"", Some(serde_json::json!([{ "requireStringLiterals": true }]))),
         (
@@ -200,6 +205,8 @@ fn test() {
         ("'strnig' == typeof foo", None),
         ("if (typeof bar == 'umdefined') {}", None),
         ("if (typeof bar === `umdefined`) {}", None),
+        ("!foo === typeof bar || typeof bar === 'fucntion'", None),
+        ("void 0 === typeof bar", Some(serde_json::json!([{ "requireStringLiterals": true }]))),
         (
             "typeof foo == 'invalid string'",
             Some(serde_json::json!([{ "requireStringLiterals": true }])),
@@ -1,7 +1,8 @@
 use oxc_ast::{
     ast::{
         match_expression, ClassElement, Declaration, ExportDefaultDeclarationKind, FunctionType,
-        ModuleDeclaration, PropertyKey, Statement, TSSignature,
+        MethodDefinitionKind, ModuleDeclaration, PropertyKey, Statement, TSMethodSignatureKind,
+        TSSignature,
     },
     AstKind,
 };
@@ -126,6 +127,12 @@ trait GetMethod {
 impl GetMethod for ClassElement<'_> {
     fn get_method(&self) -> Option<Method> {
         match self {
+            // Accessors can't be overloaded, so a `get`/`set` pair is not a signature group
+            ClassElement::MethodDefinition(def)
+                if matches!(def.kind, MethodDefinitionKind::Get | MethodDefinitionKind::Set) =>
+            {
+                None
+            }
             ClassElement::MethodDefinition(def) => def.key.static_name().map(|name| Method {
                 name: name.into(),
                 r#static: def.r#static,
@@ -141,6 +148,9 @@ impl GetMethod for ClassElement<'_> {
 impl GetMethod for TSSignature<'_> {
     fn get_method(&self) -> Option<Method> {
         match self {
+            TSSignature::TSMethodSignature(sig) if sig.kind != TSMethodSignatureKind::Method => {
+                None
+            }
             TSSignature::TSMethodSignature(sig) => sig.key.static_name().map(|name| Method {
                 name: name.into(),
                 r#static: false,
@@ -519,6 +529,31 @@ fn test() {
         function foo(n: number);
         function foo(sn: string | number) {}
       }",
+        r"class Foo {
+        get foo(): string { return ''; }
+        bar(): void {}
+        set foo(value: string) {}
+      }",
+        r"class Foo {
+        static get foo(): string { return ''; }
+        foo(): void {}
+        static set foo(value: string) {}
+      }",
+        r"interface Foo {
+        get foo(): string;
+        bar(): void;
+        set foo(value: string);
+      }",
+        r"type Foo = {
+        get foo(): string;
+        bar(): void;
+        set foo(value: string);
+      };",
+        r"class Foo {
+        [foo](s: string): void;
+        bar(): void {}
+        [foo](n: number): void {}
+      }",
     ];
 
     let fail = vec![
@@ -759,6 +794,16 @@ fn test() {
         #private(arg: number): void {}
         '#private'(arg: number): void {}
       }",
+        r"class Foo {
+        foo(s: string): void;
+        get bar(): string { return ''; }
+        foo(n: number): void {}
+      }",
+        r"interface Foo {
+        foo(s: string): void;
+        set bar(value: string);
+        foo(n: number): void;
+      }",
     ];
 
     Tester::new(AdjacentOverloadSignatures::NAME, pass, fail).test_and_snapshot();
@@ -138,19 +138,34 @@ fn check(
     readonly_config: &ArrayOption,
     ctx: &LintContext,
 ) {
-    if let TSType::TSArrayType(array_type) = &type_annotation {
-        check_and_report_error_generic(
-            default_config,
-            array_type.span,
-            &array_type.element_type,
-            ctx,
-            false,
-        );
-    }
+    check_type(type_annotation, default_config, readonly_config, ctx, false);
+}
 
-    if let TSType::TSTypeOperatorType(ts_operator_type) = &type_annotation {
-        if matches!(&ts_operator_type.operator, TSTypeOperatorOperator::Readonly) {
-            if let TSType::TSArrayType(array_type) = &ts_operator_type.type_annotation {
+/// Checks `type_annotation` and the types nested in it, e.g. the `string[]` in `string[][]` or
+/// the `Array<T>` in `Foo<Array<T>>`. `is_array_element` is set when `type_annotation` is the
+/// element type of an array type.
+fn check_type(
+    type_annotation: &TSType,
+    default_config: &ArrayOption,
+    readonly_config: &ArrayOption,
+    ctx: &LintContext,
+    is_array_element: bool,
+) {
+    match type_annotation {
+        TSType::TSArrayType(array_type) => {
+            check_and_report_error_generic(
+                default_config,
+                array_type.span,
+                &array_type.element_type,
+                ctx,
+                false,
+            );
+            check_type(&array_type.element_type, default_config, readonly_config, ctx, true);
+        }
+        TSType::TSTypeOperatorType(ts_operator_type) => {
+            if let (TSTypeOperatorOperator::Readonly, TSType::TSArrayType(array_type)) =
+                (&ts_operator_type.operator, &ts_operator_type.type_annotation)
+            {
                 check_and_report_error_generic(
                     readonly_config,
                     ts_operator_type.span,
@@ -158,12 +173,61 @@ fn check(
                     ctx,
                     true,
                 );
+                check_type(&array_type.element_type, default_config, readonly_config, ctx, true);
+            } else {
+                check_type(
+                    &ts_operator_type.type_annotation,
+                    default_config,
+                    readonly_config,
+                    ctx,
+                    false,
+                );
             }
         }
-    }
-
-    if let TSType::TSTypeReference(ts_type_reference) = &type_annotation {
-        check_and_report_error_array(default_config, readonly_config, ts_type_reference, ctx);
+        TSType::TSTypeReference(ts_type_reference) => {
+            check_and_report_error_array(
+                default_config,
+                readonly_config,
+                ts_type_reference,
+                ctx,
+                is_array_element,
+            );
+            if let Some(type_parameters) = &ts_type_reference.type_parameters {
+                for param in &type_parameters.params {
+                    check_type(param, default_config, readonly_config, ctx, false);
+                }
+            }
+        }
+        TSType::TSUnionType(union_type) => {
+            for ts_type in &union_type.types {
+                check_type(ts_type, default_config, readonly_config, ctx, false);
+            }
+        }
+        TSType::TSIntersectionType(intersection_type) => {
+            for ts_type in &intersection_type.types {
+                check_type(ts_type, default_config, readonly_config, ctx, false);
+            }
+        }
+        TSType::TSConditionalType(conditional_type) => {
+            for ts_type in [
+                &conditional_type.check_type,
+                &conditional_type.extends_type,
+                &conditional_type.true_type,
+                &conditional_type.false_type,
+            ] {
+                check_type(ts_type, default_config, readonly_config, ctx, false);
+            }
+        }
+        TSType::TSParenthesizedType(parenthesized_type) => {
+            check_type(
+                &parenthesized_type.type_annotation,
+                default_config,
+                readonly_config,
+                ctx,
+                false,
+            );
+        }
+        _ => {}
     }
 }
 
@@ -173,9 +237,10 @@ fn type_needs_parentheses(type_param: &TSType) -> bool {
             if let TSTypeName::IdentifierReference(identifier_reference) = &node.type_name {
                 return identifier_reference.name.as_str() == "ReadonlyArray";
             }
-            true
+            false
         }
         TSType::TSUnionType(_)
+        | TSType::TSConditionalType(_)
         | TSType::TSFunctionType(_)
         | TSType::TSIntersectionType(_)
         | TSType::TSTypeOperatorType(_)
@@ -230,6 +295,7 @@ fn check_and_report_error_array(
     readonly_config: &ArrayOption,
     ts_type_reference: &TSTypeReference,
     ctx: &LintContext,
+    is_array_element: bool,
 ) {
     let TSTypeName::IdentifierReference(ident_ref_type_name) = &ts_type_reference.type_name else {
         return;
@@ -273,14 +339,8 @@ fn check_and_report_error_array(
     }
 
     let type_parens = type_needs_parentheses(first_type_param);
-    // TODO: support example: type Foo = ReadonlyArray<object>[]; -> type Foo = (readonly object[])[];
-    // let mut parent_parens: bool = readonly_prefix != "";
-    // if let Some(parent) = ctx.nodes().parent_node(node.id()) {
-    //     if let AstKind::TSTypeAnnotation(parent_node) = parent.kind() {}
-    // } else {
-    //     parent_parens = false
-    // };
-    let parent_parens = false;
+    // for example: type Foo = ReadonlyArray<object>[]; -> type Foo = (readonly object[])[];
+    let parent_parens = is_readonly_array_type && is_array_element;
 
     let element_type_span = get_ts_element_type_span(first_type_param);
     let Some(element_type_span) = element_type_span else {
@@ -346,10 +406,7 @@ fn is_simple_type(ts_type: &TSType) -> bool {
                 if node.type_parameters.is_some() {
                     return false;
                 }
-                if let TSTypeName::IdentifierReference(_) = &node.type_name {
-                    return true;
-                }
-                return false;
+                return true;
             }
             false
         }
@@ -937,7 +994,10 @@ fn test() {
         ),
         ("let a: { foo: Array<Bar> }[] = [];", Some(serde_json::json!([{"default":"array"}]))),
         ("let a: Array<{ foo: Bar[] }> = [];", Some(serde_json::json!([{"default":"generic"}]))),
-        // ("let a: Array<{ foo: Foo | Bar[] }> = [];", Some(serde_json::json!([{"default":"generic"}]))),
+        (
+            "let a: Array<{ foo: Foo | Bar[] }> = [];",
+            Some(serde_json::json!([{"default":"generic"}])),
+        ),
         (
             "function foo(a: Array<Bar>): Array<Bar> {}",
             Some(serde_json::json!([{"default":"array"}])),
@@ -956,10 +1016,13 @@ fn test() {
             Some(serde_json::json!([{"default":"array-simple"}])),
         ),
         ("type Arr<T> = Array<T>;", Some(serde_json::json!([{"default":"array-simple"}]))),
-        // ("
-        // // Ignore user defined aliases
-        // let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];
-        //     ", Some(serde_json::json!([{"default":"array-simple"}]))),
+        (
+            "
+        // Ignore user defined aliases
+        let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];
+            ",
+            Some(serde_json::json!([{"default":"array-simple"}])),
+        ),
         (
             "
         interface ArrayClass<T> {
@@ -991,8 +1054,14 @@ fn test() {
             "type barIntersection = (string & number)[];",
             Some(serde_json::json!([{"default":"array-simple"}])),
         ),
-        // ("let v: Array<fooName.BarType> = [{ bar: 'bar' }];", Some(serde_json::json!([{"default":"array-simple"}]))),
-        // ("let w: fooName.BazType<string>[] = [['baz']];", Some(serde_json::json!([{"default":"array-simple"}]))),
+        (
+            "let v: Array<fooName.BarType> = [{ bar: 'bar' }];",
+            Some(serde_json::json!([{"default":"array-simple"}])),
+        ),
+        (
+            "let w: fooName.BazType<string>[] = [['baz']];",
+            Some(serde_json::json!([{"default":"array-simple"}])),
+        ),
         (
             "let x: Array<undefined> = [undefined] as undefined[];",
             Some(serde_json::json!([{"default":"array"}])),
@@ -1000,10 +1069,13 @@ fn test() {
         ("let y: string[] = <Array<string>>['2'];", Some(serde_json::json!([{"default":"array"}]))),
         ("let z: Array = [3, '4'];", Some(serde_json::json!([{"default":"array"}]))),
         ("type Arr<T> = Array<T>;", Some(serde_json::json!([{"default":"array"}]))),
-        // ("
-        // // Ignore user defined aliases
-        // let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];
-        //     ", Some(serde_json::json!([{"default":"array"}]))),
+        (
+            "
+        // Ignore user defined aliases
+        let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];
+            ",
+            Some(serde_json::json!([{"default":"array"}])),
+        ),
         (
             "
         interface ArrayClass<T> {
@@ -1050,10 +1122,13 @@ fn test() {
             "let ya = [[1, '2']] as [number, string][];",
             Some(serde_json::json!([{"default":"generic"}])),
         ),
-        // ("
-        // // Ignore user defined aliases
-        // let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];
-        //     ", Some(serde_json::json!([{"default":"generic"}]))),
+        (
+            "
+        // Ignore user defined aliases
+        let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];
+            ",
+            Some(serde_json::json!([{"default":"generic"}])),
+        ),
         (
             "
         interface ArrayClass<T> {
@@ -1092,9 +1167,15 @@ fn test() {
             ",
             Some(serde_json::json!([{"default":"generic"}])),
         ),
-        // ("type Unwrap<T> = T extends Array<infer E> ? E : T;", Some(serde_json::json!([{"default":"array"}]))),
-        // ("type Unwrap<T> = T extends (infer E)[] ? E : T;", Some(serde_json::json!([{"default":"generic"}]))),
-        // ("type Foo = ReadonlyArray<object>[];", Some(serde_json::json!([{"default":"array"}]))),
+        (
+            "type Unwrap<T> = T extends Array<infer E> ? E : T;",
+            Some(serde_json::json!([{"default":"array"}])),
+        ),
+        (
+            "type Unwrap<T> = T extends (infer E)[] ? E : T;",
+            Some(serde_json::json!([{"default":"generic"}])),
+        ),
+        ("type Foo = ReadonlyArray<object>[];", Some(serde_json::json!([{"default":"array"}]))),
         (
             "const foo: Array<new (...args: any[]) => void> = [];",
             Some(serde_json::json!([{"default":"array"}])),
@@ -1386,7 +1467,11 @@ fn test() {
             "let a: Array<{ foo: Array<Bar> }> = [];",
             Some(serde_json::json!([{"default":"generic"}])),
         ),
-        // ("let a: Array<{ foo: Foo | Bar[] }> = [];", "let a: Array<{ foo: Foo | Array<Bar> }> = [];", Some(serde_json::json!([{"default":"generic"}]))),
+        (
+            "let a: Array<{ foo: Foo | Bar[] }> = [];",
+            "let a: Array<{ foo: Foo | Array<Bar> }> = [];",
+            Some(serde_json::json!([{"default":"generic"}])),
+        ),
         (
             "function foo(a: Array<Bar>): Array<Bar> {}",
             "function foo(a: Bar[]): Bar[] {}",
@@ -1413,13 +1498,17 @@ fn test() {
             "type Arr<T> = T[];",
             Some(serde_json::json!([{"default":"array-simple"}])),
         ),
-        // ("
-        // // Ignore user defined aliases
-        // let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];
-        //     ", "
-        // // Ignore user defined aliases
-        // let yyyy: Arr<Array<Array<Arr<string>>>> = [[[['2']]]];
-        //     ", Some(serde_json::json!([{"default":"array-simple"}]))),
+        (
+            "
+        // Ignore user defined aliases
+        let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];
+            ",
+            "
+        // Ignore user defined aliases
+        let yyyy: Arr<Array<Array<Arr<string>>>> = [[[['2']]]];
+            ",
+            Some(serde_json::json!([{"default":"array-simple"}])),
+        ),
         (
             "
         interface ArrayClass<T> {
@@ -1467,8 +1556,16 @@ fn test() {
             "type barIntersection = Array<string & number>;",
             Some(serde_json::json!([{"default":"array-simple"}])),
         ),
-        // ("let v: Array<fooName.BarType> = [{ bar: 'bar' }];", "let v: fooName.BarType[] = [{ bar: 'bar' }];", Some(serde_json::json!([{"default":"array-simple"}]))),
-        // ("let w: fooName.BazType<string>[] = [['baz']];", "let w: Array<fooName.BazType<string>> = [['baz']];", Some(serde_json::json!([{"default":"array-simple"}]))),
+        (
+            "let v: Array<fooName.BarType> = [{ bar: 'bar' }];",
+            "let v: fooName.BarType[] = [{ bar: 'bar' }];",
+            Some(serde_json::json!([{"default":"array-simple"}])),
+        ),
+        (
+            "let w: fooName.BazType<string>[] = [['baz']];",
+            "let w: Array<fooName.BazType<string>> = [['baz']];",
+            Some(serde_json::json!([{"default":"array-simple"}])),
+        ),
         (
             "let x: Array<undefined> = [undefined] as undefined[];",
             "let x: undefined[] = [undefined] as undefined[];",
@@ -1485,13 +1582,17 @@ fn test() {
             "type Arr<T> = T[];",
             Some(serde_json::json!([{"default":"array"}])),
         ),
-        // ("
-        // // Ignore user defined aliases
-        // let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];
-        //     ", "
-        // // Ignore user defined aliases
-        // let yyyy: Arr<Arr<string>[][]> = [[[['2']]]];
-        //     ", Some(serde_json::json!([{"default":"array"}]))),
+        (
+            "
+        // Ignore user defined aliases
+        let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];
+            ",
+            "
+        // Ignore user defined aliases
+        let yyyy: Arr<Arr<string>[][]> = [[[['2']]]];
+            ",
+            Some(serde_json::json!([{"default":"array"}])),
+        ),
         (
             "
         interface ArrayClass<T> {
@@ -1552,13 +1653,17 @@ fn test() {
             "let ya = [[1, '2']] as Array<[number, string]>;",
             Some(serde_json::json!([{"default":"generic"}])),
         ),
-        // ("
-        // // Ignore user defined aliases
-        // let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];
-        //     ", "
-        // // Ignore user defined aliases
-        // let yyyy: Arr<Array<Array<Arr<string>>>> = [[[['2']]]];
-        //     ", Some(serde_json::json!([{"default":"generic"}]))),
+        (
+            "
+        // Ignore user defined aliases
+        let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];
+            ",
+            "
+        // Ignore user defined aliases
+        let yyyy: Arr<Array<Array<Arr<string>>>> = [[[['2']]]];
+            ",
+            Some(serde_json::json!([{"default":"generic"}])),
+        ),
         (
             "
         interface ArrayClass<T> {
@@ -1617,9 +1722,21 @@ fn test() {
             ",
             Some(serde_json::json!([{"default":"generic"}])),
         ),
-        // ("type Unwrap<T> = T extends Array<infer E> ? E : T;", "type Unwrap<T> = T extends (infer E)[] ? E : T;", Some(serde_json::json!([{"default":"array"}]))),
-        // ("type Unwrap<T> = T extends (infer E)[] ? E : T;", "type Unwrap<T> = T extends Array<infer E> ? E : T;", Some(serde_json::json!([{"default":"generic"}]))),
-        // ("type Foo = ReadonlyArray<object>[];", "type Foo = (readonly object[])[];", Some(serde_json::json!([{"default":"array"}]))),
+        (
+            "type Unwrap<T> = T extends Array<infer E> ? E : T;",
+            "type Unwrap<T> = T extends (infer E)[] ? E : T;",
+            Some(serde_json::json!([{"default":"array"}])),
+        ),
+        (
+            "type Unwrap<T> = T extends (infer E)[] ? E : T;",
+            "type Unwrap<T> = T extends Array<infer E> ? E : T;",
+            Some(serde_json::json!([{"default":"generic"}])),
+        ),
+        (
+            "type Foo = ReadonlyArray<object>[];",
+            "type Foo = (readonly object[])[];",
+            Some(serde_json::json!([{"default":"array"}])),
+        ),
         (
             "const foo: Array<new (...args: any[]) => void> = [];",
             "const foo: (new (...args: any[]) => void)[] = [];",
@@ -3,6 +3,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use regex::Regex;
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -181,7 +182,7 @@ impl Rule for BanTsComment {
                         }
                     }
                     config => {
-                        let description_len = description.trim().len();
+                        let description_len = description.trim().graphemes(true).count();
                         if (description_len as u64) < self.minimum_description_length {
                             ctx.diagnostic(comment_requires_description(
                                 directive,
@@ -711,6 +712,10 @@ fn test() {
             }])),
         ),
         ("/** @ts-expect-error */", Some(serde_json::json!([{ "ts-expect-error": true}]))),
+        (
+            "// @ts-expect-error 👨‍👩‍👧‍👦",
+            Some(serde_json::json!([{ "ts-expect-error": "allow-with-description" }])),
+        ),
         (
             "// @ts-expect-error: Suppress next line",
             Some(serde_json::json!([{ "ts-expect-error": true}])),
@@ -835,6 +840,10 @@ if (false) {
                   "minimumDescriptionLength": 25
             }])),
         ),
+        (
+            "// @ts-ignore 👨‍👩‍👧‍👦",
+            Some(serde_json::json!([{ "ts-ignore": "allow-with-description" }])),
+        ),
         ("// @ts-ignore: Suppress next line", None),
         ("/////@ts-ignore: Suppress next line", None),
         (
@@ -889,6 +898,10 @@ if (false) {
         // ts-nocheck
         ("// @ts-nocheck", Some(serde_json::json!([{ "ts-nocheck": true}]))),
         ("// @ts-nocheck", None),
+        (
+            "// @ts-nocheck 👨‍👩‍👧‍👦",
+            Some(serde_json::json!([{ "ts-nocheck": "allow-with-description" }])),
+        ),
         ("// @ts-nocheck: Suppress next line", None),
         (
             r"
@@ -933,6 +946,10 @@ if (false) {
         ),
         // ts-check
         ("// @ts-check", Some(serde_json::json!([{ "ts-check": true}]))),
+        (
+            "// @ts-check 👨‍👩‍👧‍👦",
+            Some(serde_json::json!([{ "ts-check": "allow-with-description" }])),
+        ),
         ("// @ts-check: Suppress next line", Some(serde_json::json!([{ "ts-check":true}]))),
         (
             r"
@@ -0,0 +1,771 @@
+use std::borrow::Cow;
+
+use oxc_ast::{
+    ast::{
+        Class, ClassBody, ClassElement, Expression, Function, MethodDefinition,
+        MethodDefinitionKind, PropertyDefinition, PropertyKey, SimpleAssignmentTarget, Statement,
+        TSAccessibility,
+    },
+    AstKind, Visit,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn prefer_fields_style_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Literals should be exposed using readonly fields.")
+        .with_help("Replace the literals with readonly fields.")
+        .with_label(span0)
+}
+
+fn prefer_getters_style_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Literals should be exposed using getters.")
+        .with_help("Replace the literals with getters.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ClassLiteralPropertyStyle {
+    style: Style,
+    /// Skip classes with an `implements` clause, whose getters may be required by the interface.
+    ignore_classes_with_implements: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+enum Style {
+    #[default]
+    Fields,
+    Getters,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that literals on classes are exposed in a consistent style.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Some TypeScript applications store literal values on classes using fields with the
+    /// `readonly` modifier to prevent them from being reassigned. When writing TypeScript
+    /// libraries that could be used by JavaScript users, however, it's typically safer to
+    /// expose these literals using getters, since the `readonly` modifier is enforced at
+    /// compile time.
+    ///
+    /// This rule aims to ensure that literals exposed by classes are done so consistently,
+    /// in one of the two styles: `"fields"` (default) or `"getters"`.
+    ///
+    /// ### Example
+    /// ```ts
+    /// // incorrect, when set to "fields"
+    /// class Mx {
+    ///   public static get myField1() {
+    ///     return 1;
+    ///   }
+    /// }
+    ///
+    /// // incorrect, when set to "getters"
+    /// class Mx {
+    ///   readonly myField1 = 1;
+    /// }
+    /// ```
+    ClassLiteralPropertyStyle,
+    style
+);
+
+impl Rule for ClassLiteralPropertyStyle {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let options = value.as_array();
+        let style = options
+            .and_then(|options| options.iter().find_map(serde_json::Value::as_str))
+            .map_or_else(Style::default, |value| match value {
+                "getters" => Style::Getters,
+                _ => Style::Fields,
+            });
+        let ignore_classes_with_implements = options
+            .and_then(|options| options.iter().find(|option| option.is_object()))
+            .and_then(|option| option.get("ignoreClassesWithImplements"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        Self { style, ignore_classes_with_implements }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match self.style {
+            Style::Fields => {
+                let AstKind::MethodDefinition(method) = node.kind() else {
+                    return;
+                };
+                if method.kind != MethodDefinitionKind::Get {
+                    return;
+                }
+                let Some(body) = &method.value.body else {
+                    return;
+                };
+                let Some(Statement::ReturnStatement(return_statement)) = body.statements.first()
+                else {
+                    return;
+                };
+                let Some(argument) = &return_statement.argument else {
+                    return;
+                };
+                if !get_is_supported_literal(argument) {
+                    return;
+                }
+                if self.ignore_classes_with_implements && is_in_class_with_implements(node, ctx) {
+                    return;
+                }
+
+                let Some(AstKind::ClassBody(class_body)) = ctx.nodes().parent_kind(node.id())
+                else {
+                    return;
+                };
+                if has_duplicate_key_setter(class_body, &method.key, method.computed, ctx) {
+                    return;
+                }
+
+                ctx.diagnostic_with_fix(
+                    prefer_fields_style_diagnostic(method.key.span()),
+                    |fixer| {
+                        let modifiers = get_method_definition_modifiers(method);
+                        let name = get_key_text(&method.key, method.computed, ctx);
+                        let value = ctx.source_range(argument.span());
+                        fixer.replace(method.span, format!("{modifiers}readonly {name} = {value};"))
+                    },
+                );
+            }
+            Style::Getters => {
+                let AstKind::ClassBody(class_body) = node.kind() else {
+                    return;
+                };
+                let assigned_names = get_constructor_assigned_names(class_body);
+
+                for element in &class_body.body {
+                    let ClassElement::PropertyDefinition(property) = element else {
+                        continue;
+                    };
+                    if !property.readonly || property.declare {
+                        continue;
+                    }
+                    let Some(value) = &property.value else {
+                        continue;
+                    };
+                    if !get_is_supported_literal(value) {
+                        continue;
+                    }
+                    if self.ignore_classes_with_implements && is_in_class_with_implements(node, ctx)
+                    {
+                        return;
+                    }
+                    if property
+                        .key
+                        .static_name()
+                        .is_some_and(|name| assigned_names.iter().any(|n| *n == name))
+                    {
+                        continue;
+                    }
+
+                    if has_duplicate_key_setter(class_body, &property.key, property.computed, ctx) {
+                        ctx.diagnostic(prefer_getters_style_diagnostic(property.key.span()));
+                        continue;
+                    }
+
+                    ctx.diagnostic_with_fix(
+                        prefer_getters_style_diagnostic(property.key.span()),
+                        |fixer| {
+                            let modifiers = get_property_definition_modifiers(property);
+                            let name = get_key_text(&property.key, property.computed, ctx);
+                            let value = ctx.source_range(value.span());
+                            fixer.replace(
+                                property.span,
+                                format!("{modifiers}get {name}() {{ return {value}; }}"),
+                            )
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+fn is_in_class_with_implements(node: &AstNode, ctx: &LintContext) -> bool {
+    ctx.nodes()
+        .iter_parents(node.id())
+        .find_map(|parent| match parent.kind() {
+            AstKind::Class(class) => Some(class),
+            _ => None,
+        })
+        .is_some_and(|class| class.implements.as_ref().is_some_and(|i| !i.is_empty()))
+}
+
+fn get_is_supported_literal(expression: &Expression) -> bool {
+    match expression {
+        Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BigIntLiteral(_)
+        | Expression::RegExpLiteral(_)
+        | Expression::StringLiteral(_) => true,
+        Expression::TemplateLiteral(template) => template.expressions.is_empty(),
+        Expression::TaggedTemplateExpression(tagged) => tagged.quasi.expressions.is_empty(),
+        _ => false,
+    }
+}
+
+/// Name of a class member, falling back to the source text of computed keys such as `[myValue]`
+/// so that a getter and setter sharing the same computed key are still paired up.
+fn get_key_name<'a>(
+    key: &PropertyKey<'a>,
+    computed: bool,
+    ctx: &LintContext<'a>,
+) -> Option<Cow<'a, str>> {
+    key.static_name().or_else(|| computed.then(|| Cow::Borrowed(ctx.source_range(key.span()))))
+}
+
+/// Whether the class body has a setter with the same name as the given key.
+fn has_duplicate_key_setter<'a>(
+    class_body: &ClassBody<'a>,
+    key: &PropertyKey<'a>,
+    computed: bool,
+    ctx: &LintContext<'a>,
+) -> bool {
+    let Some(name) = get_key_name(key, computed, ctx) else {
+        return false;
+    };
+    class_body.body.iter().any(|element| {
+        let ClassElement::MethodDefinition(element) = element else {
+            return false;
+        };
+        element.kind == MethodDefinitionKind::Set
+            && get_key_name(&element.key, element.computed, ctx).is_some_and(|n| n == name)
+    })
+}
+
+/// Source text of a class member key, keeping the brackets of computed keys.
+fn get_key_text<'a>(key: &PropertyKey<'a>, computed: bool, ctx: &LintContext<'a>) -> Cow<'a, str> {
+    let text = ctx.source_range(key.span());
+    if computed {
+        Cow::Owned(format!("[{text}]"))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+fn get_method_definition_modifiers(method: &MethodDefinition) -> String {
+    format_modifiers(method.accessibility, method.r#static)
+}
+
+fn get_property_definition_modifiers(property: &PropertyDefinition) -> String {
+    format_modifiers(property.accessibility, property.r#static)
+}
+
+fn format_modifiers(accessibility: Option<TSAccessibility>, is_static: bool) -> String {
+    let mut modifiers = String::new();
+    if let Some(accessibility) = accessibility {
+        modifiers.push_str(match accessibility {
+            TSAccessibility::Public => "public ",
+            TSAccessibility::Protected => "protected ",
+            TSAccessibility::Private => "private ",
+        });
+    }
+    if is_static {
+        modifiers.push_str("static ");
+    }
+    modifiers
+}
+
+/// Names of the properties reassigned through `this.<name>` or `this['<name>']` in the class
+/// constructor. Such properties cannot be turned into getters.
+fn get_constructor_assigned_names(class_body: &ClassBody) -> Vec<String> {
+    let mut finder = ThisAssignmentFinder { names: vec![] };
+    for element in &class_body.body {
+        if let ClassElement::MethodDefinition(method) = element {
+            if method.kind == MethodDefinitionKind::Constructor {
+                if let Some(body) = &method.value.body {
+                    finder.visit_function_body(body);
+                }
+            }
+        }
+    }
+    finder.names
+}
+
+struct ThisAssignmentFinder {
+    names: Vec<String>,
+}
+
+impl<'a> Visit<'a> for ThisAssignmentFinder {
+    fn visit_simple_assignment_target(&mut self, target: &SimpleAssignmentTarget<'a>) {
+        let Some(member_expr) = target.as_member_expression() else {
+            return;
+        };
+        if !matches!(member_expr.object(), Expression::ThisExpression(_)) {
+            return;
+        }
+        if let Some(name) = member_expr.static_property_name() {
+            self.names.push(name.to_string());
+        }
+    }
+
+    // `this` inside a nested function or class does not refer to the instance being constructed.
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _class: &Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("class Mx { declare readonly p1 = 1; }", None),
+        ("class Mx { readonly p1 = 'hello world'; }", None),
+        ("class Mx { p1 = 'hello world'; }", None),
+        ("class Mx { static p1 = 'hello world'; }", None),
+        ("class Mx { p1: string; }", None),
+        ("class Mx { get p1() {} }", None),
+        ("abstract class Mx { abstract get p1(): string }", None),
+        (
+            "
+            class Mx {
+              get mySetting() {
+                if (this._aValue) {
+                  return 'on';
+                }
+
+                return 'off';
+              }
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            class Mx {
+              get mySetting() {
+                return `build-${process.env.build}`;
+              }
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            class Mx {
+              getMySetting() {
+                if (this._aValue) {
+                  return 'on';
+                }
+
+                return 'off';
+              }
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            class Mx {
+              public readonly myButton = styled.button`
+                color: ${props => (props.red ? 'red' : 'blue')};
+              `;
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            class Mx {
+              set p1(val) {}
+              get p1() {
+                return '';
+              }
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            let p1 = 'p1';
+            class Mx {
+              set [p1](val) {}
+              get [p1]() {
+                return '';
+              }
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            let p1 = 'p1';
+            class Mx {
+              set [/* before set */ p1 /* after set */](val) {}
+              get [/* before get */ p1 /* before get */]() {
+                return '';
+              }
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            class Mx {
+              set ['foo'](val) {}
+              get foo() {
+                return '';
+              }
+              set bar(val) {}
+              get ['bar']() {
+                return '';
+              }
+              set ['baz'](val) {}
+              get baz() {
+                return '';
+              }
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            class Mx {
+              public get myButton() {
+                return styled.button`
+                  color: ${props => (props.red ? 'red' : 'blue')};
+                `;
+              }
+            }
+            ",
+            Some(serde_json::json!(["fields"])),
+        ),
+        ("class Mx { declare public readonly foo = 1; }", Some(serde_json::json!(["getters"]))),
+        ("class Mx { get p1() { return 'hello world'; } }", Some(serde_json::json!(["getters"]))),
+        ("class Mx { p1 = 'hello world'; }", Some(serde_json::json!(["getters"]))),
+        ("class Mx { p1: string; }", Some(serde_json::json!(["getters"]))),
+        ("class Mx { readonly p1 = [1, 2, 3]; }", Some(serde_json::json!(["getters"]))),
+        ("class Mx { static p1: string; }", Some(serde_json::json!(["getters"]))),
+        (
+            "class Mx { static get p1() { return 'hello world'; } }",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "
+            class Mx {
+              public readonly myButton = styled.button`
+                color: ${props => (props.red ? 'red' : 'blue')};
+              `;
+            }
+            ",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "
+            class Mx {
+              public get myButton() {
+                return styled.button`
+                  color: ${props => (props.red ? 'red' : 'blue')};
+                `;
+              }
+            }
+            ",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "
+            class A {
+              private readonly foo: string = 'bar';
+              constructor(foo: string) {
+                this.foo = foo;
+              }
+            }
+            ",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "
+            class A {
+              private readonly foo: string = 'bar';
+              constructor(foo: string) {
+                this['foo'] = foo;
+              }
+            }
+            ",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "
+            class A {
+              private readonly foo: string = 'bar';
+              constructor(foo: string) {
+                const bar = new (class {
+                  private readonly foo: string = 'baz';
+                  constructor() {
+                    this.foo = 'qux';
+                  }
+                })();
+
+                if (bar) {
+                  this.foo = 'baz';
+                }
+              }
+            }
+            ",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "
+            class A {
+              private readonly ['foo']: string = 'bar';
+              constructor(foo: string) {
+                this.foo = foo;
+              }
+            }
+            ",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "
+            class A {
+              private readonly foo: string = 'bar';
+              constructor(foo: string) {
+                const fn = () => {
+                  this.foo = foo;
+                };
+              }
+            }
+            ",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "class Mx implements Foo { get p1() { return 'hello world'; } }",
+            Some(serde_json::json!(["fields", { "ignoreClassesWithImplements": true }])),
+        ),
+        (
+            "const Mx = class implements Foo { public static get p1() { return 1; } }",
+            Some(serde_json::json!(["fields", { "ignoreClassesWithImplements": true }])),
+        ),
+        (
+            "class Mx implements Foo, Bar { readonly p1 = 'hello world'; }",
+            Some(serde_json::json!(["getters", { "ignoreClassesWithImplements": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("class Mx { get p1() { return 'hello world'; } }", None),
+        ("class Mx { get p1() { return `hello world`; } }", None),
+        ("class Mx { static get p1() { return 'hello world'; } }", None),
+        ("class Mx { public static get foo() { return 1; } }", None),
+        ("class Mx { public get [myValue]() { return 'a literal value'; } }", None),
+        ("class Mx { public get [myValue]() { return 12345n; } }", None),
+        ("class Mx { protected get p1() { return 'hello world'; } }", None),
+        ("class Mx { public static get p1() { return 'hello world'; } }", None),
+        ("class Mx { private get p1() { return null; } }", None),
+        (
+            "
+            class Mx {
+              public get myValue() {
+                return gql`
+                  {
+                    user(id: 5) {
+                      firstName
+                      lastName
+                    }
+                  }
+                `;
+              }
+            }
+            ",
+            None,
+        ),
+        (
+            "class Mx { public readonly [myValue] = 'a literal value'; }",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "class Mx { readonly p1 = 'hello world'; set p1(value) {} }",
+            Some(serde_json::json!(["getters"])),
+        ),
+        ("class Mx { readonly p1 = 'hello world'; }", Some(serde_json::json!(["getters"]))),
+        ("class Mx { readonly p1 = `hello world`; }", Some(serde_json::json!(["getters"]))),
+        ("class Mx { static readonly p1 = 'hello world'; }", Some(serde_json::json!(["getters"]))),
+        (
+            "class Mx { protected readonly p1 = 'hello world'; }",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "class Mx { public static readonly p1 = 'hello world'; }",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "
+            class Mx {
+              public readonly myValue = gql`
+                {
+                  user(id: 5) {
+                    firstName
+                    lastName
+                  }
+                }
+              `;
+            }
+            ",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "
+            class A {
+              private readonly foo: string = 'bar';
+              constructor(foo: string) {
+                const bar = new (class {
+                  private readonly foo: string = 'baz';
+                  constructor() {
+                    this.foo = 'qux';
+                  }
+                })();
+              }
+            }
+            ",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "
+            class A {
+              private readonly ['foo']: string = 'bar';
+              constructor(foo: string) {
+                const bar = new (class {
+                  private readonly foo: string = 'baz';
+                  constructor() {}
+                })();
+
+                if (bar) {
+                  this.foo = 'baz';
+                }
+              }
+            }
+            ",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "
+            class A {
+              private readonly foo: string = 'bar';
+              constructor(foo: string) {
+                function func() {
+                  this.foo = 'aa';
+                }
+              }
+            }
+            ",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "class Mx implements Foo { get p1() { return 'hello world'; } }",
+            Some(serde_json::json!(["fields", { "ignoreClassesWithImplements": false }])),
+        ),
+        (
+            "class Mx extends Foo { get p1() { return 'hello world'; } }",
+            Some(serde_json::json!(["fields", { "ignoreClassesWithImplements": true }])),
+        ),
+        (
+            "class Mx implements Foo { readonly p1 = 'hello world'; }",
+            Some(serde_json::json!(["getters"])),
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "class Mx { get p1() { return 'hello world'; } }",
+            "class Mx { readonly p1 = 'hello world'; }",
+            None,
+        ),
+        (
+            "class Mx { get p1() { return `hello world`; } }",
+            "class Mx { readonly p1 = `hello world`; }",
+            None,
+        ),
+        (
+            "class Mx { static get p1() { return 'hello world'; } }",
+            "class Mx { static readonly p1 = 'hello world'; }",
+            None,
+        ),
+        (
+            "class Mx { public static get foo() { return 1; } }",
+            "class Mx { public static readonly foo = 1; }",
+            None,
+        ),
+        (
+            "class Mx { public get [myValue]() { return 'a literal value'; } }",
+            "class Mx { public readonly [myValue] = 'a literal value'; }",
+            None,
+        ),
+        (
+            "class Mx { public get [myValue]() { return 12345n; } }",
+            "class Mx { public readonly [myValue] = 12345n; }",
+            None,
+        ),
+        (
+            "class Mx { protected get p1() { return 'hello world'; } }",
+            "class Mx { protected readonly p1 = 'hello world'; }",
+            None,
+        ),
+        (
+            "class Mx { private get p1() { return null; } }",
+            "class Mx { private readonly p1 = null; }",
+            None,
+        ),
+        (
+            "class Mx { public get myValue() { return gql`{ user(id: 5) { firstName } }`; } }",
+            "class Mx { public readonly myValue = gql`{ user(id: 5) { firstName } }`; }",
+            None,
+        ),
+        (
+            "class Mx { readonly p1 = 'hello world'; }",
+            "class Mx { get p1() { return 'hello world'; } }",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "class Mx { readonly p1 = `hello world`; }",
+            "class Mx { get p1() { return `hello world`; } }",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "class Mx { static readonly p1 = 'hello world'; }",
+            "class Mx { static get p1() { return 'hello world'; } }",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "class Mx { protected readonly p1 = 'hello world'; }",
+            "class Mx { protected get p1() { return 'hello world'; } }",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "class Mx { public static readonly p1 = 'hello world'; }",
+            "class Mx { public static get p1() { return 'hello world'; } }",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "class Mx { public readonly [myValue] = 'a literal value'; }",
+            "class Mx { public get [myValue]() { return 'a literal value'; } }",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "class A { private readonly foo: string = 'bar'; }",
+            "class A { private get foo() { return 'bar'; } }",
+            Some(serde_json::json!(["getters"])),
+        ),
+        (
+            "class Mx { readonly p1 = 'hello world'; set p1(value) {} }",
+            "class Mx { readonly p1 = 'hello world'; set p1(value) {} }",
+            Some(serde_json::json!(["getters"])),
+        ),
+    ];
+
+    Tester::new(ClassLiteralPropertyStyle::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
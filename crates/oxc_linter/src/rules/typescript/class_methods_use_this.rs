@@ -0,0 +1,343 @@
+use oxc_ast::{
+    ast::{
+        Class, ClassElement, Expression, FormalParameters, Function, FunctionBody,
+        MethodDefinitionKind, PropertyKey, Super, TSAccessibility, ThisExpression,
+    },
+    AstKind, Visit,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, GetSpan, Span};
+use oxc_syntax::scope::ScopeFlags;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn class_methods_use_this_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Expected 'this' to be used by class {x0}."))
+        .with_help("Make this method static, or move it out of the class.")
+        .with_label(span1)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ClassMethodsUseThis(Box<ClassMethodsUseThisConfig>);
+
+#[derive(Debug, Clone)]
+pub struct ClassMethodsUseThisConfig {
+    except_methods: Vec<CompactStr>,
+    enforce_for_class_fields: bool,
+    ignore_override_methods: bool,
+    ignore_classes_that_implement_an_interface: IgnoreImplements,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+enum IgnoreImplements {
+    #[default]
+    Never,
+    Always,
+    PublicFields,
+}
+
+impl std::ops::Deref for ClassMethodsUseThis {
+    type Target = ClassMethodsUseThisConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for ClassMethodsUseThisConfig {
+    fn default() -> Self {
+        Self {
+            except_methods: vec![],
+            enforce_for_class_fields: true,
+            ignore_override_methods: false,
+            ignore_classes_that_implement_an_interface: IgnoreImplements::Never,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that class methods utilize `this`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// If a class method does not use `this`, it can sometimes be made into a static function.
+    /// If you do convert the method into a static function, instances of the class that call
+    /// that particular method have to be converted to a static call as well.
+    ///
+    /// ### Example
+    /// ```ts
+    /// class A {
+    ///   foo() {
+    ///     console.log('Hello World');
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `exceptMethods`: `string[]` (default `[]`) method names to ignore, e.g. `["foo", "#bar"]`.
+    /// - `enforceForClassFields`: `boolean` (default `true`) also checks class fields initialized
+    ///   with a function or arrow function.
+    /// - `ignoreOverrideMethods`: `boolean` (default `false`) ignores members marked `override`.
+    /// - `ignoreClassesThatImplementAnInterface`: `boolean | "public-fields"` (default `false`)
+    ///   ignores all members of classes that implement an interface, or only their public members.
+    ClassMethodsUseThis,
+    restriction
+);
+
+impl Rule for ClassMethodsUseThis {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let obj = value.get(0);
+        let except_methods = obj
+            .and_then(|v| v.get("exceptMethods"))
+            .and_then(serde_json::Value::as_array)
+            .map(|names| names.iter().filter_map(serde_json::Value::as_str).map(CompactStr::from))
+            .map(Iterator::collect)
+            .unwrap_or_default();
+
+        Self(Box::new(ClassMethodsUseThisConfig {
+            except_methods,
+            enforce_for_class_fields: obj
+                .and_then(|v| v.get("enforceForClassFields"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true),
+            ignore_override_methods: obj
+                .and_then(|v| v.get("ignoreOverrideMethods"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+            ignore_classes_that_implement_an_interface: match obj
+                .and_then(|v| v.get("ignoreClassesThatImplementAnInterface"))
+            {
+                Some(serde_json::Value::Bool(true)) => IgnoreImplements::Always,
+                Some(serde_json::Value::String(value)) if value == "public-fields" => {
+                    IgnoreImplements::PublicFields
+                }
+                _ => IgnoreImplements::Never,
+            },
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::Class(class) = node.kind() else {
+            return;
+        };
+        let implements_interface = class.implements.as_ref().is_some_and(|list| !list.is_empty());
+        if implements_interface
+            && self.ignore_classes_that_implement_an_interface == IgnoreImplements::Always
+        {
+            return;
+        }
+
+        for element in &class.body.body {
+            let (key, kind, accessibility, uses_this) = match element {
+                ClassElement::MethodDefinition(def) => {
+                    if def.r#static
+                        || def.kind == MethodDefinitionKind::Constructor
+                        || (self.ignore_override_methods && def.r#override)
+                    {
+                        continue;
+                    }
+                    let Some(uses_this) = function_uses_this(&def.value) else {
+                        continue;
+                    };
+                    let kind = match def.kind {
+                        MethodDefinitionKind::Get => "getter",
+                        MethodDefinitionKind::Set => "setter",
+                        _ => "method",
+                    };
+                    (&def.key, kind, def.accessibility, uses_this)
+                }
+                ClassElement::PropertyDefinition(def) if self.enforce_for_class_fields => {
+                    if def.r#static || (self.ignore_override_methods && def.r#override) {
+                        continue;
+                    }
+                    let uses_this = match &def.value {
+                        Some(Expression::FunctionExpression(func)) => {
+                            let Some(uses_this) = function_uses_this(func) else {
+                                continue;
+                            };
+                            uses_this
+                        }
+                        Some(Expression::ArrowFunctionExpression(arrow)) => {
+                            uses_this(&arrow.params, &arrow.body)
+                        }
+                        _ => continue,
+                    };
+                    (&def.key, "method", def.accessibility, uses_this)
+                }
+                _ => continue,
+            };
+            if uses_this {
+                continue;
+            }
+
+            let is_private = matches!(key, PropertyKey::PrivateIdentifier(_));
+            if implements_interface
+                && self.ignore_classes_that_implement_an_interface == IgnoreImplements::PublicFields
+                && !is_private
+                && matches!(accessibility, None | Some(TSAccessibility::Public))
+            {
+                continue;
+            }
+
+            let name = match key {
+                PropertyKey::PrivateIdentifier(id) => Some(format!("#{}", id.name)),
+                _ => key.static_name().map(|name| name.to_string()),
+            };
+            if name.as_ref().is_some_and(|name| self.except_methods.iter().any(|m| m == name)) {
+                continue;
+            }
+
+            let description = match (&name, is_private) {
+                (Some(name), true) => format!("private {kind} {name}"),
+                (Some(name), false) => format!("{kind} '{name}'"),
+                (None, _) => kind.to_string(),
+            };
+            ctx.diagnostic(class_methods_use_this_diagnostic(&description, key.span()));
+        }
+    }
+}
+
+/// Returns `None` for functions without a body, e.g. abstract methods and overload signatures.
+fn function_uses_this(func: &Function) -> Option<bool> {
+    func.body.as_ref().map(|body| uses_this(&func.params, body))
+}
+
+fn uses_this(params: &FormalParameters, body: &FunctionBody) -> bool {
+    let mut finder = ThisFinder { found: false };
+    finder.visit_formal_parameters(params);
+    finder.visit_function_body(body);
+    finder.found
+}
+
+/// Finds `this` and `super` that refer to the instance, i.e. not inside nested functions or
+/// classes. Arrow functions are searched since they share the enclosing method's `this`.
+struct ThisFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for ThisFinder {
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.found = true;
+    }
+
+    fn visit_super(&mut self, _it: &Super) {
+        self.found = true;
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, it: &Class<'a>) {
+        // Only the heritage clause is evaluated with the enclosing `this`
+        if let Some(super_class) = &it.super_class {
+            self.visit_expression(super_class);
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("class A { constructor() {} }", None),
+        ("class A { foo() {this} }", None),
+        ("class A { foo() {this.bar = 'bar';} }", None),
+        ("class A { foo() {bar(this);} }", None),
+        ("class A extends B { foo() {super.foo();} }", None),
+        ("class A { foo() { if(true) { return this; } } }", None),
+        ("class A { static foo() {} }", None),
+        ("({ a(){} });", None),
+        ("class A { foo() { () => this; } }", None),
+        ("({ a: function () {} });", None),
+        ("class A { foo(a = this.bar) {} }", None),
+        ("class A { foo() {} }", Some(serde_json::json!([{ "exceptMethods": ["foo"] }]))),
+        ("class A { #bar() {} }", Some(serde_json::json!([{ "exceptMethods": ["#bar"] }]))),
+        (
+            "class A { foo = function () {} }",
+            Some(serde_json::json!([{ "enforceForClassFields": false }])),
+        ),
+        (
+            "class A { foo = () => {} }",
+            Some(serde_json::json!([{ "enforceForClassFields": false }])),
+        ),
+        ("class A { foo() { return class extends this.Base {}; } }", None),
+        ("class A { foo = () => this; }", None),
+        ("class A { foo = function () { this; }; }", None),
+        ("class A { static foo = () => {}; }", None),
+        ("class A { bar = 1; }", None),
+        ("abstract class A { abstract foo(): void; }", None),
+        ("class A { foo(): void; foo(a?: string) { this; } }", None),
+        (
+            "class A { override foo() {} }",
+            Some(serde_json::json!([{ "ignoreOverrideMethods": true }])),
+        ),
+        (
+            "class A { override foo = () => {}; }",
+            Some(serde_json::json!([{ "ignoreOverrideMethods": true }])),
+        ),
+        (
+            "class A implements B { foo() {} }",
+            Some(serde_json::json!([{ "ignoreClassesThatImplementAnInterface": true }])),
+        ),
+        (
+            "class A implements B { private foo() {} }",
+            Some(serde_json::json!([{ "ignoreClassesThatImplementAnInterface": true }])),
+        ),
+        (
+            "class A implements B { foo() {} }",
+            Some(serde_json::json!([{ "ignoreClassesThatImplementAnInterface": "public-fields" }])),
+        ),
+        (
+            "class A implements B { public foo = () => {}; }",
+            Some(serde_json::json!([{ "ignoreClassesThatImplementAnInterface": "public-fields" }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("class A { foo() {} }", None),
+        ("class A { foo() {/**this**/} }", None),
+        ("class A { foo() {var a = function () {this};} }", None),
+        ("class A { foo() {var a = function () {var b = function(){this}};} }", None),
+        ("class A { foo() {window.this} }", None),
+        ("class A { foo() {that.this = 'this';} }", None),
+        ("class A { foo() { return class { bar() { this; } }; } }", None),
+        ("class A { foo() {} bar() {} }", Some(serde_json::json!([{ "exceptMethods": ["bar"] }]))),
+        (
+            "class A { foo() {} hasOwnProperty() {} }",
+            Some(serde_json::json!([{ "exceptMethods": ["foo"] }])),
+        ),
+        ("class A { [foo]() {} }", Some(serde_json::json!([{ "exceptMethods": ["foo"] }]))),
+        ("class A { #foo() {} }", Some(serde_json::json!([{ "exceptMethods": ["foo"] }]))),
+        ("class A { get foo() {} set foo(a) {} }", None),
+        ("class A { #foo() {} get #bar() {} }", None),
+        ("class A { foo = function () {} }", None),
+        ("class A { foo = () => {} }", None),
+        ("class A { #foo = () => {} }", None),
+        ("class A { ['foo'] = () => {} }", None),
+        ("class A { foo = () => { function bar() { this; } } }", None),
+        ("class A { override foo() {} }", None),
+        ("class A implements B { foo() {} }", None),
+        (
+            "class A implements B { private foo() {} }",
+            Some(serde_json::json!([{ "ignoreClassesThatImplementAnInterface": "public-fields" }])),
+        ),
+        (
+            "class A implements B { protected foo = () => {}; }",
+            Some(serde_json::json!([{ "ignoreClassesThatImplementAnInterface": "public-fields" }])),
+        ),
+        (
+            "class A implements B { #foo() {} }",
+            Some(serde_json::json!([{ "ignoreClassesThatImplementAnInterface": "public-fields" }])),
+        ),
+        (
+            "class A { override foo() {} }",
+            Some(serde_json::json!([{ "ignoreClassesThatImplementAnInterface": true }])),
+        ),
+    ];
+
+    Tester::new(ClassMethodsUseThis::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,10 +1,11 @@
 use oxc_ast::{
-    ast::{TSSignature, TSType, TSTypeName},
-    AstKind,
+    ast::{TSIndexSignature, TSSignature, TSType, TSTypeName, TSTypeReference},
+    visit::walk::walk_ts_type_reference,
+    AstKind, Visit,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -72,137 +73,22 @@ impl Rule for ConsistentIndexedObjectStyle {
         if self.is_record_mode {
             match node.kind() {
                 AstKind::TSInterfaceDeclaration(inf) => {
-                    if inf.body.body.len() > 1 {
-                        return;
-                    }
-                    let member = inf.body.body.first();
-                    let Some(member) = member else {
-                        return;
-                    };
-
-                    let TSSignature::TSIndexSignature(sig) = member else { return };
-
-                    match &sig.type_annotation.type_annotation {
-                        TSType::TSTypeReference(r) => match &r.type_name {
-                            TSTypeName::IdentifierReference(ide) => {
-                                if ide.name != inf.id.name {
-                                    ctx.diagnostic(consistent_indexed_object_style_diagnostic(
-                                        "record",
-                                        "index signature",
-                                        sig.span,
-                                    ));
-                                }
-                            }
-                            TSTypeName::QualifiedName(_) => {
-                                ctx.diagnostic(consistent_indexed_object_style_diagnostic(
-                                    "record",
-                                    "index signature",
-                                    sig.span,
-                                ));
-                            }
-                        },
-                        TSType::TSUnionType(uni) => {
-                            for t in &uni.types {
-                                if let TSType::TSTypeReference(tref) = t {
-                                    if let TSTypeName::IdentifierReference(ide) = &tref.type_name {
-                                        let Some(AstKind::TSTypeAliasDeclaration(dec)) =
-                                            ctx.nodes().parent_kind(node.id())
-                                        else {
-                                            return;
-                                        };
-
-                                        if dec.id.name != ide.name {
-                                            ctx.diagnostic(
-                                                consistent_indexed_object_style_diagnostic(
-                                                    "record",
-                                                    "index signature",
-                                                    sig.span,
-                                                ),
-                                            );
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                        _ => {
-                            ctx.diagnostic(consistent_indexed_object_style_diagnostic(
-                                "record",
-                                "index signature",
-                                sig.span,
-                            ));
-                        }
-                    }
+                    let type_parameters = inf
+                        .type_parameters
+                        .as_ref()
+                        .map_or("", |params| ctx.source_range(params.span));
+                    check_members(
+                        &inf.body.body,
+                        inf.span,
+                        Some(&inf.id.name),
+                        (&format!("type {}{type_parameters} = ", inf.id.name), ";"),
+                        inf.extends.as_ref().map_or(true, |extends| extends.is_empty()),
+                        ctx,
+                    );
                 }
                 AstKind::TSTypeLiteral(lit) => {
-                    if lit.members.len() > 1 {
-                        return;
-                    }
-
-                    let Some(TSSignature::TSIndexSignature(sig)) = lit.members.first() else {
-                        return;
-                    };
-
-                    match &sig.type_annotation.type_annotation {
-                        TSType::TSTypeReference(r) => match &r.type_name {
-                            TSTypeName::IdentifierReference(ide) => {
-                                let Some(parent) = ctx.nodes().parent_kind(node.id()) else {
-                                    return;
-                                };
-
-                                let parent_name =
-                                    if let AstKind::TSTypeAliasDeclaration(dec) = parent {
-                                        &dec.id.name
-                                    } else {
-                                        return;
-                                    };
-
-                                if ide.name != parent_name {
-                                    ctx.diagnostic(consistent_indexed_object_style_diagnostic(
-                                        "record",
-                                        "index signature",
-                                        sig.span,
-                                    ));
-                                }
-                            }
-                            TSTypeName::QualifiedName(_) => {
-                                ctx.diagnostic(consistent_indexed_object_style_diagnostic(
-                                    "record",
-                                    "index signature",
-                                    sig.span,
-                                ));
-                            }
-                        },
-                        TSType::TSUnionType(uni) => {
-                            for t in &uni.types {
-                                if let TSType::TSTypeReference(tref) = t {
-                                    if let TSTypeName::IdentifierReference(ide) = &tref.type_name {
-                                        let Some(AstKind::TSTypeAliasDeclaration(dec)) =
-                                            ctx.nodes().parent_kind(node.id())
-                                        else {
-                                            return;
-                                        };
-
-                                        if dec.id.name != ide.name {
-                                            ctx.diagnostic(
-                                                consistent_indexed_object_style_diagnostic(
-                                                    "record",
-                                                    "index signature",
-                                                    sig.span,
-                                                ),
-                                            );
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                        _ => {
-                            ctx.diagnostic(consistent_indexed_object_style_diagnostic(
-                                "record",
-                                "index signature",
-                                sig.span,
-                            ));
-                        }
-                    }
+                    let parent_name = find_parent_type_alias_name(node, ctx);
+                    check_members(&lit.members, lit.span, parent_name, ("", ""), true, ctx);
                 }
                 _ => {}
             }
@@ -250,11 +136,133 @@ impl Rule for ConsistentIndexedObjectStyle {
     }
 }
 
+/// Reports a single index signature in `members`, fixing `span` to `{prefix}Record<K, V>{postfix}`.
+/// Members referring to the type they are declared in are skipped, since a `Record` can't be
+/// recursive.
+fn check_members(
+    members: &[TSSignature],
+    span: Span,
+    parent_name: Option<&str>,
+    (prefix, postfix): (&str, &str),
+    safe_fix: bool,
+    ctx: &LintContext,
+) {
+    let [TSSignature::TSIndexSignature(sig)] = members else {
+        return;
+    };
+    let [parameter] = sig.parameters.as_slice() else {
+        return;
+    };
+    if parent_name.is_some_and(|name| is_referencing_type(sig, name)) {
+        return;
+    }
+
+    let diagnostic =
+        consistent_indexed_object_style_diagnostic("record", "index signature", sig.span);
+    if !safe_fix {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+        let key = fixer.source_range(parameter.type_annotation.type_annotation.span());
+        let value = fixer.source_range(sig.type_annotation.type_annotation.span());
+        let record = if sig.readonly {
+            format!("Readonly<Record<{key}, {value}>>")
+        } else {
+            format!("Record<{key}, {value}>")
+        };
+        fixer.replace(span, format!("{prefix}{record}{postfix}"))
+    });
+}
+
+/// Finds the type alias a type literal is declared in, e.g. `Foo` in
+/// `type Foo = Generic<{ [key: string]: Foo }>`. Type annotations are not looked through, so a
+/// literal nested in another index signature has no parent alias.
+fn find_parent_type_alias_name<'a>(node: &AstNode, ctx: &LintContext<'a>) -> Option<&'a str> {
+    for parent in ctx.nodes().iter_parents(node.id()).skip(1) {
+        match parent.kind() {
+            AstKind::TSTypeAliasDeclaration(alias) => return Some(alias.id.name.as_str()),
+            AstKind::TSTypeAnnotation(_) => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn is_referencing_type(sig: &TSIndexSignature, name: &str) -> bool {
+    let mut finder = TypeReferenceFinder { name, found: false };
+    finder.visit_ts_index_signature(sig);
+    finder.found
+}
+
+struct TypeReferenceFinder<'n> {
+    name: &'n str,
+    found: bool,
+}
+
+impl<'a, 'n> Visit<'a> for TypeReferenceFinder<'n> {
+    fn visit_ts_type_reference(&mut self, it: &TSTypeReference<'a>) {
+        if matches!(&it.type_name, TSTypeName::IdentifierReference(ident) if ident.name == self.name)
+        {
+            self.found = true;
+            return;
+        }
+        walk_ts_type_reference(self, it);
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
 
     let fix = vec![
+        ("interface Foo { [key: string]: any; }", "type Foo = Record<string, any>;", None),
+        (
+            "interface Foo { readonly [key: string]: any; }",
+            "type Foo = Readonly<Record<string, any>>;",
+            None,
+        ),
+        (
+            "interface Foo<A = any> { [key: string]: A; }",
+            "type Foo<A = any> = Record<string, A>;",
+            None,
+        ),
+        (
+            "export interface Foo<A, B> { [key: A]: B; }",
+            "export type Foo<A, B> = Record<A, B>;",
+            None,
+        ),
+        (
+            "interface B extends A { [index: number]: unknown; }",
+            "interface B extends A { [index: number]: unknown; }",
+            None,
+        ),
+        ("type Foo = { [key: string]: any };", "type Foo = Record<string, any>;", None),
+        (
+            "type Foo = { readonly [key: string]: any };",
+            "type Foo = Readonly<Record<string, any>>;",
+            None,
+        ),
+        (
+            "type Foo = Generic<{ [key: boolean]: any }>;",
+            "type Foo = Generic<Record<boolean, any>>;",
+            None,
+        ),
+        (
+            "function foo(arg: { [key: string]: any }) {}",
+            "function foo(arg: Record<string, any>) {}",
+            None,
+        ),
+        (
+            "type Foo = { [key: string]: string } | Foo;",
+            "type Foo = Record<string, string> | Foo;",
+            None,
+        ),
+        (
+            "type Foo = { [key: string]: { [key: string]: Foo } };",
+            "type Foo = { [key: string]: Record<string, Foo> };",
+            None,
+        ),
         (
             "type Foo = Record<string, any>;",
             "type Foo = { [key: string]: any };",
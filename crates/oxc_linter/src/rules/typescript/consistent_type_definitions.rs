@@ -1,5 +1,5 @@
 use oxc_ast::{
-    ast::{ExportDefaultDeclarationKind, TSType},
+    ast::{ExportDefaultDeclarationKind, TSModuleDeclarationKind, TSType},
     AstKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
@@ -65,8 +65,11 @@ impl Rule for ConsistentTypeDefinitions {
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
-            AstKind::TSTypeAliasDeclaration(decl) => match &decl.type_annotation {
-                TSType::TSTypeLiteral(_)
+            AstKind::TSTypeAliasDeclaration(decl) => match decl
+                .type_annotation
+                .without_parenthesized()
+            {
+                TSType::TSTypeLiteral(type_ann)
                     if self.config == ConsistentTypeDefinitionsConfig::Interface =>
                 {
                     let start = if decl.declare { decl.span.start + 8 } else { decl.span.start };
@@ -81,25 +84,22 @@ impl Rule for ConsistentTypeDefinitions {
                     let name =
                         &ctx.source_text()[*name_span_start as usize..*name_span_end as usize];
 
-                    if let TSType::TSTypeLiteral(type_ann) = &decl.type_annotation {
-                        let body_span = type_ann.span;
-                        let body =
-                            &ctx.source_text()[body_span.start as usize..body_span.end as usize];
-
-                        ctx.diagnostic_with_fix(
-                            consistent_type_definitions_diagnostic(
-                                "interface",
-                                "type",
-                                Span::new(start, start + 4),
-                            ),
-                            |fixer| {
-                                fixer.replace(
-                                    Span::new(start, decl.span.end),
-                                    format!("interface {name} {body}"),
-                                )
-                            },
-                        );
-                    }
+                    let body_span = type_ann.span;
+                    let body = &ctx.source_text()[body_span.start as usize..body_span.end as usize];
+
+                    ctx.diagnostic_with_fix(
+                        consistent_type_definitions_diagnostic(
+                            "interface",
+                            "type",
+                            Span::new(start, start + 4),
+                        ),
+                        |fixer| {
+                            fixer.replace(
+                                Span::new(start, decl.span.end),
+                                format!("interface {name} {body}"),
+                            )
+                        },
+                    );
                 }
                 _ => {}
             },
@@ -197,19 +197,24 @@ impl Rule for ConsistentTypeDefinitions {
                     format!(" & {joined_extends}")
                 };
 
-                ctx.diagnostic_with_fix(
-                    consistent_type_definitions_diagnostic(
-                        "type",
-                        "interface",
-                        Span::new(start, start + 9),
-                    ),
-                    |fixer| {
-                        fixer.replace(
-                            Span::new(start, decl.span.end),
-                            format!("type {name} = {body}{extends}"),
-                        )
-                    },
+                let diagnostic = consistent_type_definitions_diagnostic(
+                    "type",
+                    "interface",
+                    Span::new(start, start + 9),
                 );
+
+                // Global augmentations can only be done with interfaces.
+                if is_within_declare_global(node, ctx) {
+                    ctx.diagnostic(diagnostic);
+                    return;
+                }
+
+                ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                    fixer.replace(
+                        Span::new(start, decl.span.end),
+                        format!("type {name} = {body}{extends}"),
+                    )
+                });
             }
             _ => {}
         }
@@ -220,6 +225,16 @@ impl Rule for ConsistentTypeDefinitions {
     }
 }
 
+fn is_within_declare_global(node: &AstNode, ctx: &LintContext) -> bool {
+    ctx.nodes().iter_parents(node.id()).any(|parent| {
+        matches!(
+            parent.kind(),
+            AstKind::TSModuleDeclaration(module)
+                if module.declare && module.kind == TSModuleDeclarationKind::Global
+        )
+    })
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -261,6 +276,7 @@ fn test() {
     let fail = vec![
         ("type T = { x: number; };", Some(serde_json::json!(["interface"]))),
         ("type T={ x: number; };", Some(serde_json::json!(["interface"]))),
+        ("type T = ({ x: number; });", Some(serde_json::json!(["interface"]))),
         ("type T=                         { x: number; };", Some(serde_json::json!(["interface"]))),
         (
             "
@@ -368,6 +384,21 @@ fn test() {
             "interface T { x: number; }",
             Some(serde_json::json!(["interface"])),
         ),
+        (
+            "type T = ({ x: number; });",
+            "interface T { x: number; }",
+            Some(serde_json::json!(["interface"])),
+        ),
+        (
+            "declare global { interface Array<T> { foo(x: (x: number) => T): T[]; } }",
+            "declare global { interface Array<T> { foo(x: (x: number) => T): T[]; } }",
+            Some(serde_json::json!(["type"])),
+        ),
+        (
+            "declare global { namespace Foo { interface Bar {} } }",
+            "declare global { namespace Foo { interface Bar {} } }",
+            Some(serde_json::json!(["type"])),
+        ),
         (
             "export type W<T> = {
             x: T;
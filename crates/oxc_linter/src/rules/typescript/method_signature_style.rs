@@ -0,0 +1,355 @@
+use oxc_ast::{
+    ast::{
+        FormalParameters, PropertyKey, TSMethodSignature, TSMethodSignatureKind, TSSignature,
+        TSType, TSTypeAnnotation, TSTypeParameterDeclaration,
+    },
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn error_method_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Shorthand method signature is forbidden. Use a function property instead.")
+        .with_help("Replace the method signature with a function property.")
+        .with_label(span0)
+}
+
+fn error_property_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Function property signature is forbidden. Use a method shorthand instead.")
+        .with_help("Replace the function property with a method signature.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct MethodSignatureStyle {
+    mode: Mode,
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+enum Mode {
+    #[default]
+    Property,
+    Method,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce using a particular method signature syntax.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// TypeScript provides two ways to define an object/interface function property:
+    /// a method shorthand (`func(arg: string): number;`) and a function property
+    /// (`func: (arg: string) => number;`). Function properties are checked more strictly when
+    /// `strictFunctionTypes` is enabled, while methods are always checked bivariantly.
+    ///
+    /// ### Example
+    /// ```ts
+    /// // incorrect, when set to "property"
+    /// interface T1 {
+    ///   func(arg: string): number;
+    /// }
+    ///
+    /// // incorrect, when set to "method"
+    /// interface T2 {
+    ///   func: (arg: string) => number;
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `"property"` (default) or `"method"`.
+    MethodSignatureStyle,
+    style
+);
+
+impl Rule for MethodSignatureStyle {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mode = match value.get(0).and_then(serde_json::Value::as_str) {
+            Some("method") => Mode::Method,
+            _ => Mode::Property,
+        };
+        Self { mode }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::TSMethodSignature(method) if self.mode == Mode::Property => {
+                if method.kind != TSMethodSignatureKind::Method {
+                    return;
+                }
+                check_method_signature(method, node, ctx);
+            }
+            AstKind::TSPropertySignature(property) if self.mode == Mode::Method => {
+                let Some(TSType::TSFunctionType(function_type)) =
+                    property.type_annotation.as_ref().map(|ann| &ann.type_annotation)
+                else {
+                    return;
+                };
+                ctx.diagnostic_with_fix(error_property_diagnostic(property.span), |fixer| {
+                    let key =
+                        get_key_text(&property.key, property.computed, property.optional, ctx);
+                    let key = if property.readonly { format!("readonly {key}") } else { key };
+                    let params = get_params_text(
+                        function_type.type_parameters.as_deref(),
+                        &function_type.params,
+                        ctx,
+                    );
+                    let return_type = get_return_type_text(Some(&function_type.return_type), ctx);
+                    let delimiter = get_delimiter(property.span, ctx);
+                    fixer.replace(property.span, format!("{key}{params}: {return_type}{delimiter}"))
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+fn check_method_signature<'a>(
+    method: &TSMethodSignature<'a>,
+    node: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+) {
+    let key = get_key_text(&method.key, method.computed, method.optional, ctx);
+    let members: &[TSSignature] = match ctx.nodes().parent_kind(node.id()) {
+        Some(AstKind::TSInterfaceDeclaration(decl)) => &decl.body.body,
+        Some(AstKind::TSTypeLiteral(lit)) => &lit.members,
+        _ => &[],
+    };
+    // Overloads, merged into an intersection of function types by the fix
+    let overloads = members
+        .iter()
+        .filter_map(|member| match member {
+            TSSignature::TSMethodSignature(other)
+                if other.kind == TSMethodSignatureKind::Method
+                    && get_key_text(&other.key, other.computed, other.optional, ctx) == key =>
+            {
+                Some(&**other)
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Interfaces in modules may be merged with other declarations,
+    // which can't be done with a function property.
+    if is_in_module_declaration(node, ctx) {
+        ctx.diagnostic(error_method_diagnostic(method.span));
+        return;
+    }
+
+    let delimiter = get_delimiter(method.span, ctx);
+    if overloads.len() <= 1 {
+        ctx.diagnostic_with_fix(error_method_diagnostic(method.span), |fixer| {
+            let params = get_params_text(method.type_parameters.as_deref(), &method.params, ctx);
+            let return_type = get_return_type_text(method.return_type.as_deref(), ctx);
+            fixer.replace(method.span, format!("{key}: {params} => {return_type}{delimiter}"))
+        });
+        return;
+    }
+
+    ctx.diagnostic_with_fix(error_method_diagnostic(method.span), |fixer| {
+        let fixer = fixer.for_multifix();
+        let mut fix = fixer.new_fix_with_capacity(overloads.len());
+        let type_text = overloads
+            .iter()
+            .map(|overload| {
+                let params =
+                    get_params_text(overload.type_parameters.as_deref(), &overload.params, ctx);
+                let return_type = get_return_type_text(overload.return_type.as_deref(), ctx);
+                format!("({params} => {return_type})")
+            })
+            .collect::<Vec<_>>()
+            .join(" & ");
+        fix.push(fixer.replace(method.span, format!("{key}: {type_text}{delimiter}")));
+        for overload in overloads.iter().filter(|overload| overload.span != method.span) {
+            // remove the overload along with the whitespace following it
+            let rest = &ctx.source_text()[overload.span.end as usize..];
+            let whitespace = rest.len() - rest.trim_start().len();
+            #[allow(clippy::cast_possible_truncation)]
+            let end = overload.span.end + whitespace as u32;
+            fix.push(fixer.delete_range(Span::new(overload.span.start, end)));
+        }
+        fix
+    });
+}
+
+fn get_key_text(key: &PropertyKey, computed: bool, optional: bool, ctx: &LintContext) -> String {
+    let mut text = ctx.source_range(key.span()).to_string();
+    if computed {
+        text = format!("[{text}]");
+    }
+    if optional {
+        text.push('?');
+    }
+    text
+}
+
+fn get_params_text(
+    type_parameters: Option<&TSTypeParameterDeclaration>,
+    params: &FormalParameters,
+    ctx: &LintContext,
+) -> String {
+    let type_parameters = type_parameters.map_or("", |params| ctx.source_range(params.span));
+    format!("{type_parameters}{}", ctx.source_range(params.span))
+}
+
+fn get_return_type_text<'a>(
+    return_type: Option<&TSTypeAnnotation>,
+    ctx: &LintContext<'a>,
+) -> &'a str {
+    return_type.map_or("any", |return_type| ctx.source_range(return_type.type_annotation.span()))
+}
+
+/// The `;` or `,` ending a member, if the member's span includes it.
+fn get_delimiter(span: Span, ctx: &LintContext) -> &'static str {
+    match ctx.source_range(span).chars().last() {
+        Some(';') => ";",
+        Some(',') => ",",
+        _ => "",
+    }
+}
+
+fn is_in_module_declaration(node: &AstNode, ctx: &LintContext) -> bool {
+    ctx.nodes()
+        .iter_parents(node.id())
+        .any(|parent| matches!(parent.kind(), AstKind::TSModuleDeclaration(_)))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("interface Test { f: (a: string) => number; }", None),
+        ("interface Test { ['f']: (a: boolean) => void; }", None),
+        ("interface Test { f: <T>(a: T) => T; }", None),
+        ("interface Test { ['f']: <T extends {}>(a: T, b: T) => T; }", None),
+        ("interface Test { 'f!': </* a */ T>(/* b */ x: any /* c */) => void; }", None),
+        ("interface Test { get f(): number; }", None),
+        ("interface Test { set f(value: number); }", None),
+        ("type Test = { readonly f: (a: string) => number };", None),
+        ("type Test = { ['f']?: (a: boolean) => void };", None),
+        ("type Test = { readonly f?: <T>(a?: T) => T };", None),
+        ("type Test = { readonly ['f']?: <T>(a: T, b: T) => T };", None),
+        ("type Test = { get f(): number };", None),
+        ("type Test = { set f(value: number) };", None),
+        ("interface Test { f(a: string): number; }", Some(serde_json::json!(["method"]))),
+        ("interface Test { ['f'](a: boolean): void; }", Some(serde_json::json!(["method"]))),
+        ("interface Test { f<T>(a: T): T; }", Some(serde_json::json!(["method"]))),
+        ("type Test = { f(a: string): number };", Some(serde_json::json!(["method"]))),
+        ("type Test = { ['f']?(a: boolean): void };", Some(serde_json::json!(["method"]))),
+        ("interface Test { f: string; }", Some(serde_json::json!(["method"]))),
+    ];
+
+    let fail = vec![
+        ("interface Test { f(a: string): number; }", None),
+        ("interface Test { ['f'](a: boolean): void; }", None),
+        ("interface Test { f<T>(a: T): T; }", None),
+        ("interface Test { ['f']<T extends {}>(a: T, b: T): T; }", None),
+        ("interface Test { 'f!'</* a */ T>(/* b */ x: any /* c */): void; }", None),
+        ("type Test = { f(a: string): number };", None),
+        ("type Test = { ['f']?(a: boolean): void };", None),
+        ("type Test = { f?<T>(a?: T): T };", None),
+        ("type Test = { ['f']?<T>(a: T, b: T): T };", None),
+        ("interface Test { f(): void }", None),
+        (
+            "
+            interface Foo {
+              foo(): one;
+              foo(): two;
+              foo(): three;
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            declare global {
+              namespace jest {
+                interface Matchers<R> {
+                  toBeCloseTo(): void;
+                }
+              }
+            }
+            ",
+            None,
+        ),
+        ("interface Test { f: (a: string) => number; }", Some(serde_json::json!(["method"]))),
+        ("interface Test { ['f']: (a: boolean) => void; }", Some(serde_json::json!(["method"]))),
+        ("interface Test { f: <T>(a: T) => T; }", Some(serde_json::json!(["method"]))),
+        ("type Test = { f?: (a: string) => number };", Some(serde_json::json!(["method"]))),
+        ("type Test = { ['f']?: <T>(a: T) => void };", Some(serde_json::json!(["method"]))),
+    ];
+
+    let fix = vec![
+        (
+            "interface Test { f(a: string): number; }",
+            "interface Test { f: (a: string) => number; }",
+            None,
+        ),
+        (
+            "interface Test { ['f'](a: boolean): void; }",
+            "interface Test { ['f']: (a: boolean) => void; }",
+            None,
+        ),
+        ("interface Test { f<T>(a: T): T; }", "interface Test { f: <T>(a: T) => T; }", None),
+        (
+            "interface Test { ['f']<T extends {}>(a: T, b: T): T; }",
+            "interface Test { ['f']: <T extends {}>(a: T, b: T) => T; }",
+            None,
+        ),
+        (
+            "type Test = { ['f']?(a: boolean): void };",
+            "type Test = { ['f']?: (a: boolean) => void };",
+            None,
+        ),
+        ("type Test = { f?<T>(a?: T): T };", "type Test = { f?: <T>(a?: T) => T };", None),
+        ("interface Test { f(): void }", "interface Test { f: () => void }", None),
+        ("interface Test { f() }", "interface Test { f: () => any }", None),
+        (
+            "interface Foo { foo(): one; foo(): two; foo(): three; }",
+            "interface Foo { foo: (() => one) & (() => two) & (() => three); }",
+            None,
+        ),
+        (
+            "interface Foo { foo(bar: string): one; foo(bar: number): two; }",
+            "interface Foo { foo: ((bar: string) => one) & ((bar: number) => two); }",
+            None,
+        ),
+        (
+            "declare global { interface Foo { foo(): one; } }",
+            "declare global { interface Foo { foo(): one; } }",
+            None,
+        ),
+        (
+            "interface Test { f: (a: string) => number; }",
+            "interface Test { f(a: string): number; }",
+            Some(serde_json::json!(["method"])),
+        ),
+        (
+            "interface Test { ['f']: (a: boolean) => void; }",
+            "interface Test { ['f'](a: boolean): void; }",
+            Some(serde_json::json!(["method"])),
+        ),
+        (
+            "interface Test { f: <T>(a: T) => T; }",
+            "interface Test { f<T>(a: T): T; }",
+            Some(serde_json::json!(["method"])),
+        ),
+        (
+            "type Test = { f?: (a: string) => number };",
+            "type Test = { f?(a: string): number };",
+            Some(serde_json::json!(["method"])),
+        ),
+    ];
+
+    Tester::new(MethodSignatureStyle::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -4,7 +4,8 @@ use oxc_ast::{
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -64,30 +65,51 @@ fn get_depth_ends_in_bang(expr: &Expression<'_>) -> Option<u32> {
 impl Rule for NoConfusingNonNullAssertion {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
-            AstKind::BinaryExpression(binary_expr) => {
+            AstKind::BinaryExpression(binary_expr)
+                if matches!(
+                    binary_expr.operator,
+                    BinaryOperator::Equality | BinaryOperator::StrictEquality
+                ) =>
+            {
                 let Some(bang_depth) = get_depth_ends_in_bang(&binary_expr.left) else {
                     return;
                 };
+                let left_span = binary_expr.left.span();
                 if bang_depth == 0 {
-                    ctx.diagnostic(not_need_no_confusing_non_null_assertion_diagnostic(
-                        binary_expr.operator.as_str(),
-                        binary_expr.span,
-                    ));
+                    ctx.diagnostic_with_suggestion(
+                        not_need_no_confusing_non_null_assertion_diagnostic(
+                            binary_expr.operator.as_str(),
+                            binary_expr.span,
+                        ),
+                        |fixer| fixer.delete_range(Span::new(left_span.end - 1, left_span.end)),
+                    );
                 } else {
-                    ctx.diagnostic(wrap_up_no_confusing_non_null_assertion_diagnostic(
-                        binary_expr.span,
-                    ));
+                    ctx.diagnostic_with_suggestion(
+                        wrap_up_no_confusing_non_null_assertion_diagnostic(binary_expr.span),
+                        |fixer| {
+                            fixer.replace(left_span, format!("({})", fixer.source_range(left_span)))
+                        },
+                    );
                 }
             }
-            AstKind::AssignmentExpression(assignment_expr) => {
+            AstKind::AssignmentExpression(assignment_expr)
+                if assignment_expr.operator == AssignmentOperator::Assign =>
+            {
                 let Some(simple_target) = assignment_expr.left.as_simple_assignment_target() else {
                     return;
                 };
-                let SimpleAssignmentTarget::TSNonNullExpression(_) = simple_target else { return };
-                ctx.diagnostic(not_need_no_confusing_non_null_assertion_diagnostic(
-                    assignment_expr.operator.as_str(),
-                    assignment_expr.span,
-                ));
+                let SimpleAssignmentTarget::TSNonNullExpression(non_null_expr) = simple_target
+                else {
+                    return;
+                };
+                let end = non_null_expr.span.end;
+                ctx.diagnostic_with_suggestion(
+                    not_need_no_confusing_non_null_assertion_diagnostic(
+                        assignment_expr.operator.as_str(),
+                        assignment_expr.span,
+                    ),
+                    |fixer| fixer.delete_range(Span::new(end - 1, end)),
+                );
             }
             _ => {}
         }
@@ -102,7 +124,17 @@ impl Rule for NoConfusingNonNullAssertion {
 fn test() {
     use crate::tester::Tester;
 
-    let pass = vec!["a == b!;", "a = b!;", "a !== b;", "a != b;", "(a + b!) == c;"]; // "(a + b!) = c;"]; that's a parse error??
+    let pass = vec![
+        "a == b!;",
+        "a = b!;",
+        "a !== b;",
+        "a != b;",
+        "(a + b!) == c;",
+        "a! + b;",
+        "a! += b;",
+        "a! < b;",
+        "(a!) == b;",
+    ]; // "(a + b!) = c;"]; that's a parse error??
     let fail = vec![
         "a! == b;",
         "a! === b;",
@@ -113,10 +145,5 @@ fn test() {
         "(obj = new new OuterObj().InnerObj).Name! = c;",
         "(a=b)! =c;",
     ];
-    // let fix = vec![
-    //     // source, expected, rule_config?
-    //     // ("f = 1 + d! == 2", "f = (1 + d!) == 2", None), TODO: Add suggest or the weird ;() fix
-    //     // ("f =  d! == 2", "f = d == 2", None), TODO: Add suggest remove bang
-    // ];
     Tester::new(NoConfusingNonNullAssertion::NAME, pass, fail).test_and_snapshot();
 }
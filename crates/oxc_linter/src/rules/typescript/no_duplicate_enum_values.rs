@@ -25,9 +25,9 @@ declare_oxc_lint!(
     /// ### Example
     /// ```javascript
     /// enum E {
-    //    A = 0,
-    //    B = 0,
-    //  }
+    ///   A = 0,
+    ///   B = 0,
+    /// }
     /// ```
     NoDuplicateEnumValues,
     pedantic
@@ -56,8 +56,18 @@ impl Rule for NoDuplicateEnumValues {
                     }
                 }
                 Expression::StringLiteral(s) => {
-                    if let Some(old_span) = seen_string_values.insert(s.value.as_str(), s.span) {
-                        ctx.diagnostic(no_duplicate_enum_values_diagnostic(old_span, s.span));
+                    check_string_value(&mut seen_string_values, s.value.as_str(), s.span, ctx);
+                }
+                Expression::TemplateLiteral(template) if template.expressions.is_empty() => {
+                    if let Some(cooked) =
+                        template.quasis.first().and_then(|quasi| quasi.value.cooked.as_ref())
+                    {
+                        check_string_value(
+                            &mut seen_string_values,
+                            cooked.as_str(),
+                            template.span,
+                            ctx,
+                        );
                     }
                 }
                 _ => {}
@@ -70,6 +80,19 @@ impl Rule for NoDuplicateEnumValues {
     }
 }
 
+/// Reports `span` if `value` was already seen, keeping the first occurrence as the original.
+fn check_string_value<'a>(
+    seen_string_values: &mut FxHashMap<&'a str, Span>,
+    value: &'a str,
+    span: Span,
+    ctx: &LintContext,
+) {
+    let old_span = *seen_string_values.entry(value).or_insert(span);
+    if old_span != span {
+        ctx.diagnostic(no_duplicate_enum_values_diagnostic(old_span, span));
+    }
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -172,6 +195,15 @@ fn test() {
 			    ",
             None,
         ),
+        (
+            "
+			enum E {
+			  A = `A`,
+			  B = `${'A'}`,
+			}
+			    ",
+            None,
+        ),
     ];
 
     let fail = vec![
@@ -204,6 +236,25 @@ fn test() {
 			      ",
             None,
         ),
+        (
+            "
+			enum E {
+			  A = 'A',
+			  B = `A`,
+			}
+			      ",
+            None,
+        ),
+        (
+            "
+			enum E {
+			  A = 'A',
+			  B = 'A',
+			  C = 'A',
+			}
+			      ",
+            None,
+        ),
     ];
 
     Tester::new(NoDuplicateEnumValues::NAME, pass, fail).test_and_snapshot();
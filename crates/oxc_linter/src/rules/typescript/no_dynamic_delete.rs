@@ -42,10 +42,16 @@ impl Rule for NoDynamicDelete {
 
         let Expression::ComputedMemberExpression(computed_expr) = &expr.argument else { return };
         let inner_expression = computed_expr.expression.get_inner_expression();
-        if inner_expression.is_string_literal() || inner_expression.is_number_literal() {
+        if inner_expression.is_number_literal() {
             return;
         }
 
+        match &inner_expression {
+            Expression::StringLiteral(_) => return,
+            Expression::TemplateLiteral(template) if template.expressions.is_empty() => return,
+            _ => {}
+        }
+
         if let Expression::UnaryExpression(unary_expr) = &inner_expression {
             if unary_expr.operator == UnaryOperator::UnaryNegation
                 && unary_expr.argument.is_number_literal()
@@ -110,6 +116,10 @@ fn test() {
         	const container = {};
         	delete container[('aaa')]
         	    ",
+        "
+        	const container = {};
+        	delete container[`aaa`]
+        	    ",
     ];
 
     let fail = vec![
@@ -156,6 +166,11 @@ fn test() {
         	const container: { [i: string]: 0 } = {};
         	delete container[typeof 1];
         	      ",
+        "
+        	const container: { [i: string]: 0 } = {};
+        	const name = 'name';
+        	delete container[`${name}`];
+        	      ",
     ];
 
     Tester::new(NoDynamicDelete::NAME, pass, fail).test_and_snapshot();
@@ -1,10 +1,10 @@
-use oxc_ast::AstKind;
+use oxc_ast::{ast::TSInterfaceDeclaration, AstKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use serde_json::Value;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, fixer::RuleFixer, rule::Rule, AstNode};
 
 fn no_empty_interface_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("an empty interface is equivalent to `{}`").with_label(span0)
@@ -12,6 +12,7 @@ fn no_empty_interface_diagnostic(span0: Span) -> OxcDiagnostic {
 
 fn no_empty_interface_extend_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("an interface declaring no members is equivalent to its supertype")
+        .with_help("Replace the interface with a type alias.")
         .with_label(span0)
 }
 
@@ -36,6 +37,11 @@ declare_oxc_lint!(
     /// interface Foo {}
     /// interface Bar extends Foo {}
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// `allowSingleExtends`: `boolean` (default `false`) allows an empty interface that extends
+    /// a single interface.
     NoEmptyInterface,
     style
 );
@@ -44,7 +50,7 @@ impl Rule for NoEmptyInterface {
     fn from_configuration(value: Value) -> Self {
         let allow_single_extends =
             value.get(0).map_or(Self::default().allow_single_extends, |config| {
-                config.get("allow_single_extends").and_then(Value::as_bool).unwrap_or_default()
+                config.get("allowSingleExtends").and_then(Value::as_bool).unwrap_or_default()
             });
 
         Self { allow_single_extends }
@@ -59,8 +65,32 @@ impl Rule for NoEmptyInterface {
                     }
 
                     Some(extends) if extends.len() == 1 => {
-                        if !self.allow_single_extends {
-                            ctx.diagnostic(no_empty_interface_extend_diagnostic(interface.span));
+                        if self.allow_single_extends {
+                            return;
+                        }
+                        let diagnostic = no_empty_interface_extend_diagnostic(interface.span);
+                        let fix = |fixer: RuleFixer<'_, 'a>| {
+                            let type_parameters = interface
+                                .type_parameters
+                                .as_ref()
+                                .map_or("", |params| ctx.source_range(params.span));
+                            fixer.replace(
+                                interface.span,
+                                format!(
+                                    "{}type {}{} = {}",
+                                    if interface.declare { "declare " } else { "" },
+                                    interface.id.name,
+                                    type_parameters,
+                                    ctx.source_range(extends[0].span),
+                                ),
+                            )
+                        };
+                        // An interface merged with a class can't be turned into a type alias
+                        // without breaking the class declaration.
+                        if is_merged_with_class(interface, ctx) {
+                            ctx.diagnostic_with_suggestion(diagnostic, fix);
+                        } else {
+                            ctx.diagnostic_with_fix(diagnostic, fix);
                         }
                     }
                     _ => {}
@@ -74,6 +104,14 @@ impl Rule for NoEmptyInterface {
     }
 }
 
+fn is_merged_with_class(interface: &TSInterfaceDeclaration, ctx: &LintContext) -> bool {
+    interface
+        .id
+        .symbol_id
+        .get()
+        .is_some_and(|symbol_id| ctx.symbols().get_flag(symbol_id).is_class())
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -110,7 +148,7 @@ fn test() {
 
 			interface Bar extends Foo {}
 			      ",
-            Some(serde_json::json!([{ "allow_single_extends": true }])),
+            Some(serde_json::json!([{ "allowSingleExtends": true }])),
         ),
         (
             "
@@ -122,7 +160,7 @@ fn test() {
 
 			class Bar {}
 			      ",
-            Some(serde_json::json!([{ "allow_single_extends": true }])),
+            Some(serde_json::json!([{ "allowSingleExtends": true }])),
         ),
     ];
 
@@ -139,7 +177,7 @@ fn test() {
 
 			class Baz {}
 			      ",
-            Some(serde_json::json!([{ "allow_single_extends": false }])),
+            Some(serde_json::json!([{ "allowSingleExtends": false }])),
         ),
         (
             "
@@ -151,7 +189,7 @@ fn test() {
 
 			class Bar {}
 			      ",
-            Some(serde_json::json!([{ "allow_single_extends": false }])),
+            Some(serde_json::json!([{ "allowSingleExtends": false }])),
         ),
         (
             "
@@ -163,7 +201,7 @@ fn test() {
 
 			const bar = class Bar {};
 			      ",
-            Some(serde_json::json!([{ "allow_single_extends": false }])),
+            Some(serde_json::json!([{ "allowSingleExtends": false }])),
         ),
         (
             "
@@ -173,7 +211,7 @@ fn test() {
 
 			interface Bar extends Foo {}
 			      ",
-            Some(serde_json::json!([{ "allow_single_extends": false }])),
+            Some(serde_json::json!([{ "allowSingleExtends": false }])),
         ),
         ("interface Foo extends Array<number> {}", None),
         ("interface Foo extends Array<number | {}> {}", None),
@@ -210,5 +248,17 @@ fn test() {
         ),
     ];
 
-    Tester::new(NoEmptyInterface::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("interface Foo extends Bar {}", "type Foo = Bar", None),
+        ("interface Foo<T> extends Bar<T> {}", "type Foo<T> = Bar<T>", None),
+        ("export interface Foo extends Bar {}", "export type Foo = Bar", None),
+        ("declare interface Foo extends Bar {}", "declare type Foo = Bar", None),
+        (
+            "interface Foo extends Bar {}\nclass Foo {}",
+            "interface Foo extends Bar {}\nclass Foo {}",
+            None,
+        ),
+    ];
+
+    Tester::new(NoEmptyInterface::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -61,7 +61,10 @@ impl Rule for NoExtraNonNullAssertion {
 
         if let Some(expr) = expr {
             let end = expr.span.end - 1;
-            ctx.diagnostic(no_extra_non_null_assertion_diagnostic(Span::new(end, end)));
+            ctx.diagnostic_with_fix(
+                no_extra_non_null_assertion_diagnostic(Span::new(end, end)),
+                |fixer| fixer.delete_range(Span::new(end, expr.span.end)),
+            );
         }
     }
 
@@ -93,5 +96,43 @@ fn test() {
         "function foo(bar?: { n: number }) { return (bar!)?.(); }",
     ];
 
-    Tester::new(NoExtraNonNullAssertion::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (
+            "const foo: { bar: number } | null = null; const bar = foo!!.bar; ",
+            "const foo: { bar: number } | null = null; const bar = foo!.bar; ",
+            None,
+        ),
+        (
+            "function foo(bar: number | undefined) { const a: number = bar!!; }",
+            "function foo(bar: number | undefined) { const a: number = bar!; }",
+            None,
+        ),
+        (
+            "function foo(bar?: { n: number }) { return bar!?.n; }",
+            "function foo(bar?: { n: number }) { return bar?.n; }",
+            None,
+        ),
+        (
+            "function foo(bar?: { n: number }) { return bar!?.(); }",
+            "function foo(bar?: { n: number }) { return bar?.(); }",
+            None,
+        ),
+        (
+            "const foo: { bar: number } | null = null; const bar = (foo!)!.bar;",
+            "const foo: { bar: number } | null = null; const bar = (foo)!.bar;",
+            None,
+        ),
+        (
+            "function foo(bar?: { n: number }) { return (bar!)?.n; }",
+            "function foo(bar?: { n: number }) { return (bar)?.n; }",
+            None,
+        ),
+        (
+            "function foo(bar?: { n: number }) { return (bar)!?.n; }",
+            "function foo(bar?: { n: number }) { return (bar)?.n; }",
+            None,
+        ),
+    ];
+
+    Tester::new(NoExtraNonNullAssertion::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -111,29 +111,71 @@ impl Rule for NoExtraneousClass {
         }
         let span = class.id.as_ref().map_or(class.span, |id| id.span);
         let body = &class.body.body;
-        match body.as_slice() {
-            [] => {
-                if !self.allow_empty {
-                    ctx.diagnostic(empty_no_extraneous_class_diagnostic(class.span));
-                }
+        if body.is_empty() {
+            if !self.allow_empty {
+                ctx.diagnostic(empty_no_extraneous_class_diagnostic(span));
             }
-            [ClassElement::MethodDefinition(constructor)] if constructor.kind.is_constructor() => {
-                let only_constructor =
-                    !constructor.value.params.items.iter().any(FormalParameter::has_modifier);
-                if only_constructor && !self.allow_constructor_only {
-                    ctx.diagnostic(only_constructor_no_extraneous_class_diagnostic(span));
+            return;
+        }
+
+        let mut only_static = true;
+        let mut only_constructor = true;
+        for element in body {
+            match element {
+                ClassElement::MethodDefinition(method) if method.kind.is_constructor() => {
+                    // `constructor(public bar: string) {}` declares instance members
+                    if method.value.params.items.iter().any(FormalParameter::has_modifier) {
+                        only_static = false;
+                        only_constructor = false;
+                    }
                 }
-            }
-            _ => {
-                let only_static = body.iter().all(|prop| prop.r#static() && !prop.is_abstract());
-                if only_static && !self.allow_static_only {
-                    ctx.diagnostic(only_static_no_extraneous_class_diagnostic(span));
+                ClassElement::MethodDefinition(_)
+                | ClassElement::PropertyDefinition(_)
+                | ClassElement::AccessorProperty(_) => {
+                    only_constructor = false;
+                    if !element.r#static() || element.is_abstract() {
+                        only_static = false;
+                    }
+                }
+                ClassElement::TSIndexSignature(sig) => {
+                    only_constructor = false;
+                    if !is_static_index_signature(sig.span, ctx) {
+                        only_static = false;
+                    }
+                }
+                ClassElement::StaticBlock(_) => {
+                    only_constructor = false;
                 }
             }
-        };
+            if !only_static && !only_constructor {
+                return;
+            }
+        }
+
+        if only_constructor {
+            if !self.allow_constructor_only {
+                ctx.diagnostic(only_constructor_no_extraneous_class_diagnostic(span));
+            }
+        } else if only_static && !self.allow_static_only {
+            ctx.diagnostic(only_static_no_extraneous_class_diagnostic(span));
+        }
     }
 }
 
+/// The `static` modifier of an index signature is not kept in the AST, so look for it
+/// in the source right before the signature, and before a `readonly` modifier if any.
+fn is_static_index_signature(span: Span, ctx: &LintContext) -> bool {
+    let before = ctx.source_text()[..span.start as usize].trim_end();
+    let before = strip_keyword(before, "readonly").map_or(before, str::trim_end);
+    strip_keyword(before, "static").is_some()
+}
+
+/// Strips `keyword` from the end of `text` if it is a whole word.
+fn strip_keyword<'a>(text: &'a str, keyword: &str) -> Option<&'a str> {
+    text.strip_suffix(keyword)
+        .filter(|before| !before.ends_with(|c: char| c == '$' || c == '_' || c.is_alphanumeric()))
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -168,6 +210,9 @@ fn test() {
 			    ",
             None,
         ),
+        ("class Foo { [key: string]: any }", None),
+        ("class Foo { [key: string]: any; static bar() {} }", None),
+        ("class Foo { readonly [key: string]: any; static bar() {} }", None),
         ("class Foo {}", Some(serde_json::json!([{ "allowEmpty": true }]))),
         (
             "
@@ -237,6 +282,8 @@ fn test() {
     ];
 
     let fail = vec![
+        ("class Foo { static [key: string]: any; static bar() {} }", None),
+        ("class Foo { static readonly [key: string]: any; static bar() {} }", None),
         ("class Foo {}", None),
         (
             "
@@ -329,6 +376,14 @@ fn test() {
 			      ",
             None,
         ),
+        (
+            "
+			class Foo {
+			  static {}
+			}
+			      ",
+            None,
+        ),
     ];
 
     Tester::new(NoExtraneousClass::NAME, pass, fail).test_and_snapshot();
@@ -0,0 +1,371 @@
+use oxc_ast::{
+    ast::{
+        BindingPatternKind, ChainElement, Expression, TSLiteral, TSType, TSTypeAnnotation,
+        TSTypeName,
+    },
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn no_inferrable_types_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "Type {x0} trivially inferred from a {x0} literal, remove type annotation."
+    ))
+    .with_help("Remove the type annotation.")
+    .with_label(span1)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoInferrableTypes {
+    ignore_parameters: bool,
+    ignore_properties: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow explicit type declarations for variables or parameters initialized to a number,
+    /// string, or boolean.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// TypeScript is able to infer the types of parameters, properties, and variables from
+    /// their default or initial values. There is no need to use an explicit `:` type annotation
+    /// on one of those constructs initialized to a boolean, number, or string. Doing so adds
+    /// unnecessary verbosity to code - making it harder to read - and in some cases can prevent
+    /// TypeScript from inferring a more specific literal type (e.g. `10`) instead of the more
+    /// general primitive type (e.g. `number`).
+    ///
+    /// ### Example
+    /// ```ts
+    /// const a: bigint = 10n;
+    /// const b: boolean = !0;
+    /// const c: number = 10;
+    /// const d: null = null;
+    /// const e: RegExp = /a/;
+    /// const f: string = 'str';
+    /// const g: symbol = Symbol('a');
+    /// const h: undefined = void someValue;
+    ///
+    /// class Foo {
+    ///   prop: number = 5;
+    /// }
+    ///
+    /// function fn(a: number = 5, b: boolean = true) {}
+    /// ```
+    NoInferrableTypes,
+    style
+);
+
+impl Rule for NoInferrableTypes {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else {
+            return Self::default();
+        };
+        let get_as_bool = |name: &str| -> bool {
+            config.get(name).and_then(serde_json::Value::as_bool).unwrap_or(false)
+        };
+        Self {
+            ignore_parameters: get_as_bool("ignoreParameters"),
+            ignore_properties: get_as_bool("ignoreProperties"),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::VariableDeclarator(declarator) => {
+                let (Some(type_annotation), Some(init)) =
+                    (&declarator.id.type_annotation, &declarator.init)
+                else {
+                    return;
+                };
+                report_inferrable_type(declarator.span, type_annotation, init, None, ctx);
+            }
+            AstKind::FormalParameter(param) => {
+                if self.ignore_parameters {
+                    return;
+                }
+                let BindingPatternKind::AssignmentPattern(assignment) = &param.pattern.kind else {
+                    return;
+                };
+                let Some(type_annotation) = &assignment.left.type_annotation else {
+                    return;
+                };
+                let marker = assignment.left.optional.then_some('?');
+                report_inferrable_type(param.span, type_annotation, &assignment.right, marker, ctx);
+            }
+            AstKind::PropertyDefinition(property) => {
+                // `readonly` is ignored because a readonly property without a type annotation is
+                // inferred as its literal type, which may break code when the annotation is removed.
+                if self.ignore_properties || property.readonly || property.optional {
+                    return;
+                }
+                let (Some(type_annotation), Some(value)) =
+                    (&property.type_annotation, &property.value)
+                else {
+                    return;
+                };
+                let marker = property.definite.then_some('!');
+                report_inferrable_type(property.span, type_annotation, value, marker, ctx);
+            }
+            _ => {}
+        }
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+/// `marker` is the `?` or `!` token before the type annotation, which must be removed together
+/// with the annotation.
+fn report_inferrable_type<'a>(
+    span: Span,
+    type_annotation: &TSTypeAnnotation<'a>,
+    init: &Expression<'a>,
+    marker: Option<char>,
+    ctx: &LintContext<'a>,
+) {
+    let Some(type_name) = get_inferrable_type_name(&type_annotation.type_annotation, init) else {
+        return;
+    };
+
+    ctx.diagnostic_with_fix(no_inferrable_types_diagnostic(type_name, span), |fixer| {
+        let start = marker
+            .and_then(|marker| {
+                ctx.source_range(Span::new(span.start, type_annotation.span.start))
+                    .rfind(marker)
+                    .map(|offset| span.start + u32::try_from(offset).unwrap())
+            })
+            .unwrap_or(type_annotation.span.start);
+        fixer.delete_range(Span::new(start, type_annotation.span.end))
+    });
+}
+
+/// Returns the name of the annotated type if it can be trivially inferred from `init`.
+fn get_inferrable_type_name(ts_type: &TSType, init: &Expression) -> Option<&'static str> {
+    let init = init.without_parenthesized();
+    let is_inferrable = match ts_type {
+        TSType::TSBigIntKeyword(_) => {
+            // note that bigint cannot have `+` prefixed to it
+            let init = unwrap_unary_prefix(init, &[UnaryOperator::UnaryNegation]);
+            is_function_call(init, "BigInt") || matches!(init, Expression::BigIntLiteral(_))
+        }
+        TSType::TSBooleanKeyword(_) => {
+            has_unary_prefix(init, &[UnaryOperator::LogicalNot])
+                || is_function_call(init, "Boolean")
+                || matches!(init, Expression::BooleanLiteral(_))
+        }
+        TSType::TSNumberKeyword(_) => {
+            let init = unwrap_unary_prefix(
+                init,
+                &[UnaryOperator::UnaryPlus, UnaryOperator::UnaryNegation],
+            );
+            init.is_specific_id("Infinity")
+                || init.is_specific_id("NaN")
+                || is_function_call(init, "Number")
+                || matches!(init, Expression::NumericLiteral(_))
+        }
+        // `null` is parsed as a literal type
+        TSType::TSNullKeyword(_) => matches!(init, Expression::NullLiteral(_)),
+        TSType::TSLiteralType(literal) => {
+            matches!(literal.literal, TSLiteral::NullLiteral(_))
+                && matches!(init, Expression::NullLiteral(_))
+        }
+        TSType::TSStringKeyword(_) => {
+            is_function_call(init, "String")
+                || matches!(init, Expression::StringLiteral(_) | Expression::TemplateLiteral(_))
+        }
+        TSType::TSSymbolKeyword(_) => is_function_call(init, "Symbol"),
+        TSType::TSTypeReference(reference) => {
+            let TSTypeName::IdentifierReference(ident) = &reference.type_name else {
+                return None;
+            };
+            ident.name == "RegExp"
+                && match init {
+                    Expression::RegExpLiteral(_) => true,
+                    Expression::NewExpression(new_expr) => new_expr.callee.is_specific_id("RegExp"),
+                    _ => is_function_call(init, "RegExp"),
+                }
+        }
+        TSType::TSUndefinedKeyword(_) => {
+            has_unary_prefix(init, &[UnaryOperator::Void]) || init.is_specific_id("undefined")
+        }
+        _ => false,
+    };
+
+    if !is_inferrable {
+        return None;
+    }
+
+    Some(match ts_type {
+        TSType::TSBigIntKeyword(_) => "bigint",
+        TSType::TSBooleanKeyword(_) => "boolean",
+        TSType::TSNumberKeyword(_) => "number",
+        TSType::TSNullKeyword(_) | TSType::TSLiteralType(_) => "null",
+        TSType::TSStringKeyword(_) => "string",
+        TSType::TSSymbolKeyword(_) => "symbol",
+        TSType::TSUndefinedKeyword(_) => "undefined",
+        _ => "RegExp",
+    })
+}
+
+fn has_unary_prefix(expr: &Expression, operators: &[UnaryOperator]) -> bool {
+    matches!(expr, Expression::UnaryExpression(unary) if operators.contains(&unary.operator))
+}
+
+fn unwrap_unary_prefix<'e, 'a>(
+    expr: &'e Expression<'a>,
+    operators: &[UnaryOperator],
+) -> &'e Expression<'a> {
+    match expr {
+        Expression::UnaryExpression(unary) if operators.contains(&unary.operator) => {
+            &unary.argument
+        }
+        _ => expr,
+    }
+}
+
+fn is_function_call(expr: &Expression, name: &str) -> bool {
+    match expr {
+        Expression::CallExpression(call_expr) => call_expr.callee.is_specific_id(name),
+        Expression::ChainExpression(chain) => {
+            matches!(&chain.expression, ChainElement::CallExpression(call_expr) if call_expr.callee.is_specific_id(name))
+        }
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("const a = 10n;", None),
+        ("const a = -10n;", None),
+        ("const a = BigInt(10);", None),
+        ("const a = -BigInt(10);", None),
+        ("const a = false;", None),
+        ("const a = true;", None),
+        ("const a = Boolean(null);", None),
+        ("const a = !0;", None),
+        ("const a = 10;", None),
+        ("const a = +10;", None),
+        ("const a = -10;", None),
+        ("const a = Number('1');", None),
+        ("const a = +Number('1');", None),
+        ("const a = -Number('1');", None),
+        ("const a = Infinity;", None),
+        ("const a = NaN;", None),
+        ("const a = null;", None),
+        ("const a = /a/;", None),
+        ("const a = RegExp('a');", None),
+        ("const a = new RegExp('a');", None),
+        ("const a = 'str';", None),
+        ("const a = `str`;", None),
+        ("const a = String(1);", None),
+        ("const a = Symbol('a');", None),
+        ("const a = undefined;", None),
+        ("const a = void someValue;", None),
+        ("const fn = (a = 5, b = true, c = 'foo') => {};", None),
+        ("const fn = function (a = 5, b = true, c = 'foo') {};", None),
+        ("function fn(a = 5, b = true, c = 'foo') {}", None),
+        ("function fn(a: number, b: boolean, c: string) {}", None),
+        ("class Foo { a = 5; b = true; c = 'foo'; }", None),
+        ("class Foo { readonly a: number = 5; }", None),
+        ("class Foo { a?: number = 5; b?: boolean = true; c?: string = 'foo'; }", None),
+        ("class Foo { constructor(public a = true) {} }", None),
+        ("const a: any = 5;", None),
+        ("const fn = function (a: any = 5, b: any = true, c: any = 'foo') {};", None),
+        ("const a: number | string = 5;", None),
+        ("const a: number = something();", None),
+        ("const a: string = foo.bar;", None),
+        ("const a: RegExp = foo;", None),
+        ("const a: Foo.RegExp = /a/;", None),
+        ("const fn: () => number = () => 5;", None),
+        ("let a: number;", None),
+        (
+            "const fn = (a: number = 5, b: boolean = true, c: string = 'foo') => {};",
+            Some(serde_json::json!([{ "ignoreParameters": true }])),
+        ),
+        (
+            "function fn(a: number = 5, b: boolean = true, c: string = 'foo') {}",
+            Some(serde_json::json!([{ "ignoreParameters": true }])),
+        ),
+        (
+            "const fn = function (a: number = 5, b: boolean = true, c: string = 'foo') {};",
+            Some(serde_json::json!([{ "ignoreParameters": true }])),
+        ),
+        (
+            "class Foo { a: number = 5; b: boolean = true; c: string = 'foo'; }",
+            Some(serde_json::json!([{ "ignoreProperties": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("const a: bigint = 10n;", None),
+        ("const a: bigint = -10n;", None),
+        ("const a: bigint = BigInt(10);", None),
+        ("const a: bigint = -BigInt(10);", None),
+        ("const a: boolean = false;", None),
+        ("const a: boolean = true;", None),
+        ("const a: boolean = Boolean(null);", None),
+        ("const a: boolean = !0;", None),
+        ("const a: number = 10;", None),
+        ("const a: number = +10;", None),
+        ("const a: number = -10;", None),
+        ("const a: number = Number('1');", None),
+        ("const a: number = +Number('1');", None),
+        ("const a: number = -Number('1');", None),
+        ("const a: number = Infinity;", None),
+        ("const a: number = -Infinity;", None),
+        ("const a: number = NaN;", None),
+        ("const a: null = null;", None),
+        ("const a: RegExp = /a/;", None),
+        ("const a: RegExp = RegExp('a');", None),
+        ("const a: RegExp = new RegExp('a');", None),
+        ("const a: string = 'str';", None),
+        ("const a: string = `str`;", None),
+        ("const a: string = String(1);", None),
+        ("const a: symbol = Symbol('a');", None),
+        ("const a: undefined = undefined;", None),
+        ("const a: undefined = void someValue;", None),
+        ("const fn = (a: number = 5, b: boolean = true, c: string = 'foo') => {};", None),
+        ("function fn(a: number = 5, b: boolean = true, c: string = 'foo') {}", None),
+        ("class Foo { constructor(public a: boolean = true) {} }", None),
+        ("class Foo { a: number = 5; b: boolean = true; c: string = 'foo'; }", None),
+        ("class Foo { a: number = 5; }", Some(serde_json::json!([{ "ignoreParameters": true }]))),
+        ("function fn(a: number = 5) {}", Some(serde_json::json!([{ "ignoreProperties": true }]))),
+    ];
+
+    let fix = vec![
+        ("const a: bigint = 10n;", "const a = 10n;", None),
+        ("const a: boolean = !0;", "const a = !0;", None),
+        ("const a: number = -Infinity;", "const a = -Infinity;", None),
+        ("const a: RegExp = new RegExp('a');", "const a = new RegExp('a');", None),
+        (
+            "const fn = (a: number = 5, b: boolean = true, c: string = 'foo') => {};",
+            "const fn = (a = 5, b = true, c = 'foo') => {};",
+            None,
+        ),
+        ("function fn(a?: number = 5) {}", "function fn(a = 5) {}", None),
+        (
+            "class Foo { constructor(public a: boolean = true) {} }",
+            "class Foo { constructor(public a = true) {} }",
+            None,
+        ),
+        (
+            "class Foo { a: number = 5; b: boolean = true; c: string = 'foo'; }",
+            "class Foo { a = 5; b = true; c = 'foo'; }",
+            None,
+        ),
+        ("class Foo { a!: number = 5; }", "class Foo { a = 5; }", None),
+    ];
+
+    Tester::new(NoInferrableTypes::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
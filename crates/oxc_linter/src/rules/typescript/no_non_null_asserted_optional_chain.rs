@@ -81,10 +81,13 @@ impl Rule for NoNonNullAssertedOptionalChain {
             if let Some(chain_span) = chain_span {
                 let chain_span_end = chain_span.end;
                 let non_null_end = non_null_expr.span.end - 1;
-                ctx.diagnostic(no_non_null_asserted_optional_chain_diagnostic(
-                    Span::new(chain_span_end, chain_span_end),
-                    Span::new(non_null_end, non_null_end),
-                ));
+                ctx.diagnostic_with_suggestion(
+                    no_non_null_asserted_optional_chain_diagnostic(
+                        Span::new(chain_span_end, chain_span_end),
+                        Span::new(non_null_end, non_null_end),
+                    ),
+                    |fixer| fixer.delete_range(Span::new(non_null_end, non_null_expr.span.end)),
+                );
             }
         }
     }
@@ -120,6 +123,8 @@ fn test() {
         "foo?.bar!();",
         "foo?.['bar']!.baz;",
         "foo?.get()!.bar()",
+        "foo?.bar().baz!;",
+        "foo?.bar()().baz!;",
     ];
 
     let fail = vec![
@@ -48,17 +48,31 @@ impl Default for NoThisAliasConfig {
 declare_oxc_lint!(
     /// ### What it does
     ///
-    /// Disallow unnecessary constraints on generic types.
+    /// Disallow aliasing `this`.
     ///
     /// ### Why is this bad?
     ///
-    /// Generic type parameters (<T>) in TypeScript may be "constrained" with an extends keyword.
-    /// When no extends is provided, type parameters default a constraint to unknown. It is therefore redundant to extend from any or unknown.
+    /// Assigning a variable to `this` instead of properly using arrow lambdas may be a symptom of
+    /// pre-ES6 practices or not managing scope well.
     ///
-    /// the rule doesn't allow const {allowedName} = this
-    /// this is to keep 1:1 with eslint implementation
-    /// sampe with obj.<allowedName> = this
+    /// ### Example
+    /// ```javascript
+    /// const self = this;
+    ///
+    /// setTimeout(function () {
+    ///   self.doWork();
+    /// });
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `allowDestructuring`: `boolean` (default `true`) allows destructuring `this`, e.g.
+    ///   `const { props } = this;`.
+    /// - `allowedNames`: `string[]` (default `[]`) names that may alias `this`, e.g. `["self"]`.
+    ///
+    /// The allowed names only apply to plain variables and assignments, so
+    /// `const { allowedName } = this` and `obj.allowedName = this` are still reported,
+    /// like in the original ESLint rule.
     NoThisAlias,
     correctness
 );
@@ -68,7 +82,7 @@ impl Rule for NoThisAlias {
         let obj = value.get(0);
         let allowed_names = value
             .get(0)
-            .and_then(|v| v.get("allow_names"))
+            .and_then(|v| v.get("allowedNames"))
             .and_then(serde_json::Value::as_array)
             .unwrap_or(&vec![])
             .iter()
@@ -79,9 +93,9 @@ impl Rule for NoThisAlias {
 
         Self(Box::new(NoThisAliasConfig {
             allow_destructuring: obj
-                .and_then(|v| v.get("allow_destructuring"))
+                .and_then(|v| v.get("allowDestructuring"))
                 .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
+                .unwrap_or(true),
             allow_names: allowed_names,
         }))
     }
@@ -158,30 +172,32 @@ fn test() {
 
     let pass = vec![
         // allow destructuring
+        ("const { props, state } = this;", None),
+        ("const [foo] = this;", None),
         (
             "const { props, state } = this;",
-            Some(serde_json::json!([{ "allow_destructuring": true }])),
+            Some(serde_json::json!([{ "allowDestructuring": true }])),
         ),
-        ("const { length } = this;", Some(serde_json::json!([{ "allow_destructuring": true }]))),
+        ("const { length } = this;", Some(serde_json::json!([{ "allowDestructuring": true }]))),
         (
             "const { length, toString } = this;",
-            Some(serde_json::json!([{ "allow_destructuring": true }])),
+            Some(serde_json::json!([{ "allowDestructuring": true }])),
         ),
-        ("const [foo] = this;", Some(serde_json::json!([{ "allow_destructuring": true }]))),
-        ("const [foo, bar] = this;", Some(serde_json::json!([{ "allow_destructuring": true }]))),
+        ("const [foo] = this;", Some(serde_json::json!([{ "allowDestructuring": true }]))),
+        ("const [foo, bar] = this;", Some(serde_json::json!([{ "allowDestructuring": true }]))),
         // allow list
-        ("const self = this;", Some(serde_json::json!([{ "allow_names": vec!["self"] }]))),
+        ("const self = this;", Some(serde_json::json!([{ "allowedNames": vec!["self"] }]))),
     ];
 
     let fail = vec![
         ("const self = this;", None),
         (
             "const { props, state } = this;",
-            Some(serde_json::json!([{ "allow_destructuring": false }])),
+            Some(serde_json::json!([{ "allowDestructuring": false }])),
         ),
         (
             "const [ props, state ] = this;",
-            Some(serde_json::json!([{ "allow_destructuring": false }])),
+            Some(serde_json::json!([{ "allowDestructuring": false }])),
         ),
         ("let foo; \nconst other =3;\n\n\n\nfoo = this", None),
         ("let foo; (foo as any) = this", None),
@@ -217,7 +233,7 @@ fn test() {
               const [foo, bar] = this;
             }
           }",
-            Some(serde_json::json!([{ "allow_destructuring": false }])),
+            Some(serde_json::json!([{ "allowDestructuring": false }])),
         ),
     ];
 
@@ -50,6 +50,13 @@ declare_oxc_lint!(
 impl Rule for NoUnnecessaryTypeConstraint {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::TSTypeParameterDeclaration(decl) = node.kind() {
+            // `<T,>() => {}` needs the trailing comma in tsx, otherwise `<T>` is parsed as JSX.
+            let needs_trailing_comma = ctx.source_type().is_jsx()
+                && decl.params.len() == 1
+                && matches!(
+                    ctx.nodes().parent_kind(node.id()),
+                    Some(AstKind::ArrowFunctionExpression(_))
+                );
             for param in &decl.params {
                 if let Some(ty) = &param.constraint {
                     let (value, ty_span) = match ty {
@@ -57,12 +64,30 @@ impl Rule for NoUnnecessaryTypeConstraint {
                         TSType::TSUnknownKeyword(t) => ("unknown", t.span),
                         _ => continue,
                     };
-                    ctx.diagnostic(no_unnecessary_type_constraint_diagnostic(
-                        param.name.name.as_str(),
-                        value,
-                        param.name.span,
-                        ty_span,
-                    ));
+                    ctx.diagnostic_with_fix(
+                        no_unnecessary_type_constraint_diagnostic(
+                            param.name.name.as_str(),
+                            value,
+                            param.name.span,
+                            ty_span,
+                        ),
+                        |fixer| {
+                            // keep the default type, if any
+                            let rest = fixer.source_range(Span::new(ty_span.end, param.span.end));
+                            let has_trailing_comma = ctx.source_text()[param.span.end as usize..]
+                                .trim_start()
+                                .starts_with(',');
+                            let replacement = if needs_trailing_comma && !has_trailing_comma {
+                                format!("{rest},")
+                            } else {
+                                rest.to_string()
+                            };
+                            fixer.replace(
+                                Span::new(param.name.span.end, param.span.end),
+                                replacement,
+                            )
+                        },
+                    );
                 }
             }
         }
@@ -115,5 +140,26 @@ fn test() {
         "type Data<T extends unknown> = {};",
     ];
 
-    Tester::new(NoUnnecessaryTypeConstraint::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("function data<T extends any>() {}", "function data<T>() {}", None),
+        ("function data<T extends any, U>() {}", "function data<T, U>() {}", None),
+        ("function data<T, U extends unknown>() {}", "function data<T, U>() {}", None),
+        ("const data = <T extends any>() => {};", "const data = <T,>() => {};", None),
+        ("const data = <T extends any,>() => {};", "const data = <T,>() => {};", None),
+        ("const data = <T extends any ,>() => {};", "const data = <T ,>() => {};", None),
+        (
+            "const data = <T extends any = unknown>() => {};",
+            "const data = <T = unknown,>() => {};",
+            None,
+        ),
+        (
+            "const data = <T extends any, U extends any>() => {};",
+            "const data = <T, U>() => {};",
+            None,
+        ),
+        ("class Data<T extends unknown> {}", "class Data<T> {}", None),
+        ("type Data<T extends unknown> = {};", "type Data<T> = {};", None),
+    ];
+
+    Tester::new(NoUnnecessaryTypeConstraint::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
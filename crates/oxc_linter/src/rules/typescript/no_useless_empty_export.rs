@@ -1,7 +1,7 @@
-use oxc_ast::AstKind;
+use oxc_ast::{ast::Statement, AstKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -38,23 +38,42 @@ declare_oxc_lint!(
 
 impl Rule for NoUselessEmptyExport {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::ExportNamedDeclaration(decl) = node.kind() else { return };
-        if decl.declaration.is_some() || !decl.specifiers.is_empty() {
+        let body = match node.kind() {
+            AstKind::Program(program) => &program.body,
+            AstKind::TSModuleBlock(block) => &block.body,
+            _ => return,
+        };
+
+        let (empty_exports, others): (Vec<_>, Vec<_>) = body
+            .iter()
+            .filter(|stmt| {
+                stmt.is_module_declaration()
+                    || matches!(stmt, Statement::TSImportEqualsDeclaration(_))
+            })
+            .partition(|stmt| is_empty_export(stmt));
+
+        // `export {}` is what makes this file a module
+        if others.is_empty() {
             return;
         }
-        let module_record = ctx.module_record();
-        if module_record.exported_bindings.is_empty()
-            && module_record.local_export_entries.is_empty()
-            && module_record.indirect_export_entries.is_empty()
-            && module_record.star_export_entries.is_empty()
-            && module_record.export_default.is_none()
-        {
-            return;
+
+        for stmt in empty_exports {
+            let span = stmt.span();
+            ctx.diagnostic_with_fix(no_useless_empty_export_diagnostic(span), |fixer| {
+                fixer.delete(&span)
+            });
         }
-        ctx.diagnostic_with_fix(no_useless_empty_export_diagnostic(decl.span), |fixer| {
-            fixer.delete(&decl.span)
-        });
     }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        // In a declaration file, `export {}` is what keeps the declarations from being global,
+        // even when there are other exports.
+        !ctx.source_type().is_typescript_definition()
+    }
+}
+
+fn is_empty_export(stmt: &Statement) -> bool {
+    matches!(stmt, Statement::ExportNamedDeclaration(decl) if decl.declaration.is_none() && decl.specifiers.is_empty())
 }
 
 #[test]
@@ -77,6 +96,22 @@ fn test() {
             export = {};
         ",
         "export {};",
+        "
+            export {};
+            export {};
+        ",
+        "
+            export {};
+            declare module '_' {
+                export const _ = {};
+            }
+        ",
+        "
+            import _ = require('_');
+            declare module '_' {
+                export {};
+            }
+        ",
     ];
 
     let fail = vec![
@@ -107,10 +142,24 @@ fn test() {
             export { _ };
             export {};
         ",
-        // "
-        // import _ = require('_');
-        // export {};
-        // ",
+        "
+            import _ = require('_');
+            export {};
+        ",
+        "
+            import {} from '_';
+            export {};
+        ",
+        "
+            export = {};
+            export {};
+        ",
+        "
+            declare module '_' {
+                export const _ = {};
+                export {};
+            }
+        ",
     ];
 
     let fix = vec![
@@ -120,7 +169,9 @@ fn test() {
         ("const _ = {};export default _;export {};", "const _ = {};export default _;"),
         ("export {};const _ = {};export default _;", "const _ = {};export default _;"),
         ("const _ = {};export { _ };export {};", "const _ = {};export { _ };"),
-        // ("import _ = require('_');export {};", "import _ = require('_');"),
+        ("import _ = require('_');export {};", "import _ = require('_');"),
+        ("import {} from '_';export {};", "import {} from '_';"),
+        ("export {};export {};export * from '_';", "export * from '_';"),
     ];
 
     Tester::new(NoUselessEmptyExport::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
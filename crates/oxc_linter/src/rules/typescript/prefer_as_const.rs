@@ -1,5 +1,5 @@
 use oxc_ast::{
-    ast::{Expression, TSLiteral, TSType},
+    ast::{Expression, TSLiteral, TSType, TSTypeAnnotation},
     AstKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
@@ -52,7 +52,7 @@ impl Rule for PreferAsConst {
                     &type_annotation.type_annotation,
                     initial_value_expression,
                     ctx,
-                    false,
+                    Some(type_annotation),
                 );
             }
             AstKind::PropertyDefinition(property_definition) => {
@@ -66,7 +66,7 @@ impl Rule for PreferAsConst {
                     &type_annotation.type_annotation,
                     initial_value_expression,
                     ctx,
-                    false,
+                    Some(type_annotation),
                 );
             }
             AstKind::TSAsExpression(as_expression) => {
@@ -74,7 +74,7 @@ impl Rule for PreferAsConst {
                     &as_expression.type_annotation,
                     &as_expression.expression,
                     ctx,
-                    true,
+                    None,
                 );
             }
             _ => {}
@@ -86,11 +86,13 @@ impl Rule for PreferAsConst {
     }
 }
 
+/// `type_annotation` is the `: 'foo'` annotation of a variable or class property, and is `None`
+/// for `as` expressions.
 fn check_and_report(
     ts_type: &TSType,
     initial_value_expression: &Expression,
     ctx: &LintContext,
-    can_fix: bool,
+    type_annotation: Option<&TSTypeAnnotation>,
 ) {
     if let TSType::TSLiteralType(literal_type) = &ts_type {
         let error_span = match &literal_type.literal {
@@ -108,6 +110,16 @@ fn check_and_report(
                 Expression::NullLiteral(_) => Some(null_literal.span),
                 _ => None,
             },
+            TSLiteral::BooleanLiteral(boolean_literal) => match initial_value_expression {
+                Expression::BooleanLiteral(initial_boolean) => {
+                    if boolean_literal.value == initial_boolean.value {
+                        Some(boolean_literal.span)
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            },
             TSLiteral::NumericLiteral(number_literal) => match initial_value_expression {
                 Expression::NumericLiteral(initial_number) => {
                     if (number_literal.value - initial_number.value).abs() < f64::EPSILON {
@@ -121,12 +133,18 @@ fn check_and_report(
             _ => None,
         };
         if let Some(span) = error_span {
-            if can_fix {
+            if let Some(type_annotation) = type_annotation {
+                ctx.diagnostic_with_suggestion(prefer_as_const_diagnostic(span), |fixer| {
+                    let fixer = fixer.for_multifix();
+                    let mut fix = fixer.new_fix_with_capacity(2);
+                    fix.push(fixer.delete(type_annotation));
+                    fix.push(fixer.insert_text_after(initial_value_expression, " as const"));
+                    fix.with_message("Replace the type annotation with `as const`")
+                });
+            } else {
                 ctx.diagnostic_with_fix(prefer_as_const_diagnostic(span), |fixer| {
                     fixer.replace(span, "const")
                 });
-            } else {
-                ctx.diagnostic(prefer_as_const_diagnostic(span));
             }
         }
     }
@@ -144,6 +162,9 @@ fn test() {
         "let foo = { bar: 'baz' };",
         "let foo = { bar: 2 };",
         "let foo = 'bar' as string;",
+        "let foo = true as boolean;",
+        "let foo: false = true;",
+        "let foo: `bar${baz}` = `bar${baz}`;",
         "let foo = `bar` as `bar`;",
         "let foo = `bar` as `foo`;",
         "let foo = `bar` as 'bar';",
@@ -175,6 +196,7 @@ fn test() {
         "let []: 'bar' = 'bar';",
         "let foo: 'bar' = 'bar';",
         "let foo: 2 = 2;",
+        "let foo: false = false;",
         "class foo { bar: 'baz' = 'baz';}",
         "class foo { bar: 2 = 2;}",
     ];
@@ -187,6 +209,7 @@ fn test() {
         ("let foo = 5 as 5;", "let foo = 5 as const;", None),
         ("class foo { foo = 'bar' as 'bar'; }", "class foo { foo = 'bar' as const; }", None),
         ("class foo { foo = 5 as 5; }", "class foo { foo = 5 as const; }", None),
+        ("let foo = false as false;", "let foo = false as const;", None),
         // NOTE: OXC does not parse these format yet.
         // ("let foo = <4>4;", "let foo = <const>4;", None),
         // ("let foo = <'bar'>'bar';", "let foo = <const>'bar';", None),
@@ -1,18 +1,23 @@
-use oxc_ast::{ast::TSEnumMemberName, AstKind};
+use oxc_ast::{
+    ast::{Expression, TSEnumMemberName},
+    AstKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
-fn prefer_enum_initializers_diagnostic(x0: &str, x1: usize, span2: Span) -> OxcDiagnostic {
+fn prefer_enum_initializers_diagnostic(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("The value of the member {x0:?} should be explicitly defined."))
-        .with_help(format!("Can be fixed to {x0:?} = {x1:?}."))
+        .with_help(format!("Can be fixed to {x0:?} = {x1}."))
         .with_label(span2)
 }
 
 #[derive(Debug, Default, Clone)]
-pub struct PreferEnumInitializers;
+pub struct PreferEnumInitializers {
+    prefer_string: bool,
+}
 
 declare_oxc_lint!(
     /// ### What it does
@@ -29,26 +34,58 @@ declare_oxc_lint!(
     ///  Close,
     /// }
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// `preferString`: `boolean` (default `false`) suggests initializing members with their own
+    /// name (`Close = 'Close'`) instead of the numeric value they would implicitly have.
     PreferEnumInitializers,
     pedantic
 );
 
 impl Rule for PreferEnumInitializers {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            prefer_string: value
+                .get(0)
+                .and_then(|config| config.get("preferString"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::TSEnumDeclaration(decl) = node.kind() else {
             return;
         };
 
-        for (index, member) in decl.members.iter().enumerate() {
-            if member.initializer.is_none() {
-                if let TSEnumMemberName::StaticIdentifier(i) = &member.id {
-                    ctx.diagnostic(prefer_enum_initializers_diagnostic(
-                        i.name.as_str(),
-                        index + 1,
-                        member.span,
-                    ));
-                }
-            }
+        // The value a member without an initializer implicitly has: one more than the previous
+        // numeric member, or its index if that can't be determined.
+        let mut next_value = Some(0.0);
+        for (member, index) in decl.members.iter().zip((0..).map(f64::from)) {
+            let Some(initializer) = &member.initializer else {
+                let name = match &member.id {
+                    TSEnumMemberName::StaticIdentifier(ident) => ident.name.as_str(),
+                    TSEnumMemberName::StaticStringLiteral(lit) => lit.value.as_str(),
+                    _ => continue,
+                };
+                let value = next_value.unwrap_or(index);
+                let suggested = if self.prefer_string {
+                    format!("'{}'", escape_single_quoted(name))
+                } else {
+                    value.to_string()
+                };
+                ctx.diagnostic_with_suggestion(
+                    prefer_enum_initializers_diagnostic(name, &suggested, member.span),
+                    |fixer| fixer.insert_text_after_range(member.span, format!(" = {suggested}")),
+                );
+                next_value = Some(value + 1.0);
+                continue;
+            };
+            next_value = match initializer.without_parenthesized() {
+                Expression::NumericLiteral(lit) => Some(lit.value + 1.0),
+                _ => None,
+            };
         }
     }
 
@@ -57,57 +94,138 @@ impl Rule for PreferEnumInitializers {
     }
 }
 
+/// Escapes `name` to be put in a single-quoted string literal, e.g. `it's` to `it\'s`.
+fn escape_single_quoted(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            '\\' | '\'' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\u{2028}' => escaped.push_str("\\u2028"),
+            '\u{2029}' => escaped.push_str("\\u2029"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
-        "
+        (
+            "
 			enum Direction {}
 			    ",
-        "
+            None,
+        ),
+        (
+            "
 			enum Direction {
 			  Up = 1,
 			}
 			    ",
-        "
+            None,
+        ),
+        (
+            "
 			enum Direction {
 			  Up = 1,
 			  Down = 2,
 			}
 			    ",
-        "
+            None,
+        ),
+        (
+            "
 			enum Direction {
 			  Up = 'Up',
 			  Down = 'Down',
 			}
 			    ",
+            None,
+        ),
     ];
 
     let fail = vec![
-        "
+        (
+            "
 			enum Direction {
 			  Up,
 			}
 			      ",
-        "
+            None,
+        ),
+        (
+            "
 			enum Direction {
 			  Up,
 			  Down,
 			}
 			      ",
-        "
+            None,
+        ),
+        (
+            "
 			enum Direction {
 			  Up = 'Up',
 			  Down,
 			}
 			      ",
-        "
+            None,
+        ),
+        (
+            "
 			enum Direction {
 			  Up,
 			  Down = 'Down',
 			}
 			      ",
+            None,
+        ),
+        (
+            "
+			enum Direction {
+			  Up = 5,
+			  Down,
+			  Left = 10,
+			  Right,
+			}
+			      ",
+            None,
+        ),
+        (
+            "
+			enum Direction {
+			  'Up',
+			  Down,
+			}
+			      ",
+            None,
+        ),
+        (
+            "
+			enum Direction {
+			  Up,
+			  Down,
+			}
+			      ",
+            Some(serde_json::json!([{ "preferString": true }])),
+        ),
+        (
+            r#"
+			enum Quotes {
+			  "it's",
+			  'back\\slash',
+			}
+			      "#,
+            Some(serde_json::json!([{ "preferString": true }])),
+        ),
     ];
 
     Tester::new(PreferEnumInitializers::NAME, pass, fail).test_and_snapshot();
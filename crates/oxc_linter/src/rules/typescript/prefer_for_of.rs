@@ -1,7 +1,7 @@
 use oxc_ast::{
     ast::{
-        match_member_expression, AssignmentTarget, BindingPatternKind, Expression,
-        ForStatementInit, SimpleAssignmentTarget, VariableDeclarationKind,
+        AssignmentTarget, BindingPatternKind, Expression, ForStatementInit, MemberExpression,
+        SimpleAssignmentTarget, VariableDeclarationKind,
     },
     AstKind,
 };
@@ -148,26 +148,18 @@ impl Rule for PreferForOf {
             return;
         }
 
-        let array_name = {
-            let Some(mem_expr) = test_expr.right.as_member_expression() else {
-                return;
-            };
-            if !matches!(mem_expr.static_property_name(), Some(prop_name) if prop_name == "length")
-            {
-                return;
-            }
-
-            match mem_expr.object() {
-                Expression::Identifier(id) => id.name.as_str(),
-                expr @ match_member_expression!(Expression) => {
-                    match expr.to_member_expression().static_property_name() {
-                        Some(array_name) => array_name,
-                        None => return,
-                    }
-                }
-                _ => return,
-            }
+        let Some(array_length) = test_expr.right.as_member_expression() else {
+            return;
         };
+        if !matches!(array_length.static_property_name(), Some(prop_name) if prop_name == "length")
+        {
+            return;
+        }
+        let array = array_length.object();
+        if !matches!(array, Expression::Identifier(_)) && !array.is_member_expression() {
+            return;
+        }
+        let array_text = ctx.source_range(array.span());
 
         let Some(update_expr) = &for_stmt.update else {
             return;
@@ -208,20 +200,14 @@ impl Rule for PreferForOf {
                 }
             }
 
-            let parent_kind = ref_parent.kind();
-            let AstKind::MemberExpression(mem_expr) = parent_kind else {
+            // The index must only be used as the subscript of the iterated array, e.g. `arr[i]`
+            let AstKind::MemberExpression(MemberExpression::ComputedMemberExpression(mem_expr)) =
+                ref_parent.kind()
+            else {
                 return true;
             };
-            match mem_expr.object() {
-                Expression::Identifier(id) => id.name.as_str() != array_name,
-                expr if expr.is_member_expression() => {
-                    match expr.to_member_expression().static_property_name() {
-                        Some(prop_name) => prop_name != array_name,
-                        None => true,
-                    }
-                }
-                _ => true,
-            }
+            mem_expr.expression.span() != symbol_span
+                || ctx.source_range(mem_expr.object.span()) != array_text
         }) {
             return;
         }
@@ -280,6 +266,11 @@ fn test() {
         "for (let i = 0; i < arr.length; i++) { ({ foo: arr[i] } = { foo: 1 }); }",
         "for (let i = 0; i < arr.length; i++) { arr[i]++; }",
         "function* gen() { for (let i = 0; i < this.length; ++i) { yield this[i]; } }",
+        "for (let i = 0; i < a.arr.length; i++) { console.log(b.arr[i]); }",
+        "for (let i = 0; i < arr.length; i++) { console.log(other[i]); }",
+        "for (let i = 0; i < arr.length; i++) { console.log(arr[i], i); }",
+        "for (let i = 0; i < arr.length; i++) { console.log(arr[i][i]); }",
+        "for (let i = 0; i < arr.length; i--) { console.log(arr[i]); }",
     ];
 
     let fail = vec![
@@ -303,6 +294,8 @@ fn test() {
         "for (let i = 0; i < arr.length; i++) { ({ foo: obj[arr[i]] } = { foo: 1 }); }",
         "for (let i = 0; i < this.item.length; ++i) { this.item[i]; }",
         "function* gen() { for (let i = 0; i < this.array.length; ++i) { yield this.array[i]; } }",
+        "for (let i = 0; i < a.b.c.length; i++) { console.log(a.b.c[i]); }",
+        "for (let i = 0; i < arr.length; i++) { console.log(arr[i][0]); }",
     ];
 
     Tester::new(PreferForOf::NAME, pass, fail).test_and_snapshot();
@@ -1,6 +1,9 @@
 use oxc_ast::{
-    ast::{ExportDefaultDeclarationKind, Expression, TSInterfaceDeclaration, TSSignature, TSType},
-    AstKind, CommentKind,
+    ast::{
+        ExportDefaultDeclarationKind, Expression, TSCallSignatureDeclaration,
+        TSInterfaceDeclaration, TSSignature, TSThisType, TSType, TSTypeLiteral,
+    },
+    AstKind, CommentKind, Visit,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -14,6 +17,14 @@ fn prefer_function_type_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
         .with_label(span1)
 }
 
+fn unexpected_this_on_function_only_interface_diagnostic(
+    x0: &str,
+    spans: impl IntoIterator<Item = Span>,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("`this` refers to the function type '{x0}', did you intend to use a generic `this` parameter like `<Self>(this: Self, ...) => Self` instead?"))
+        .with_labels(spans)
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct PreferFunctionType;
 
@@ -101,6 +112,27 @@ fn has_one_super_type(decl: &TSInterfaceDeclaration) -> bool {
     true
 }
 
+/// Collects the `this` types of a call signature, except those nested in type literals where
+/// `this` refers to the literal instead of the interface.
+#[derive(Default)]
+struct ThisTypeFinder {
+    spans: Vec<Span>,
+}
+
+impl<'a> Visit<'a> for ThisTypeFinder {
+    fn visit_ts_this_type(&mut self, it: &TSThisType) {
+        self.spans.push(it.span);
+    }
+
+    fn visit_ts_type_literal(&mut self, _it: &TSTypeLiteral<'a>) {}
+}
+
+fn find_this_types(decl: &TSCallSignatureDeclaration) -> Vec<Span> {
+    let mut finder = ThisTypeFinder::default();
+    finder.visit_ts_call_signature_declaration(decl);
+    finder.spans
+}
+
 fn check_member(member: &TSSignature, node: &AstNode<'_>, ctx: &LintContext<'_>) {
     match member {
         TSSignature::TSCallSignatureDeclaration(decl) => {
@@ -122,6 +154,17 @@ fn check_member(member: &TSSignature, node: &AstNode<'_>, ctx: &LintContext<'_>)
 
                 match node.kind() {
                     AstKind::TSInterfaceDeclaration(interface_decl) => {
+                        // A function type can't refer to itself through `this`, so converting
+                        // the interface would change its meaning.
+                        let this_types = find_this_types(decl);
+                        if !this_types.is_empty() {
+                            ctx.diagnostic(unexpected_this_on_function_only_interface_diagnostic(
+                                &interface_decl.id.name,
+                                this_types,
+                            ));
+                            return;
+                        }
+
                         if let Some(type_parameters) = &interface_decl.type_parameters {
                             ctx.diagnostic_with_fix(
                                 prefer_function_type_diagnostic(&suggestion, decl.span),
@@ -504,6 +547,7 @@ fn test() {
               ",
         "type X = {} | { (): void; }",
         "type X = {} & { (): void; };",
+        "interface Foo { <T>(arg: T): T; }",
     ];
 
     let fix = vec![
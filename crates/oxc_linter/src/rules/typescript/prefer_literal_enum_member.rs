@@ -1,4 +1,7 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{
+    ast::{Expression, TSEnumDeclaration, TSEnumMemberName},
+    AstKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -72,37 +75,43 @@ impl Rule for PreferLiteralEnumMember {
         }
 
         if let Expression::UnaryExpression(unary_expr) = initializer {
-            if unary_expr.argument.is_literal() {
-                if matches!(
+            if unary_expr.argument.is_literal()
+                && matches!(
                     unary_expr.operator,
                     UnaryOperator::UnaryPlus | UnaryOperator::UnaryNegation,
-                ) {
-                    return;
-                }
-
-                if self.allow_bitwise_expressions
-                    && matches!(unary_expr.operator, UnaryOperator::BitwiseNot)
-                {
-                    return;
-                }
+                )
+            {
+                return;
             }
         }
 
         if self.allow_bitwise_expressions {
-            if let Expression::BinaryExpression(binary_expr) = initializer {
-                if matches!(
-                    binary_expr.operator,
-                    BinaryOperator::BitwiseOR
-                        | BinaryOperator::BitwiseAnd
-                        | BinaryOperator::BitwiseXOR
-                        | BinaryOperator::ShiftLeft
-                        | BinaryOperator::ShiftRight
-                        | BinaryOperator::ShiftRightZeroFill
-                ) && binary_expr.left.is_literal()
-                    && binary_expr.right.is_literal()
+            let Some(AstKind::TSEnumDeclaration(enum_decl)) = ctx.nodes().parent_kind(node.id())
+            else {
+                return;
+            };
+            match initializer {
+                Expression::UnaryExpression(unary_expr)
+                    if matches!(unary_expr.operator, UnaryOperator::BitwiseNot)
+                        && is_allowed_bitwise_operand(enum_decl, &unary_expr.argument) =>
+                {
+                    return;
+                }
+                Expression::BinaryExpression(binary_expr)
+                    if matches!(
+                        binary_expr.operator,
+                        BinaryOperator::BitwiseOR
+                            | BinaryOperator::BitwiseAnd
+                            | BinaryOperator::BitwiseXOR
+                            | BinaryOperator::ShiftLeft
+                            | BinaryOperator::ShiftRight
+                            | BinaryOperator::ShiftRightZeroFill
+                    ) && is_allowed_bitwise_operand(enum_decl, &binary_expr.left)
+                        && is_allowed_bitwise_operand(enum_decl, &binary_expr.right) =>
                 {
                     return;
                 }
+                _ => {}
             }
         }
 
@@ -114,6 +123,34 @@ impl Rule for PreferLiteralEnumMember {
     }
 }
 
+/// A bitwise operand is allowed to be a number literal or a member of the enum being declared,
+/// e.g. `A` or `Foo.A` in `enum Foo { A = 1 << 0, B = A | Foo.A }`.
+fn is_allowed_bitwise_operand(enum_decl: &TSEnumDeclaration, expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::NumericLiteral(_) => true,
+        Expression::Identifier(ident) => has_enum_member(enum_decl, &ident.name),
+        Expression::StaticMemberExpression(member) => {
+            matches!(&member.object, Expression::Identifier(object) if object.name == enum_decl.id.name)
+                && has_enum_member(enum_decl, &member.property.name)
+        }
+        Expression::ComputedMemberExpression(member) => {
+            matches!(&member.object, Expression::Identifier(object) if object.name == enum_decl.id.name)
+                && member
+                    .static_property_name()
+                    .is_some_and(|name| has_enum_member(enum_decl, &name))
+        }
+        _ => false,
+    }
+}
+
+fn has_enum_member(enum_decl: &TSEnumDeclaration, name: &str) -> bool {
+    enum_decl.members.iter().any(|member| match &member.id {
+        TSEnumMemberName::StaticIdentifier(ident) => ident.name == name,
+        TSEnumMemberName::StaticStringLiteral(lit) => lit.value == name,
+        _ => false,
+    })
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -213,6 +250,21 @@ fn test() {
         	      ",
             Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
         ),
+        (
+            "
+        	enum Foo {
+        	  A = 1 << 0,
+        	  B = 1 << 1,
+        	  C = A | B,
+        	  D = Foo.A | Foo.B,
+        	  E = Foo['A'] & B,
+        	  F = ~A,
+        	  'G' = 1 << 2,
+        	  H = G ^ A,
+        	}
+        	      ",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
     ];
 
     let fail = vec![
@@ -350,6 +402,26 @@ fn test() {
         	      ",
             Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
         ),
+        (
+            "
+        	enum Foo {
+        	  A = 1 << 0,
+        	  B = A | 'a',
+        	  C = Bar.A | A,
+        	  D = (A + 1) | A,
+        	}
+        	      ",
+            Some(serde_json::json!([{ "allowBitwiseExpressions": true }])),
+        ),
+        (
+            "
+        	enum Foo {
+        	  A = 1 << 0,
+        	  B = A | A,
+        	}
+        	      ",
+            None,
+        ),
     ];
 
     Tester::new(PreferLiteralEnumMember::NAME, pass, fail).test_and_snapshot();
@@ -0,0 +1,119 @@
+use oxc_ast::{
+    ast::{TSModuleDeclarationKind, TSModuleDeclarationName},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn prefer_namespace_keyword_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Use 'namespace' instead of 'module' to declare custom TypeScript modules.")
+        .with_help("Replace the 'module' keyword with 'namespace'.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferNamespaceKeyword;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require using `namespace` keyword over `module` keyword to declare custom TypeScript modules.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// TypeScript historically allowed a form of code organization called "custom modules"
+    /// (`module Example {}`), later renamed to "namespaces" (`namespace Example {}`).
+    /// The `module` keyword is now reserved for declaring external modules
+    /// (`declare module 'example' {}`), so using it for namespaces is confusing.
+    ///
+    /// ### Example
+    /// ```ts
+    /// module Example {}
+    /// ```
+    PreferNamespaceKeyword,
+    style
+);
+
+impl Rule for PreferNamespaceKeyword {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::TSModuleDeclaration(module) = node.kind() else {
+            return;
+        };
+        if module.kind != TSModuleDeclarationKind::Module {
+            return;
+        }
+        // `declare module 'foo' {}` declares an external module
+        let TSModuleDeclarationName::Identifier(id) = &module.id else {
+            return;
+        };
+
+        // The keyword isn't repeated for the inner declarations of `module A.B {}`
+        let before_id = Span::new(module.span.start, id.span().start);
+        let Some(offset) = ctx.source_range(before_id).rfind("module") else {
+            return;
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        let keyword_start = before_id.start + offset as u32;
+        let keyword = Span::new(keyword_start, keyword_start + 6);
+
+        ctx.diagnostic_with_fix(prefer_namespace_keyword_diagnostic(module.span), |fixer| {
+            fixer.replace(keyword, "namespace")
+        });
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "declare module 'foo';",
+        "declare module 'foo' {}",
+        "namespace foo {}",
+        "declare namespace foo {}",
+        "declare global {}",
+        "namespace foo.bar {}",
+    ];
+
+    let fail = vec![
+        "module foo {}",
+        "declare module foo {}",
+        "
+            declare module foo {
+              declare module bar {}
+            }
+        ",
+        "module foo.bar {}",
+        "export module foo {}",
+    ];
+
+    let fix = vec![
+        ("module foo {}", "namespace foo {}", None),
+        ("declare module foo {}", "declare namespace foo {}", None),
+        ("declare  module foo {}", "declare  namespace foo {}", None),
+        (
+            "
+            declare module foo {
+              declare module bar {}
+            }
+            ",
+            "
+            declare namespace foo {
+              declare namespace bar {}
+            }
+            ",
+            None,
+        ),
+        ("module foo.bar {}", "namespace foo.bar {}", None),
+        ("export module foo {}", "export namespace foo {}", None),
+    ];
+
+    Tester::new(PreferNamespaceKeyword::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
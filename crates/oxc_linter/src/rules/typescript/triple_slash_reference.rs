@@ -113,22 +113,21 @@ impl Rule for TripleSlashReference {
         let mut refs_for_import = HashMap::new();
 
         for comment in ctx.semantic().trivias().comments_range(0..comments_range_end) {
-            let raw = &ctx.semantic().source_text()
-                [comment.span.start as usize..comment.span.end as usize];
-            if let Some((group1, group2)) = get_attr_key_and_value(raw) {
-                if (group1 == "types" && self.types == TypesOption::Never)
-                    || (group1 == "path" && self.path == PathOption::Never)
-                    || (group1 == "lib" && self.lib == LibOption::Never)
+            if !comment.kind.is_single_line() {
+                continue;
+            }
+            let raw = ctx.source_range(comment.span);
+            if let Some((kind, target)) = parse_reference_directive(raw) {
+                let span = Span::new(comment.span.start - 2, comment.span.end);
+                if (kind == "types" && self.types == TypesOption::Never)
+                    || (kind == "path" && self.path == PathOption::Never)
+                    || (kind == "lib" && self.lib == LibOption::Never)
                 {
-                    ctx.diagnostic(triple_slash_reference_diagnostic(
-                        &group2,
-                        Span::new(comment.span.start - 2, comment.span.end),
-                    ));
+                    ctx.diagnostic(triple_slash_reference_diagnostic(target, span));
                 }
 
-                if group1 == "types" && self.types == TypesOption::PreferImport {
-                    refs_for_import
-                        .insert(group2, Span::new(comment.span.start - 2, comment.span.end));
+                if kind == "types" && self.types == TypesOption::PreferImport {
+                    refs_for_import.insert(target, span);
                 }
             }
         }
@@ -168,44 +167,16 @@ impl Rule for TripleSlashReference {
     }
 }
 
-fn get_attr_key_and_value(raw: &str) -> Option<(String, String)> {
-    if !raw.starts_with('/') {
-        return None;
-    }
-
-    let reference_start = "<reference ";
-    let reference_end = "/>";
-
-    if let Some(start_idx) = raw.find(reference_start) {
-        // Check if the string contains '/>' after the start index
-        if let Some(end_idx) = raw[start_idx..].find(reference_end) {
-            let reference_str = &raw[start_idx + reference_start.len()..start_idx + end_idx];
-
-            // Split the string by whitespaces
-            let parts = reference_str.split_whitespace();
-
-            // Filter parts that start with attribute key pattern
-            let filtered_parts: Vec<&str> = parts
-                .into_iter()
-                .filter(|part| {
-                    part.starts_with("types=")
-                        || part.starts_with("path=")
-                        || part.starts_with("lib=")
-                })
-                .collect();
-
-            if let Some(attr) = filtered_parts.first() {
-                // Split the attribute by '=' to get key and value
-                let attr_parts: Vec<&str> = attr.split('=').collect();
-                if attr_parts.len() == 2 {
-                    let key = attr_parts[0].trim().trim_matches('"').to_string();
-                    let value = attr_parts[1].trim_matches('"').trim_end_matches('/').to_string();
-                    return Some((key, value));
-                }
-            }
-        }
-    }
-    None
+/// Parses the text of a `/// <reference kind="target" />` directive, returning the kind of the
+/// reference (`types`, `path` or `lib`) and its target.
+fn parse_reference_directive(raw: &str) -> Option<(&str, &str)> {
+    let rest = raw.strip_prefix('/')?.trim_start().strip_prefix("<reference")?.trim_start();
+    let kind = ["types", "path", "lib"].into_iter().find(|kind| rest.starts_with(kind))?;
+    let rest = rest[kind.len()..].trim_start().strip_prefix('=')?.trim_start();
+    let quote = rest.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+    let rest = &rest[1..];
+    let end = rest.find(quote)?;
+    Some((kind, &rest[..end]))
 }
 
 #[test]
@@ -303,6 +274,28 @@ fn test() {
         	      "#,
             Some(serde_json::json!([{ "path": "never", "types": "never", "lib": "never" }])),
         ),
+        (
+            r#"
+        	        /* <reference types="foo" /> */
+        	        import * as foo from 'foo';
+        	      "#,
+            Some(serde_json::json!([{ "path": "never", "types": "never", "lib": "never" }])),
+        ),
+        (
+            r#"
+        	        /// <reference no-default-lib="true" />
+        	        /// <amd-module name="foo" />
+        	        import * as foo from 'foo';
+        	      "#,
+            Some(serde_json::json!([{ "path": "never", "types": "never", "lib": "never" }])),
+        ),
+        (
+            r#"
+        	        /// <reference types="foo" />
+        	        import * as foo from 'foobar';
+        	      "#,
+            Some(serde_json::json!([{ "types": "prefer-import" }])),
+        ),
     ];
 
     let fail = vec![
@@ -323,6 +316,27 @@ fn test() {
         (r#"/// <reference path="foo" />"#, Some(serde_json::json!([{ "path": "never" }]))),
         (r#"/// <reference types="foo" />"#, Some(serde_json::json!([{ "types": "never" }]))),
         (r#"/// <reference lib="foo" />"#, Some(serde_json::json!([{ "lib": "never" }]))),
+        (r"/// <reference types='foo' />", Some(serde_json::json!([{ "types": "never" }]))),
+        (r#"/// <reference types="foo"/>"#, Some(serde_json::json!([{ "types": "never" }]))),
+        (
+            r#"///<reference  path = "./foo.d.ts" />"#,
+            Some(serde_json::json!([{ "path": "never" }])),
+        ),
+        (
+            r"
+        	/// <reference types='foo' />
+        	import * as foo from 'foo';
+        	      ",
+            Some(serde_json::json!([{ "types": "prefer-import" }])),
+        ),
+        (
+            r#"
+        	/// <reference types="foo" />
+        	/// <reference lib="es2017" />
+        	import * as foo from 'foo';
+        	      "#,
+            Some(serde_json::json!([{ "types": "prefer-import", "lib": "never" }])),
+        ),
     ];
 
     Tester::new(TripleSlashReference::NAME, pass, fail).test_and_snapshot();
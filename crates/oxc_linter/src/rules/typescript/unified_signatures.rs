@@ -0,0 +1,571 @@
+use oxc_ast::{
+    ast::{
+        ClassElement, Declaration, ExportDefaultDeclarationKind, FormalParameters, Function,
+        PropertyKey, Statement, TSSignature, TSTypeAnnotation, TSTypeName,
+        TSTypeParameterDeclaration, TSTypeReference,
+    },
+    visit::walk::walk_ts_type_reference,
+    AstKind, Visit,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+fn single_parameter_difference_diagnostic(
+    type0: &str,
+    type1: &str,
+    span0: Span,
+    span1: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "These overloads can be combined into one signature taking `{type0} | {type1}`."
+    ))
+    .with_labels([span0, span1])
+}
+
+fn omitting_parameter_diagnostic(kind: &str, span0: Span, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "These overloads can be combined into one signature with {kind} parameter."
+    ))
+    .with_labels([span0, span1])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct UnifiedSignatures {
+    ignore_differently_named_parameters: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow two overloads that could be unified into one with a union or an optional/rest parameter.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Function overload signatures are a TypeScript way to define a function that can be called
+    /// in multiple very different ways. Overload signatures that only differ by a single parameter
+    /// type, or by an extra optional parameter, can be written as a single signature instead,
+    /// which is easier to read and maintain.
+    ///
+    /// ### Example
+    /// ```ts
+    /// function x(x: number): void;
+    /// function x(x: string): void;
+    ///
+    /// function y(): void;
+    /// function y(...x: number[]): void;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `ignoreDifferentlyNamedParameters`: `boolean` (default `false`) skips overloads whose
+    /// parameters at the same position have different names.
+    UnifiedSignatures,
+    style
+);
+
+/// A parameter of an overload signature, including the rest parameter.
+struct Param<'a> {
+    span: Span,
+    name: Option<Atom<'a>>,
+    type_annotation: Option<&'a TSTypeAnnotation<'a>>,
+    optional: bool,
+    rest: bool,
+}
+
+impl<'a> Param<'a> {
+    fn may_be_missing(&self) -> bool {
+        self.optional || self.rest
+    }
+
+    fn has_equal_sigils(&self, other: &Self) -> bool {
+        self.optional == other.optional && self.rest == other.rest
+    }
+}
+
+struct Signature<'a> {
+    span: Span,
+    this_type: Option<&'a TSTypeAnnotation<'a>>,
+    params: Vec<Param<'a>>,
+    return_type: Option<&'a TSTypeAnnotation<'a>>,
+    type_parameters: Option<&'a TSTypeParameterDeclaration<'a>>,
+}
+
+impl<'a> Signature<'a> {
+    fn new(
+        span: Span,
+        this_type: Option<&'a TSTypeAnnotation<'a>>,
+        params: &'a FormalParameters<'a>,
+        return_type: Option<&'a TSTypeAnnotation<'a>>,
+        type_parameters: Option<&'a TSTypeParameterDeclaration<'a>>,
+    ) -> Self {
+        let mut list: Vec<Param> = params
+            .items
+            .iter()
+            .map(|param| Param {
+                span: param.span,
+                name: param.pattern.get_identifier(),
+                type_annotation: param.pattern.type_annotation.as_deref(),
+                optional: param.pattern.optional,
+                rest: false,
+            })
+            .collect();
+        if let Some(rest) = &params.rest {
+            list.push(Param {
+                span: rest.span,
+                name: rest.argument.get_identifier(),
+                type_annotation: rest.argument.type_annotation.as_deref(),
+                optional: false,
+                rest: true,
+            });
+        }
+        Self { span, this_type, params: list, return_type, type_parameters }
+    }
+
+    fn from_function(func: &'a Function<'a>) -> Self {
+        Self::new(
+            func.span,
+            func.this_param.as_ref().and_then(|param| param.type_annotation.as_deref()),
+            &func.params,
+            func.return_type.as_deref(),
+            func.type_parameters.as_deref(),
+        )
+    }
+}
+
+enum Unify<'s, 'a> {
+    SingleParameterDifference { p0: &'s Param<'a>, p1: &'s Param<'a> },
+    ExtraParameter { extra: &'s Param<'a>, other: &'s Signature<'a> },
+}
+
+/// Overload signatures grouped by the name they overload, in source order.
+#[derive(Default)]
+struct Overloads<'a> {
+    groups: Vec<(String, Vec<Signature<'a>>)>,
+}
+
+impl<'a> Overloads<'a> {
+    fn add(&mut self, key: String, signature: Signature<'a>) {
+        if let Some((_, group)) = self.groups.iter_mut().find(|(k, _)| *k == key) {
+            group.push(signature);
+        } else {
+            self.groups.push((key, vec![signature]));
+        }
+    }
+
+    fn add_statement(&mut self, stmt: &'a Statement<'a>) {
+        let func = match stmt {
+            Statement::FunctionDeclaration(func) => func,
+            Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+                Some(Declaration::FunctionDeclaration(func)) => func,
+                _ => return,
+            },
+            Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => func,
+                _ => return,
+            },
+            _ => return,
+        };
+        if func.body.is_some() {
+            return;
+        }
+        let key = func.id.as_ref().map_or_else(|| "default".to_string(), |id| id.name.to_string());
+        self.add(key, Signature::from_function(func));
+    }
+
+    fn add_class_element(&mut self, element: &'a ClassElement<'a>, ctx: &LintContext<'a>) {
+        let ClassElement::MethodDefinition(def) = element else {
+            return;
+        };
+        if def.value.body.is_some() {
+            return;
+        }
+        let key = format!(
+            "{}{}",
+            if def.r#static { "static " } else { "" },
+            get_key_text(&def.key, def.computed, ctx)
+        );
+        self.add(key, Signature::from_function(&def.value));
+    }
+
+    fn add_signature(&mut self, signature: &'a TSSignature<'a>, ctx: &LintContext<'a>) {
+        match signature {
+            TSSignature::TSMethodSignature(sig) => {
+                self.add(
+                    get_key_text(&sig.key, sig.computed, ctx),
+                    Signature::new(
+                        sig.span,
+                        sig.this_param.as_ref().and_then(|param| param.type_annotation.as_deref()),
+                        &sig.params,
+                        sig.return_type.as_deref(),
+                        sig.type_parameters.as_deref(),
+                    ),
+                );
+            }
+            TSSignature::TSCallSignatureDeclaration(sig) => {
+                self.add(
+                    "()".to_string(),
+                    Signature::new(
+                        sig.span,
+                        sig.this_param.as_ref().and_then(|param| param.type_annotation.as_deref()),
+                        &sig.params,
+                        sig.return_type.as_deref(),
+                        sig.type_parameters.as_deref(),
+                    ),
+                );
+            }
+            TSSignature::TSConstructSignatureDeclaration(sig) => {
+                self.add(
+                    "new".to_string(),
+                    Signature::new(
+                        sig.span,
+                        None,
+                        &sig.params,
+                        sig.return_type.as_deref(),
+                        sig.type_parameters.as_deref(),
+                    ),
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+fn get_key_text(key: &PropertyKey, computed: bool, ctx: &LintContext) -> String {
+    let text = ctx.source_range(key.span());
+    if computed {
+        format!("[{text}]")
+    } else {
+        text.to_string()
+    }
+}
+
+impl Rule for UnifiedSignatures {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            ignore_differently_named_parameters: value
+                .get(0)
+                .and_then(|config| config.get("ignoreDifferentlyNamedParameters"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let mut overloads = Overloads::default();
+        let type_parameters = match node.kind() {
+            AstKind::Program(program) => {
+                program.body.iter().for_each(|stmt| overloads.add_statement(stmt));
+                None
+            }
+            AstKind::TSModuleBlock(block) => {
+                block.body.iter().for_each(|stmt| overloads.add_statement(stmt));
+                None
+            }
+            AstKind::Class(class) => {
+                class.body.body.iter().for_each(|el| overloads.add_class_element(el, ctx));
+                class.type_parameters.as_deref()
+            }
+            AstKind::TSInterfaceDeclaration(decl) => {
+                decl.body.body.iter().for_each(|sig| overloads.add_signature(sig, ctx));
+                decl.type_parameters.as_deref()
+            }
+            AstKind::TSTypeLiteral(literal) => {
+                literal.members.iter().for_each(|sig| overloads.add_signature(sig, ctx));
+                None
+            }
+            _ => return,
+        };
+
+        let type_parameter_names: Vec<&str> = type_parameters
+            .map(|decl| decl.params.iter().map(|param| param.name.name.as_str()).collect())
+            .unwrap_or_default();
+
+        for (_, group) in &overloads.groups {
+            for (i, a) in group.iter().enumerate() {
+                for b in &group[i + 1..] {
+                    let Some(unify) = self.compare_signatures(a, b, &type_parameter_names, ctx)
+                    else {
+                        continue;
+                    };
+                    match unify {
+                        Unify::SingleParameterDifference { p0, p1 } => {
+                            ctx.diagnostic(single_parameter_difference_diagnostic(
+                                get_type_text(p0.type_annotation, ctx),
+                                get_type_text(p1.type_annotation, ctx),
+                                p0.span,
+                                p1.span,
+                            ));
+                        }
+                        Unify::ExtraParameter { extra, other } => {
+                            ctx.diagnostic(omitting_parameter_diagnostic(
+                                if extra.rest { "a rest" } else { "an optional" },
+                                other.span,
+                                extra.span,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        ctx.source_type().is_typescript()
+    }
+}
+
+impl UnifiedSignatures {
+    fn compare_signatures<'s, 'a>(
+        &self,
+        a: &'s Signature<'a>,
+        b: &'s Signature<'a>,
+        type_parameter_names: &[&str],
+        ctx: &LintContext<'a>,
+    ) -> Option<Unify<'s, 'a>> {
+        if !self.signatures_can_be_unified(a, b, type_parameter_names, ctx) {
+            return None;
+        }
+        if a.params.len() == b.params.len() {
+            signatures_differ_by_single_parameter(&a.params, &b.params, ctx)
+        } else {
+            signatures_differ_by_optional_or_rest_parameter(a, b, ctx)
+        }
+    }
+
+    fn signatures_can_be_unified(
+        &self,
+        a: &Signature,
+        b: &Signature,
+        type_parameter_names: &[&str],
+        ctx: &LintContext,
+    ) -> bool {
+        if self.ignore_differently_named_parameters
+            && a.params
+                .iter()
+                .zip(&b.params)
+                .any(|(a, b)| a.rest == b.rest && a.name.as_ref() != b.name.as_ref())
+        {
+            return false;
+        }
+
+        types_are_equal(a.return_type, b.return_type, ctx)
+            && types_are_equal(a.this_type, b.this_type, ctx)
+            // Must take the same type parameters.
+            && type_parameters_are_equal(a.type_parameters, b.type_parameters, ctx)
+            // If one uses a type parameter (from outside) and the other doesn't, they shouldn't be joined.
+            && signature_uses_type_parameter(a, type_parameter_names)
+                == signature_uses_type_parameter(b, type_parameter_names)
+    }
+}
+
+/// Detect `a(x: number, y: number, z: number)` and `a(x: number, y: string, z: number)`.
+fn signatures_differ_by_single_parameter<'s, 'a>(
+    params0: &'s [Param<'a>],
+    params1: &'s [Param<'a>],
+    ctx: &LintContext<'a>,
+) -> Option<Unify<'s, 'a>> {
+    let index = params0.iter().zip(params1).position(|(a, b)| !parameters_are_equal(a, b, ctx))?;
+    // If the remaining parameters are equal, the signatures differ by just one parameter type
+    if !params0[index + 1..]
+        .iter()
+        .zip(&params1[index + 1..])
+        .all(|(a, b)| parameters_are_equal(a, b, ctx))
+    {
+        return None;
+    }
+    let (p0, p1) = (&params0[index], &params1[index]);
+    // Can unify `a?: string` and `b?: number`. Can't unify `...args: string[]` and `...args: number[]`.
+    (p0.has_equal_sigils(p1) && !p0.rest).then_some(Unify::SingleParameterDifference { p0, p1 })
+}
+
+/// Detect `a(): void` and `a(x: number): void`.
+/// Returns the parameter declaration (`x: number` in this example) that should be optional/rest.
+fn signatures_differ_by_optional_or_rest_parameter<'s, 'a>(
+    a: &'s Signature<'a>,
+    b: &'s Signature<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<Unify<'s, 'a>> {
+    let (shorter, longer) = if a.params.len() < b.params.len() { (a, b) } else { (b, a) };
+    let min_length = shorter.params.len();
+
+    // If one has 2+ parameters more than the other, they must all be optional/rest.
+    // Differ by optional parameters: f() and f(x), f() and f(x, ?y, ...z)
+    // Not allowed: f() and f(x, y)
+    if !longer.params.iter().skip(min_length + 1).all(Param::may_be_missing) {
+        return None;
+    }
+    if !shorter
+        .params
+        .iter()
+        .zip(&longer.params)
+        .all(|(a, b)| types_are_equal(a.type_annotation, b.type_annotation, ctx))
+    {
+        return None;
+    }
+    if shorter.params.last().is_some_and(|param| param.rest) {
+        return None;
+    }
+
+    longer.params.last().map(|extra| Unify::ExtraParameter { extra, other: shorter })
+}
+
+fn parameters_are_equal(a: &Param, b: &Param, ctx: &LintContext) -> bool {
+    a.has_equal_sigils(b) && types_are_equal(a.type_annotation, b.type_annotation, ctx)
+}
+
+fn types_are_equal(
+    a: Option<&TSTypeAnnotation>,
+    b: Option<&TSTypeAnnotation>,
+    ctx: &LintContext,
+) -> bool {
+    match (a, b) {
+        (None, None) => true,
+        (Some(a), Some(b)) => {
+            ctx.source_range(a.type_annotation.span()) == ctx.source_range(b.type_annotation.span())
+        }
+        _ => false,
+    }
+}
+
+fn type_parameters_are_equal(
+    a: Option<&TSTypeParameterDeclaration>,
+    b: Option<&TSTypeParameterDeclaration>,
+    ctx: &LintContext,
+) -> bool {
+    match (a, b) {
+        (None, None) => true,
+        (Some(a), Some(b)) => {
+            a.params.len() == b.params.len()
+                && a.params.iter().zip(&b.params).all(|(a, b)| {
+                    a.name.name == b.name.name
+                        && a.constraint.as_ref().map(|t| ctx.source_range(t.span()))
+                            == b.constraint.as_ref().map(|t| ctx.source_range(t.span()))
+                })
+        }
+        _ => false,
+    }
+}
+
+fn get_type_text<'a>(annotation: Option<&TSTypeAnnotation>, ctx: &LintContext<'a>) -> &'a str {
+    annotation.map_or("any", |annotation| ctx.source_range(annotation.type_annotation.span()))
+}
+
+fn signature_uses_type_parameter(signature: &Signature, type_parameter_names: &[&str]) -> bool {
+    if type_parameter_names.is_empty() {
+        return false;
+    }
+    let mut finder = TypeParameterFinder { names: type_parameter_names, found: false };
+    for annotation in signature.params.iter().filter_map(|param| param.type_annotation) {
+        finder.visit_ts_type_annotation(annotation);
+    }
+    finder.found
+}
+
+struct TypeParameterFinder<'n> {
+    names: &'n [&'n str],
+    found: bool,
+}
+
+impl<'a, 'n> Visit<'a> for TypeParameterFinder<'n> {
+    fn visit_ts_type_reference(&mut self, it: &TSTypeReference<'a>) {
+        if matches!(&it.type_name, TSTypeName::IdentifierReference(ident) if self.names.contains(&ident.name.as_str()))
+        {
+            self.found = true;
+            return;
+        }
+        walk_ts_type_reference(self, it);
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function g(): void;\nfunction g(a: number, b: number): void;\nfunction g(a?: number, b?: number): void {}", None),
+        ("function rest(...xs: number[]): void;\nfunction rest(xs: number[], y: string): void;\nfunction rest(...args: any[]) {}", None),
+        ("class C {\n  constructor();\n  constructor(a: number, b: number);\n  constructor(a?: number, b?: number) {}\n  a(): void;\n  a(a: number, b: number): void;\n  a(a?: number, b?: number): void {}\n}", None),
+        // No error for arity difference greater than 1.
+        ("interface I {\n  a2(): void;\n  a2(x: number, y: number): void;\n}", None),
+        // No error for different return types.
+        ("interface I {\n  a4(): void;\n  a4(x: number): number;\n}", None),
+        // No error if one takes a type parameter and the other doesn't.
+        ("interface I {\n  a5<T>(x: T): T;\n  a5(x: number): number;\n}", None),
+        // No error if one is a rest parameter and other isn't.
+        ("interface I {\n  b2(x: string): void;\n  b2(...x: number[]): void;\n}", None),
+        // No error if both are rest parameters. (https://github.com/Microsoft/TypeScript/issues/5077)
+        ("interface I {\n  b3(...x: number[]): void;\n  b3(...x: string[]): void;\n}", None),
+        // No error if one is optional and the other isn't.
+        ("interface I {\n  c3(x: number): void;\n  c3(x?: string): void;\n}", None),
+        // No error if they differ by 2 or more parameters.
+        ("interface I {\n  d2(x: string, y: number): void;\n  d2(x: number, y: string): void;\n}", None),
+        // No conflict between static/non-static members.
+        ("declare class D {\n  static a();\n  a(x: number);\n}", None),
+        // Allow separate overloads if one is generic and the other isn't.
+        ("interface Generic<T> {\n  x(): void;\n  x(x: T[]): void;\n}", None),
+        // Allow signatures if the type is not equal.
+        ("interface I {\n  f(x1: number): void;\n  f(x1: boolean, x2?: number): void;\n}", None),
+        // AllowType parameters that are not equal
+        ("function f<T extends number>(x: T[]): void;\nfunction f<T extends string>(x: T): void;", None),
+        // Same name, different scopes
+        ("declare function foo(n: number): number;\ndeclare module 'hello' {\n  function foo(n: number, s: string): number;\n}", None),
+        // children of block not checked to match TSLint
+        ("{\n  function block(): number;\n  function block(n: number): number;\n  function block(n?: number): number {\n    return 3;\n  }\n}", None),
+        ("export interface Foo {\n  bar(baz: string): number[];\n  bar(): string[];\n}", None),
+        ("declare module 'foo' {\n  export default function (foo: number): string[];\n}", None),
+        ("export default function (foo: number): string[];", None),
+        ("function p(key: string): Map<string, string>;\nfunction p(key: string, defaultValue: string): string;\nfunction p(key: string, defaultValue: string | null): string | null;", None),
+        ("function f(a: number): void;\nfunction f(b: string): void;\nfunction f(a: number | string): void {}", Some(serde_json::json!([{ "ignoreDifferentlyNamedParameters": true }]))),
+        ("function f(a: boolean, ...c: number[]): void;\nfunction f(a: boolean, ...d: string[]): void;\nfunction f(a: boolean, ...c: (number | string)[]): void {}", Some(serde_json::json!([{ "ignoreDifferentlyNamedParameters": true }]))),
+        ("class C {\n  get foo(): string;\n  set foo(value: string);\n}", None),
+        ("interface I {\n  f(this: A): void;\n  f(this: B): void;\n}", None),
+    ];
+
+    let fail = vec![
+        ("function f(a: number): void;\nfunction f(b: string): void;\nfunction f(a: number | string): void {}", None),
+        ("function f(a: number): void;\nfunction f(a: string): void;\nfunction f(a: number | string): void {}", Some(serde_json::json!([{ "ignoreDifferentlyNamedParameters": true }]))),
+        ("function opt(xs?: number[]): void;\nfunction opt(xs: number[], y: string): void;\nfunction opt(...args: any[]) {}", None),
+        ("interface I {\n  a0(): void;\n  a0(x: string): string;\n  a0(x: number): void;\n}", None),
+        // Error for extra parameter.
+        ("interface I {\n  a1(): void;\n  a1(x: number): void;\n}", None),
+        // Error for arity difference greater than 1 if the additional parameters are all optional/rest.
+        ("interface I {\n  a3(): void;\n  a3(x: number, y?: number, ...z: number[]): void;\n}", None),
+        // Error if only one defines a rest parameter.
+        ("interface I {\n  b(): void;\n  b(...x: number[]): void;\n}", None),
+        // Error if only one defines an optional parameter.
+        ("interface I {\n  c(): void;\n  c(x?: number): void;\n}", None),
+        // Error if both define optional parameters.
+        ("interface I {\n  c2(x?: number): void;\n  c2(x?: string): void;\n}", None),
+        // Error for different types (could be a union)
+        ("interface I {\n  d(x: number): void;\n  d(x: string): void;\n}", None),
+        // Works for type literal and call signature too.
+        ("type T = {\n  (): void;\n  (x: number): void;\n};", None),
+        // Works for constructor.
+        ("declare class C {\n  constructor();\n  constructor(x: number);\n}", None),
+        // Works with unions.
+        ("interface I {\n  f(x: number);\n  f(x: string | boolean);\n}", None),
+        // Works with parameter properties. Note that this is invalid TypeScript, but the rule should still report it.
+        ("interface I {\n  f(x: number);\n  f(x: [string, boolean]);\n}", None),
+        ("interface I {\n  f(x: number);\n  f(x: { a: string });\n}", None),
+        ("interface Generic<T> {\n  y(x: T[]): void;\n  y(x: T): void;\n}", None),
+        // Check type parameters when equal
+        ("function f<T>(x: T[]): void;\nfunction f<T>(x: T): void;", None),
+        // Verifies type parameters and constraints
+        ("function f<T extends number>(x: T[]): void;\nfunction f<T extends number>(x: T): void;", None),
+        // Works with abstract
+        ("abstract class Foo {\n  public abstract f(x: number): void;\n  public abstract f(x: string): void;\n}", None),
+        // Works with literals
+        ("interface Foo {\n  'f'(x: string): void;\n  'f'(x: number): void;\n}", None),
+        // Works with static methods
+        ("class Foo {\n  static f(x: number): void;\n  static f(x: string): void;\n  static f(x: number | string): void {}\n}", None),
+        // Works in modules
+        ("declare module 'foo' {\n  function f(x: number): void;\n  function f(x: string): void;\n}", None),
+        ("export function foo(line: number): number;\nexport function foo(line: number, character?: number): number;", None),
+        ("declare function foo(line: number): number;\nexport function foo(line: number, character?: number): number;", None),
+    ];
+
+    Tester::new(UnifiedSignatures::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,10 +1,10 @@
 use oxc_ast::{
-    ast::{match_member_expression, Expression},
+    ast::{match_member_expression, Expression, MemberExpression},
     AstKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::BinaryOperator;
 use phf::phf_set;
 
@@ -45,6 +45,10 @@ declare_oxc_lint!(
     /// const foo = String('hello world');
     /// const bar = new Array(1, 2, 3);
     /// ```
+    ///
+    /// Missing and redundant `new` are fixed automatically, except for `String`, `Number`
+    /// and `Boolean`, where removing `new` would change the result, and `Date`, where
+    /// `String(new Date())` is suggested since `Date()` returns a string.
     NewForBuiltins,
     pedantic
 );
@@ -59,11 +63,44 @@ impl Rule for NewForBuiltins {
                     return;
                 };
 
-                if DISALLOW_NEW_FOR_BUILTINS.contains(builtin_name) {
-                    ctx.diagnostic(disallow(new_expr.span, builtin_name));
+                if !DISALLOW_NEW_FOR_BUILTINS.contains(builtin_name) {
+                    return;
                 }
+                let diagnostic = disallow(new_expr.span, builtin_name);
+                // `new String()` creates a wrapper object, unlike `String()`
+                if matches!(builtin_name, "String" | "Number" | "Boolean") {
+                    ctx.diagnostic(diagnostic);
+                    return;
+                }
+                ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                    let callee_span = new_expr.callee.span();
+                    // Keep comments between `new` and the callee
+                    let gap = fixer.source_range(Span::new(new_expr.span.start, callee_span.start));
+                    // Drop the parentheses around the callee, so that a leading `(` can't
+                    // continue the previous line
+                    let mut replacement = format!(
+                        "{}{}{}",
+                        gap["new".len()..].trim_start(),
+                        fixer.source_range(callee.span()),
+                        fixer.source_range(Span::new(callee_span.end, new_expr.span.end)),
+                    );
+                    if new_expr.span.end == callee_span.end {
+                        replacement.push_str("()");
+                    }
+                    fixer.replace(new_expr.span, replacement)
+                });
             }
             AstKind::CallExpression(call_expr) => {
+                // `new` can't be applied to an optional call or optional chain
+                if call_expr.optional
+                    || call_expr
+                        .callee
+                        .without_parenthesized()
+                        .as_member_expression()
+                        .is_some_and(MemberExpression::optional)
+                {
+                    return;
+                }
                 let Some(builtin_name) =
                     is_expr_global_builtin(call_expr.callee.without_parenthesized(), ctx)
                 else {
@@ -83,7 +120,24 @@ impl Rule for NewForBuiltins {
                         }
                     }
 
-                    ctx.diagnostic(enforce(call_expr.span, builtin_name));
+                    let diagnostic = enforce(call_expr.span, builtin_name);
+                    // `Date()` returns a string, unlike `new Date()`
+                    if builtin_name == "Date" {
+                        ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                            fixer
+                                .replace(call_expr.span, "String(new Date())")
+                                .with_message("Switch to `String(new Date())`.")
+                        });
+                        return;
+                    }
+                    // Replace the callee rather than inserting before the call, so the fix
+                    // overlaps other fixes that add `new`, like unicorn/throw-new-error's
+                    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                        let callee = call_expr.callee.span();
+                        fixer
+                            .replace(callee, format!("new {}", fixer.source_range(callee)))
+                            .with_message("Insert `new `")
+                    });
                 }
             }
             _ => {}
@@ -163,6 +217,9 @@ fn test() {
 
     let pass = vec![
         r"const foo = new Object()",
+        r"const foo = Map?.()",
+        r"const foo = globalThis?.Map()",
+        r"const foo = globalThis.Map?.()",
         r"const foo = new Array()",
         r"const foo = new ArrayBuffer()",
         r"const foo = new BigInt64Array()",
@@ -284,5 +341,54 @@ fn test() {
         ",
     ];
 
-    Tester::new(NewForBuiltins::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r"const foo = Map()", r"const foo = new Map()", None),
+        (r"const foo = (( Map ))()", r"const foo = new (( Map ))()", None),
+        (r"const foo = globalThis.Array(1)", r"const foo = new globalThis.Array(1)", None),
+        (r"const foo = Object(bar)", r"const foo = new Object(bar)", None),
+        (r"const foo = Date()", r"const foo = Date()", None),
+        (r"Map(); const foo = Map?.()", r"new Map(); const foo = Map?.()", None),
+        (
+            r"Map(); const foo = globalThis?.Map()",
+            r"new Map(); const foo = globalThis?.Map()",
+            None,
+        ),
+        (
+            r"Map(); const foo = globalThis.Map?.()",
+            r"new Map(); const foo = globalThis.Map?.()",
+            None,
+        ),
+        (r#"const symbol = new Symbol("")"#, r#"const symbol = Symbol("")"#, None),
+        (r#"const symbol = new (Symbol)("")"#, r#"const symbol = Symbol("")"#, None),
+        (
+            r#"const symbol = new /* comment */ Symbol("")"#,
+            r#"const symbol = /* comment */ Symbol("")"#,
+            None,
+        ),
+        (r"const symbol = new Symbol;", r"const symbol = Symbol();", None),
+        (r"const foo = new window.BigInt(1)", r"const foo = window.BigInt(1)", None),
+        ("foo\nnew (Symbol)()", "foo\nSymbol()", None),
+        ("foo\nnew (( Symbol ))", "foo\nSymbol()", None),
+        (r"if (foo) new (Symbol)()", r"if (foo) Symbol()", None),
+        (r"const foo = new String()", r"const foo = new String()", None),
+        (r"const foo = new Number('123')", r"const foo = new Number('123')", None),
+        (r"const foo = new Boolean()", r"const foo = new Boolean()", None),
+    ];
+
+    Tester::new(NewForBuiltins::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
+
+#[test]
+fn test_with_throw_new_error() {
+    use crate::tester::Tester;
+
+    let fix = vec![
+        (r#"throw Error("x")"#, r#"throw new Error("x")"#, None),
+        (r#"throw globalThis.TypeError("x")"#, r#"throw new globalThis.TypeError("x")"#, None),
+    ];
+
+    Tester::new(NewForBuiltins::NAME, Vec::<&str>::new(), vec![])
+        .with_rule("throw-new-error")
+        .expect_fix(fix)
+        .test();
 }
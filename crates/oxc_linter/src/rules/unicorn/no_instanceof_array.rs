@@ -41,12 +41,24 @@ impl Rule for NoInstanceofArray {
         }
 
         match &expr.right.without_parenthesized() {
-            Expression::Identifier(identifier) if identifier.name == "Array" => {
+            Expression::Identifier(identifier)
+                if identifier.name == "Array"
+                    && ctx.semantic().is_reference_to_global_variable(identifier) =>
+            {
                 ctx.diagnostic_with_fix(no_instanceof_array_diagnostic(expr.span), |fixer| {
+                    let left = expr.left.without_parenthesized();
                     let modified_code = {
                         let mut codegen = String::new();
                         codegen.push_str("Array.isArray(");
-                        codegen.push_str(fixer.source_range(expr.left.span()));
+                        // A sequence would be split into several arguments
+                        let is_sequence = matches!(left, Expression::SequenceExpression(_));
+                        if is_sequence {
+                            codegen.push('(');
+                        }
+                        codegen.push_str(fixer.source_range(left.span()));
+                        if is_sequence {
+                            codegen.push(')');
+                        }
                         codegen.push(')');
                         codegen
                     };
@@ -71,6 +83,11 @@ fn test() {
         ("a.x[2] instanceof foo()", None),
         ("Array.isArray([1,2,3]) === true", None),
         ("\"arr instanceof Array\"", None),
+        ("function foo(Array) { return arr instanceof Array }", None),
+        ("const Array = MyArray; arr instanceof Array", None),
+        ("import { Array } from 'immutable'; arr instanceof Array", None),
+        ("class Array {} arr instanceof Array", None),
+        ("arr instanceof window.Array", None),
     ];
 
     let fail = vec![
@@ -83,6 +100,10 @@ fn test() {
         ("foo.bar[2] instanceof Array", None),
         ("(0, array) instanceof Array", None),
         ("function foo(){return [] instanceof Array}", None),
+        ("(arr) instanceof Array", None),
+        ("((0, array)) instanceof Array", None),
+        ("function foo(Array) {} arr instanceof Array", None),
+        ("(arr as unknown) instanceof Array", None),
     ];
 
     let fix = vec![
@@ -93,6 +114,15 @@ fn test() {
         ("obj.arr instanceof Array", "Array.isArray(obj.arr)", None),
         ("foo.bar[2] instanceof Array", "Array.isArray(foo.bar[2])", None),
         ("(0, array) instanceof Array", "Array.isArray((0, array))", None),
+        ("(arr) instanceof Array", "Array.isArray(arr)", None),
+        ("((0, array)) instanceof Array", "Array.isArray((0, array))", None),
+        ("(( arr )) instanceof (Array)", "Array.isArray(arr)", None),
+        ("(arr as unknown) instanceof Array", "Array.isArray(arr as unknown)", None),
+        (
+            "function foo(Array) {} arr instanceof Array",
+            "function foo(Array) {} Array.isArray(arr)",
+            None,
+        ),
         (
             "function foo(){return [] instanceof Array}",
             "function foo(){return Array.isArray([])}",
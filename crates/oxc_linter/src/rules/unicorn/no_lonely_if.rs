@@ -208,5 +208,5 @@ fn test() {
     ",
     ];
 
-    Tester::new(NoLonelyIf::NAME, pass, fail).test_and_snapshot();
+    Tester::new(NoLonelyIf::NAME, pass, fail).change_rule_plugin("unicorn").test_and_snapshot();
 }
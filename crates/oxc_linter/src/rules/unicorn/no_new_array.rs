@@ -1,12 +1,13 @@
 use oxc_ast::{ast::Expression, AstKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{ast_util::needs_leading_semicolon, context::LintContext, rule::Rule, AstNode};
 
-fn no_new_array_diagnostic(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("Do not use `new Array(singleArgument)`.").with_help(r"It's not clear whether the argument is meant to be the length of the array or the only element. If the argument is the array's length, consider using `Array.from({ length: n })`. If the argument is the only element, use `[element]`.").with_label(span0)
+fn no_new_array_diagnostic(is_new: bool, span0: Span) -> OxcDiagnostic {
+    let new = if is_new { "new " } else { "" };
+    OxcDiagnostic::warn(format!("Do not use `{new}Array(singleArgument)`.")).with_help(r"It's not clear whether the argument is meant to be the length of the array or the only element. If the argument is the array's length, consider using `Array.from({ length: n })`. If the argument is the only element, use `[element]`.").with_label(span0)
 }
 
 #[derive(Debug, Default, Clone)]
@@ -27,34 +28,74 @@ declare_oxc_lint!(
     /// const array = new Array(1);
     /// const array = new Array(42);
     /// const array = new Array(foo);
+    /// const array = Array(foo);
     ///
     /// // good
     /// const array = Array.from({ length: 42 });
     /// const array = [42];
     /// ```
+    ///
+    /// Integer literals are fixed to `Array.from({ length: n })`, and other literals to
+    /// `[element]`. Other arguments are only reported, since their intent is unclear.
     NoNewArray,
     correctness
 );
 
 impl Rule for NoNewArray {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::NewExpression(new_expr) = node.kind() else {
-            return;
+        let (callee, arguments, span, is_new) = match node.kind() {
+            AstKind::NewExpression(new_expr) => {
+                (&new_expr.callee, &new_expr.arguments, new_expr.span, true)
+            }
+            AstKind::CallExpression(call_expr) if !call_expr.optional => {
+                (&call_expr.callee, &call_expr.arguments, call_expr.span, false)
+            }
+            _ => return,
         };
 
-        let Expression::Identifier(ident) = &new_expr.callee else {
+        let Expression::Identifier(ident) = callee.without_parenthesized() else {
             return;
         };
 
-        if ident.name != "Array" {
+        if ident.name != "Array" || !ctx.semantic().is_reference_to_global_variable(ident) {
             return;
         }
 
-        if new_expr.arguments.len() != 1 {
+        if arguments.len() != 1 {
             return;
         }
 
-        ctx.diagnostic(no_new_array_diagnostic(new_expr.span));
+        let diagnostic = no_new_array_diagnostic(is_new, span);
+        let Some(argument) = arguments[0].as_expression() else {
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+        let text = ctx.source_range(argument.span());
+        let replacement = match argument.without_parenthesized() {
+            // `new Array(1.5)` throws, so only integers are lengths
+            Expression::NumericLiteral(number)
+                if number.value.fract() == 0.0 && number.value >= 0.0 =>
+            {
+                format!("Array.from({{ length: {text} }})")
+            }
+            Expression::StringLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::BooleanLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::TemplateLiteral(_)
+            | Expression::ArrayExpression(_)
+            | Expression::ObjectExpression(_) => {
+                // `[` at the start of a statement would continue the previous one
+                let semicolon = if needs_leading_semicolon(node, ctx) { ";" } else { "" };
+                format!("{semicolon}[{text}]")
+            }
+            // Unknown whether the argument is a length or an element
+            _ => {
+                ctx.diagnostic(diagnostic);
+                return;
+            }
+        };
+        ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(span, replacement));
     }
 }
 
@@ -68,7 +109,12 @@ fn test() {
         r"const array = new Array",
         r"const array = new Array(1, 2)",
         r"const array = Array(1, 2)",
-        r"const array = Array(1)",
+        r"const array = new Array(...foo, bar)",
+        r"const array = Array?.(1)",
+        r"function foo(Array) { new Array(1) }",
+        r"const Array = MyArray; new Array(1)",
+        r"import { Array } from 'immutable'; Array(1)",
+        r"const array = new window.Array(1)",
     ];
 
     let fail = vec![
@@ -128,7 +174,39 @@ fn test() {
         r"new Array(++foo)",
         r"const array = new Array(foo)",
         r"const array = new Array(length)",
+        r"const array = Array(1)",
+        r"const array = (Array)(foo)",
+        r"const array = new (Array)(1)",
+        r"const array = new Array(...foo)",
+        r"function foo(Array) {} new Array(1)",
+    ];
+
+    let fix = vec![
+        (r"const array = new Array(1)", r"const array = Array.from({ length: 1 })", None),
+        (r"const array = Array(42)", r"const array = Array.from({ length: 42 })", None),
+        (r"const array = new Array(0xff)", r"const array = Array.from({ length: 0xff })", None),
+        (r"const array = new (Array)(1)", r"const array = Array.from({ length: 1 })", None),
+        (r"const array = new Array(1.5)", r"const array = new Array(1.5)", None),
+        (r#"const array = new Array("1")"#, r#"const array = ["1"]"#, None),
+        (r#"const array = new Array(("1"))"#, r#"const array = [("1")]"#, None),
+        (r"const array = new Array(null)", r"const array = [null]", None),
+        (r"const array = Array(`foo`)", r"const array = [`foo`]", None),
+        (r"const array = new Array([1, 2])", r"const array = [[1, 2]]", None),
+        (r"const array = new Array(foo)", r"const array = new Array(foo)", None),
+        (r"const array = new Array(length)", r"const array = new Array(length)", None),
+        (r"const array = new Array(...foo)", r"const array = new Array(...foo)", None),
+        ("foo\nnew Array('a').forEach(bar)", "foo\n;['a'].forEach(bar)", None),
+        ("foo;\nnew Array('a').forEach(bar)", "foo;\n['a'].forEach(bar)", None),
+        ("new Array('a').forEach(bar)", "['a'].forEach(bar)", None),
+        ("if (foo) new Array('a').forEach(bar)", "if (foo) ['a'].forEach(bar)", None),
+        ("foo\nbar = new Array('a')", "foo\nbar = ['a']", None),
+        ("const k = function () {}\nnew Array('a')", "const k = function () {}\n;['a']", None),
+        ("const k = class {}\nnew Array('a')", "const k = class {}\n;['a']", None),
+        ("const k = {}\nnew Array('a')", "const k = {}\n;['a']", None),
+        ("if (foo) {}\nnew Array('a')", "if (foo) {}\n;['a']", None),
+        ("if (foo) {} else new Array('a')", "if (foo) {} else ['a']", None),
+        ("const f = () => new Array('a')", "const f = () => ['a']", None),
     ];
 
-    Tester::new(NoNewArray::NAME, pass, fail).test_and_snapshot();
+    Tester::new(NoNewArray::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
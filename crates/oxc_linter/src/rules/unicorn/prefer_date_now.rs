@@ -5,9 +5,17 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
-use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, UnaryOperator};
+use oxc_syntax::{
+    identifier::is_identifier_part,
+    operator::{AssignmentOperator, BinaryOperator, UnaryOperator},
+};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::{RuleFix, RuleFixer},
+    rule::Rule,
+    AstNode,
+};
 
 fn prefer_date_now(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Prefer `Date.now()` over `new Date()`")
@@ -64,12 +72,15 @@ impl Rule for PreferDateNow {
                     if call_expr.arguments.is_empty()
                         && !member_expr.is_computed()
                         && matches!(member_expr.static_property_name(), Some("getTime" | "valueOf"))
-                        && is_new_date(member_expr.object().without_parenthesized())
+                        && is_new_date(member_expr.object().without_parenthesized(), ctx)
                     {
-                        ctx.diagnostic(prefer_date_now_over_methods(
-                            call_expr.span,
-                            member_expr.static_property_name().unwrap(),
-                        ));
+                        ctx.diagnostic_with_fix(
+                            prefer_date_now_over_methods(
+                                call_expr.span,
+                                member_expr.static_property_name().unwrap(),
+                            ),
+                            |fixer| replace_with_date_now(call_expr.span, fixer),
+                        );
                     }
                 }
 
@@ -77,14 +88,22 @@ impl Rule for PreferDateNow {
                 if let Expression::Identifier(ident) = &call_expr.callee {
                     if matches!(ident.name.as_str(), "Number" | "BigInt")
                         && call_expr.arguments.len() == 1
+                        && ctx.semantic().is_reference_to_global_variable(ident)
                     {
                         if let Some(expr) =
                             call_expr.arguments.first().and_then(Argument::as_expression)
                         {
-                            if is_new_date(expr.without_parenthesized()) {
-                                ctx.diagnostic(prefer_date_now_over_number_date_object(
-                                    call_expr.span,
-                                ));
+                            if is_new_date(expr.without_parenthesized(), ctx) {
+                                // `BigInt` is still needed to convert the number
+                                let span = if ident.name == "BigInt" {
+                                    expr.span()
+                                } else {
+                                    call_expr.span
+                                };
+                                ctx.diagnostic_with_fix(
+                                    prefer_date_now_over_number_date_object(call_expr.span),
+                                    |fixer| replace_with_date_now(span, fixer),
+                                );
                             }
                         }
                     }
@@ -97,8 +116,16 @@ impl Rule for PreferDateNow {
                 ) {
                     return;
                 }
-                if is_new_date(&unary_expr.argument) {
-                    ctx.diagnostic(prefer_date_now(unary_expr.argument.span()));
+                if is_new_date(&unary_expr.argument, ctx) {
+                    // `+new Date()` is already a number, `-new Date()` still needs the `-`
+                    let span = if unary_expr.operator == UnaryOperator::UnaryPlus {
+                        unary_expr.span
+                    } else {
+                        unary_expr.argument.span()
+                    };
+                    ctx.diagnostic_with_fix(prefer_date_now(unary_expr.argument.span()), |fixer| {
+                        replace_with_date_now(span, fixer)
+                    });
                 }
             }
             AstKind::AssignmentExpression(assignment_expr) => {
@@ -113,8 +140,8 @@ impl Rule for PreferDateNow {
                     return;
                 }
 
-                if is_new_date(&assignment_expr.right) {
-                    ctx.diagnostic(prefer_date_now(assignment_expr.right.span()));
+                if is_new_date(&assignment_expr.right, ctx) {
+                    report_new_date(assignment_expr.right.span(), ctx);
                 }
             }
             AstKind::BinaryExpression(bin_expr) => {
@@ -129,11 +156,11 @@ impl Rule for PreferDateNow {
                     return;
                 }
 
-                if is_new_date(&bin_expr.left) {
-                    ctx.diagnostic(prefer_date_now(bin_expr.left.span()));
+                if is_new_date(&bin_expr.left, ctx) {
+                    report_new_date(bin_expr.left.span(), ctx);
                 }
-                if is_new_date(&bin_expr.right) {
-                    ctx.diagnostic(prefer_date_now(bin_expr.right.span()));
+                if is_new_date(&bin_expr.right, ctx) {
+                    report_new_date(bin_expr.right.span(), ctx);
                 }
             }
             _ => {}
@@ -141,13 +168,29 @@ impl Rule for PreferDateNow {
     }
 }
 
-fn is_new_date(expr: &Expression) -> bool {
+fn report_new_date(span: Span, ctx: &LintContext) {
+    ctx.diagnostic_with_fix(prefer_date_now(span), |fixer| replace_with_date_now(span, fixer));
+}
+
+fn replace_with_date_now<'a>(span: Span, fixer: RuleFixer<'_, 'a>) -> RuleFix<'a> {
+    // Keep `return+new Date` from becoming `returnDate.now()`
+    let needs_space = fixer
+        .source_range(Span::new(0, span.start))
+        .chars()
+        .next_back()
+        .is_some_and(is_identifier_part);
+    fixer.replace(span, if needs_space { " Date.now()" } else { "Date.now()" })
+}
+
+fn is_new_date(expr: &Expression, ctx: &LintContext) -> bool {
     let Expression::NewExpression(new_expr) = expr.without_parenthesized() else {
         return false;
     };
 
     if let Expression::Identifier(ident) = &new_expr.callee {
-        return ident.name == "Date" && new_expr.arguments.is_empty();
+        return ident.name == "Date"
+            && new_expr.arguments.is_empty()
+            && ctx.semantic().is_reference_to_global_variable(ident);
     }
     false
 }
@@ -186,6 +229,11 @@ fn test() {
         r"foo = new Date() | 0",
         r"foo &= new Date()",
         r"foo = new Date() >> 0",
+        r"function foo(Date) { return new Date().getTime() }",
+        r"class Date {} +new Date()",
+        r"const Number = toNumber; Number(new Date())",
+        r"function foo(BigInt) { return BigInt(new Date()) }",
+        r"import Date from 'date'; const ts = new Date() - 0",
     ];
 
     let fail = vec![
@@ -214,7 +262,36 @@ fn test() {
         r"foo **= (new Date())",
         r"function foo(){return+new Date}",
         r"function foo(){return-new Date}",
+        r"function foo(Number) {} Number(new Date())",
+    ];
+
+    let fix = vec![
+        (r"const ts = new Date().getTime();", r"const ts = Date.now();", None),
+        (r"const ts = (new Date).valueOf();", r"const ts = Date.now();", None),
+        (
+            r"const ts = /* 1 */ Number(/* 2 */ new /* 3 */ Date( /* 4 */ ) /* 5 */) /* 6 */",
+            r"const ts = /* 1 */ Date.now() /* 6 */",
+            None,
+        ),
+        (
+            r"const tsBigInt = /* 1 */ BigInt(/* 2 */ new /* 3 */ Date( /* 4 */ ) /* 5 */) /* 6 */",
+            r"const tsBigInt = /* 1 */ BigInt(/* 2 */ Date.now() /* 5 */) /* 6 */",
+            None,
+        ),
+        (r"const ts = + /* 1 */ new Date;", r"const ts = Date.now();", None),
+        (r"const ts = - /* 1 */ new Date();", r"const ts = - /* 1 */ Date.now();", None),
+        (r"const ts = new Date() - 0", r"const ts = Date.now() - 0", None),
+        (r"const foo = bar - new Date", r"const foo = bar - Date.now()", None),
+        (
+            r"const zero = (new Date(/* 1 */) /* 2 */) /* 3 */ - /* 4 */new Date",
+            r"const zero = Date.now() /* 3 */ - /* 4 */Date.now()",
+            None,
+        ),
+        (r"foo -= new Date()", r"foo -= Date.now()", None),
+        (r"foo **= (new Date())", r"foo **= Date.now()", None),
+        (r"function foo(){return+new Date}", r"function foo(){return Date.now()}", None),
+        (r"function foo(){return-new Date}", r"function foo(){return-Date.now()}", None),
     ];
 
-    Tester::new(PreferDateNow::NAME, pass, fail).test_and_snapshot();
+    Tester::new(PreferDateNow::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
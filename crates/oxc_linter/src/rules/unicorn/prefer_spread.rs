@@ -442,5 +442,8 @@ fn test() {
         (r#""foo bar baz".split("")"#, r#"[..."foo bar baz"]"#, None),
     ];
 
-    Tester::new(PreferSpread::NAME, pass, fail).expect_fix(expect_fix).test_and_snapshot();
+    Tester::new(PreferSpread::NAME, pass, fail)
+        .change_rule_plugin("unicorn")
+        .expect_fix(expect_fix)
+        .test_and_snapshot();
 }
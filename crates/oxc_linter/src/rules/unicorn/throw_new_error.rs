@@ -1,11 +1,11 @@
 use lazy_static::lazy_static;
 use oxc_ast::{
-    ast::{match_member_expression, Expression},
+    ast::{match_member_expression, Expression, IdentifierReference},
     AstKind,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 use regex::Regex;
 
 use crate::{
@@ -46,6 +46,9 @@ declare_oxc_lint!(
     /// throw new lib.TypeError('unicorn');
     ///
     /// ```
+    ///
+    /// Calls to locally defined functions that can't be constructors, such as arrow
+    /// functions, are ignored, as are built-in error names shadowed by a local binding.
     ThrowNewError,
     style
 );
@@ -66,7 +69,7 @@ impl Rule for ThrowNewError {
 
         match call_expr.callee.without_parenthesized() {
             Expression::Identifier(v) => {
-                if !CUSTOM_ERROR_REGEX_PATTERN.is_match(&v.name) {
+                if !CUSTOM_ERROR_REGEX_PATTERN.is_match(&v.name) || !may_be_constructor(v, ctx) {
                     return;
                 }
             }
@@ -84,10 +87,66 @@ impl Rule for ThrowNewError {
             _ => return,
         }
 
-        ctx.diagnostic(throw_new_error_diagnostic(call_expr.span));
+        ctx.diagnostic_with_fix(throw_new_error_diagnostic(call_expr.span), |fixer| {
+            let callee = fixer.source_range(call_expr.callee.span());
+            let rest =
+                fixer.source_range(Span::new(call_expr.callee.span().end, call_expr.span.end));
+            // `new a().Error()` would construct `a` instead
+            let replacement = if contains_call(&call_expr.callee) {
+                format!("new ({callee}){rest}")
+            } else {
+                format!("new {callee}{rest}")
+            };
+            fixer.replace(call_expr.span, replacement)
+        });
+    }
+}
+
+/// Whether `ident` may refer to an error constructor. Built-in errors that are shadowed
+/// and local functions that can't be called with `new` are ruled out.
+fn may_be_constructor(ident: &IdentifierReference, ctx: &LintContext) -> bool {
+    let symbols = ctx.symbols();
+    let Some(symbol_id) =
+        ident.reference_id.get().and_then(|id| symbols.get_reference(id).symbol_id())
+    else {
+        return true;
+    };
+    if BUILTIN_ERRORS.contains(&ident.name.as_str()) {
+        return false;
+    }
+    match ctx.nodes().kind(symbols.get_declaration(symbol_id)) {
+        AstKind::Function(func) => !func.r#async && !func.generator,
+        AstKind::VariableDeclarator(decl) => match &decl.init {
+            Some(Expression::ArrowFunctionExpression(_)) => false,
+            Some(Expression::FunctionExpression(func)) => !func.r#async && !func.generator,
+            _ => true,
+        },
+        _ => true,
+    }
+}
+
+/// Whether the callee is a member chain on a call, like `getGlobalThis().Error`.
+fn contains_call(expr: &Expression) -> bool {
+    match expr {
+        Expression::CallExpression(_) => true,
+        member @ match_member_expression!(Expression) => {
+            contains_call(member.to_member_expression().object())
+        }
+        _ => false,
     }
 }
 
+const BUILTIN_ERRORS: [&str; 8] = [
+    "Error",
+    "AggregateError",
+    "EvalError",
+    "RangeError",
+    "ReferenceError",
+    "SyntaxError",
+    "TypeError",
+    "URIError",
+];
+
 lazy_static! {
     static ref CUSTOM_ERROR_REGEX_PATTERN: Regex =
         Regex::new(r"^(?:[A-Z][\da-z]*)*Error$").unwrap();
@@ -115,6 +174,14 @@ fn test() {
         ("throw lib[Error]()", None),
         ("throw lib[\"Error\"]()", None),
         ("throw lib.getError()", None),
+        ("throw Error?.()", None),
+        ("throw lib?.Error()", None),
+        ("function foo(Error) { throw Error() }", None),
+        ("const TypeError = createError; throw TypeError('foo')", None),
+        ("const CustomError = (message) => new Error(message); throw CustomError('foo')", None),
+        ("const CustomError = async function () {}; throw CustomError('foo')", None),
+        ("function* CustomError() {} throw CustomError('foo')", None),
+        ("async function CustomError() {} throw CustomError('foo')", None),
     ];
 
     let fail = vec![
@@ -140,7 +207,24 @@ fn test() {
         ("throw getGlobalThis().Error()", None),
         ("throw utils.getGlobalThis().Error()", None),
         ("throw (( getGlobalThis().Error ))()", None),
+        ("function CustomError(message) { this.message = message } throw CustomError('foo')", None),
+        ("class CustomError extends Error {} throw CustomError('foo')", None),
+        ("const CustomError = function () {}; throw CustomError('foo')", None),
+        ("import { CustomError } from 'errors'; throw CustomError('foo')", None),
+        ("throw Error<string>('foo')", None),
+    ];
+
+    let fix = vec![
+        ("throw Error()", "throw new Error()", None),
+        ("throw (Error)('foo')", "throw new (Error)('foo')", None),
+        ("throw lib.mod.Error()", "throw new lib.mod.Error()", None),
+        ("throw (( URIError() ))", "throw (( new URIError() ))", None),
+        ("throw getGlobalThis().Error()", "throw new (getGlobalThis().Error)()", None),
+        ("throw utils.getGlobalThis().Error()", "throw new (utils.getGlobalThis().Error)()", None),
+        ("throw (( getGlobalThis().Error ))()", "throw new (( getGlobalThis().Error ))()", None),
+        ("throw lib[mod].Error()", "throw new lib[mod].Error()", None),
+        ("throw Error<string>('foo')", "throw new Error<string>('foo')", None),
     ];
 
-    Tester::new(ThrowNewError::NAME, pass, fail).test_and_snapshot();
+    Tester::new(ThrowNewError::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
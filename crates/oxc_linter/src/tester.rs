@@ -88,6 +88,7 @@ impl<S: Into<String>> From<(S, S)> for ExpectFix {
 
 pub struct Tester {
     rule_name: &'static str,
+    plugin_name: Option<&'static str>,
     rule_path: PathBuf,
     expect_pass: Vec<TestCase>,
     expect_fail: Vec<TestCase>,
@@ -100,6 +101,8 @@ pub struct Tester {
     jsx_a11y_plugin: bool,
     nextjs_plugin: bool,
     react_perf_plugin: bool,
+    /// Other rules enabled alongside the tested rule
+    other_rules: Vec<&'static str>,
 }
 
 impl Tester {
@@ -115,6 +118,7 @@ impl Tester {
             env::current_dir().unwrap().join("fixtures/import").into_boxed_path();
         Self {
             rule_name,
+            plugin_name: None,
             rule_path,
             expect_pass,
             expect_fail,
@@ -127,6 +131,7 @@ impl Tester {
             nextjs_plugin: false,
             react_perf_plugin: false,
             vitest_plugin: false,
+            other_rules: vec![],
         }
     }
 
@@ -136,6 +141,18 @@ impl Tester {
         self
     }
 
+    /// Select the rule from the given plugin, for rule names shared by several plugins
+    pub fn change_rule_plugin(mut self, plugin_name: &'static str) -> Self {
+        self.plugin_name = Some(plugin_name);
+        self
+    }
+
+    /// Also enable another rule, e.g. to check that overlapping fixes aren't applied twice
+    pub fn with_rule(mut self, rule_name: &'static str) -> Self {
+        self.other_rules.push(rule_name);
+        self
+    }
+
     /// Change the extension of the path
     pub fn change_rule_path_extension(mut self, ext: &str) -> Self {
         self.rule_path = self.rule_path.with_extension(ext);
@@ -211,7 +228,10 @@ impl Tester {
     }
 
     pub fn snapshot(&self) {
-        let name = self.rule_name.replace('-', "_");
+        let name = match self.plugin_name {
+            Some(plugin_name) => format!("{plugin_name}_{}", self.rule_name.replace('-', "_")),
+            None => self.rule_name.replace('-', "_"),
+        };
         insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
             insta::assert_snapshot!(name, self.snapshot);
         });
@@ -258,6 +278,14 @@ impl Tester {
     ) -> TestResult {
         let allocator = Allocator::default();
         let rule = self.find_rule().read_json(rule_config.unwrap_or_default());
+        let mut rules = vec![RuleWithSeverity::new(rule, AllowWarnDeny::Warn)];
+        for rule_name in &self.other_rules {
+            let rule = RULES
+                .iter()
+                .find(|rule| rule.name() == *rule_name)
+                .unwrap_or_else(|| panic!("Rule not found: {rule_name}"));
+            rules.push(RuleWithSeverity::new(rule.clone(), AllowWarnDeny::Warn));
+        }
         let options = LintOptions::default()
             .with_fix(is_fix.then_some(FixKind::DangerousFix).unwrap_or_default())
             .with_import_plugin(self.import_plugin)
@@ -271,7 +299,7 @@ impl Tester {
             .map_or_else(OxlintConfig::default, |v| OxlintConfig::deserialize(v).unwrap());
         let linter = Linter::from_options(options)
             .unwrap()
-            .with_rules(vec![RuleWithSeverity::new(rule, AllowWarnDeny::Warn)])
+            .with_rules(rules)
             .with_eslint_config(eslint_config);
         let path_to_lint = if self.import_plugin {
             assert!(path.is_none(), "import plugin does not support path");
@@ -320,7 +348,12 @@ impl Tester {
     fn find_rule(&self) -> &RuleEnum {
         RULES
             .iter()
-            .find(|rule| rule.name() == self.rule_name)
+            .find(|rule| {
+                rule.name() == self.rule_name
+                    && self
+                        .plugin_name
+                        .map_or(true, |plugin_name| rule.plugin_name() == plugin_name)
+            })
             .unwrap_or_else(|| panic!("Rule not found: {}", &self.rule_name))
     }
 }
@@ -0,0 +1,31 @@
+use std::borrow::Cow;
+
+use oxc_ast::ast::PropertyKey;
+use oxc_span::GetSpan;
+
+use crate::context::LintContext;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AccessorKind {
+    Get,
+    Set,
+}
+
+/// The key of a getter or setter, used to match accessors of the same property.
+#[derive(PartialEq, Eq)]
+pub enum AccessorKey<'a> {
+    Static(Cow<'a, str>),
+    Private(&'a str),
+    /// A computed key, compared by its source text
+    Computed(&'a str),
+}
+
+pub fn get_accessor_key<'a>(key: &PropertyKey<'a>, ctx: &LintContext<'a>) -> AccessorKey<'a> {
+    if let PropertyKey::PrivateIdentifier(ident) = key {
+        AccessorKey::Private(ident.name.as_str())
+    } else if let Some(name) = key.static_name() {
+        AccessorKey::Static(name)
+    } else {
+        AccessorKey::Computed(ctx.source_range(key.span()))
+    }
+}
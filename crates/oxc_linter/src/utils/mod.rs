@@ -1,3 +1,4 @@
+mod accessor;
 mod jest;
 mod jsdoc;
 mod nextjs;
@@ -7,7 +8,7 @@ mod tree_shaking;
 mod unicorn;
 
 pub use self::{
-    jest::*, jsdoc::*, nextjs::*, react::*, react_perf::*, tree_shaking::*, unicorn::*,
+    accessor::*, jest::*, jsdoc::*, nextjs::*, react::*, react_perf::*, tree_shaking::*, unicorn::*,
 };
 
 /// Check if the Jest rule is adapted to Vitest.
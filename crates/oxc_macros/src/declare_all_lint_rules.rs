@@ -38,20 +38,43 @@ impl Parse for AllLintRulesMeta {
 pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
     let AllLintRulesMeta { rules } = metadata;
     let use_stmts = rules.iter().map(|rule| &rule.path).collect::<Vec<_>>();
-    let struct_names = rules.iter().map(|rule| &rule.name).collect::<Vec<_>>();
-    let plugin_names = rules.iter().map(|node| {
-        node.path
-            .segments
-            .iter()
-            .take(node.path.segments.len() - 1)
-            .map(|s| format!("{}", s.ident))
-            .collect::<Vec<_>>()
-            .join("/")
-    });
+    let rule_names = rules.iter().map(|rule| &rule.name).collect::<Vec<_>>();
+    let plugin_names = rules
+        .iter()
+        .map(|node| {
+            node.path
+                .segments
+                .iter()
+                .take(node.path.segments.len() - 1)
+                .map(|s| format!("{}", s.ident))
+                .collect::<Vec<_>>()
+                .join("/")
+        })
+        .collect::<Vec<_>>();
+    // Rules sharing a name with a rule from another plugin are prefixed with their plugin name,
+    // e.g. `EslintNoLonelyIf` and `UnicornNoLonelyIf`
+    let struct_names = rules
+        .iter()
+        .zip(&plugin_names)
+        .map(|(rule, plugin_name)| {
+            if rules.iter().filter(|other| other.name == rule.name).count() > 1 {
+                syn::Ident::new(
+                    &format!(
+                        "{}{}",
+                        plugin_name.replace('/', "_").to_case(Case::Pascal),
+                        rule.name
+                    ),
+                    rule.name.span(),
+                )
+            } else {
+                rule.name.clone()
+            }
+        })
+        .collect::<Vec<_>>();
     let ids = rules.iter().enumerate().map(|(i, _)| i).collect::<Vec<_>>();
 
     let expanded = quote! {
-        #(pub use self::#use_stmts::#struct_names;)*
+        #(pub use self::#use_stmts::#rule_names as #struct_names;)*
 
         use crate::{context::LintContext, rule::{Rule, RuleCategory, RuleMeta}, AstNode};
         use oxc_semantic::SymbolId;
@@ -1135,7 +1135,11 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         let label = &stmt.label.name;
         control_flow!(self, |cfg| {
             let ctx = cfg.ctx(Some(label.as_str())).default().allow_break();
-            if stmt.body.is_iteration_statement() {
+            let mut body = &stmt.body;
+            while let Statement::LabeledStatement(labeled) = body {
+                body = &labeled.body;
+            }
+            if body.is_iteration_statement() {
                 ctx.allow_continue();
             }
         });
@@ -1487,19 +1491,20 @@ impl<'a> Visit<'a> for SemanticBuilder<'a> {
         self.visit_expression(&stmt.object);
 
         /* cfg - body basic block */
-        let body_graph_ix = control_flow!(self, |cfg| cfg.new_basic_block_normal());
+        let (after_condition_graph_ix, body_graph_ix) =
+            control_flow!(self, |cfg| (cfg.current_node_ix, cfg.new_basic_block_normal()));
         /* cfg */
 
         self.visit_statement(&stmt.body);
 
         /* cfg - after body basic block */
         control_flow!(self, |cfg| {
+            let end_of_body_graph_ix = cfg.current_node_ix;
             let after_body_graph_ix = cfg.new_basic_block_normal();
 
             cfg.add_edge(before_with_stmt_graph_ix, condition_graph_ix, EdgeType::Normal);
-            cfg.add_edge(condition_graph_ix, body_graph_ix, EdgeType::Normal);
-            cfg.add_edge(body_graph_ix, after_body_graph_ix, EdgeType::Normal);
-            cfg.add_edge(condition_graph_ix, after_body_graph_ix, EdgeType::Normal);
+            cfg.add_edge(after_condition_graph_ix, body_graph_ix, EdgeType::Normal);
+            cfg.add_edge(end_of_body_graph_ix, after_body_graph_ix, EdgeType::Normal);
         });
         /* cfg */
 
@@ -0,0 +1,6 @@
+outer: inner: while (a) {
+    if (b) {
+        continue outer;
+    }
+    break;
+}
@@ -84,6 +84,26 @@ impl AssignmentOperator {
             Self::Exponential => "**=",
         }
     }
+
+    /// Returns the binary operator of an arithmetic or bitwise operator assignment,
+    /// e.g. `+` for `+=`.
+    pub fn to_binary_operator(self) -> Option<BinaryOperator> {
+        match self {
+            Self::Addition => Some(BinaryOperator::Addition),
+            Self::Subtraction => Some(BinaryOperator::Subtraction),
+            Self::Multiplication => Some(BinaryOperator::Multiplication),
+            Self::Division => Some(BinaryOperator::Division),
+            Self::Remainder => Some(BinaryOperator::Remainder),
+            Self::ShiftLeft => Some(BinaryOperator::ShiftLeft),
+            Self::ShiftRight => Some(BinaryOperator::ShiftRight),
+            Self::ShiftRightZeroFill => Some(BinaryOperator::ShiftRightZeroFill),
+            Self::BitwiseOR => Some(BinaryOperator::BitwiseOR),
+            Self::BitwiseXOR => Some(BinaryOperator::BitwiseXOR),
+            Self::BitwiseAnd => Some(BinaryOperator::BitwiseAnd),
+            Self::Exponential => Some(BinaryOperator::Exponential),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -201,6 +221,26 @@ impl BinaryOperator {
         }
     }
 
+    /// Returns the operator assignment of an arithmetic or bitwise operator,
+    /// e.g. `+=` for `+`.
+    pub fn to_assignment_operator(self) -> Option<AssignmentOperator> {
+        match self {
+            Self::Addition => Some(AssignmentOperator::Addition),
+            Self::Subtraction => Some(AssignmentOperator::Subtraction),
+            Self::Multiplication => Some(AssignmentOperator::Multiplication),
+            Self::Division => Some(AssignmentOperator::Division),
+            Self::Remainder => Some(AssignmentOperator::Remainder),
+            Self::ShiftLeft => Some(AssignmentOperator::ShiftLeft),
+            Self::ShiftRight => Some(AssignmentOperator::ShiftRight),
+            Self::ShiftRightZeroFill => Some(AssignmentOperator::ShiftRightZeroFill),
+            Self::BitwiseOR => Some(AssignmentOperator::BitwiseOR),
+            Self::BitwiseXOR => Some(AssignmentOperator::BitwiseXOR),
+            Self::BitwiseAnd => Some(AssignmentOperator::BitwiseAnd),
+            Self::Exponential => Some(AssignmentOperator::Exponential),
+            _ => None,
+        }
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Equality => "==",